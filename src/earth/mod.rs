@@ -1,6 +1,8 @@
 //! Constants and helpers for calculations involving earth
 mod periodic_terms;
 mod terms;
+mod nutation;
 
 pub use self::periodic_terms::*;
 pub use self::terms::*;
+pub use self::nutation::*;
@@ -0,0 +1,593 @@
+//! Nutation in longitude and obliquity, and the obliquity of the ecliptic.
+#![allow(clippy::unreadable_literal)]
+
+/// A row from the Periodic Terms for the Nutation in Longitude and
+/// Obliquity table (from the NREL SPA document)
+#[derive(Debug, Copy, Clone)]
+pub struct NutationTableRow {
+    /// multiples of the mean elongation of the moon from the sun, the mean
+    /// anomaly of the sun, the mean anomaly of the moon, the moon's argument
+    /// of latitude and the longitude of the ascending node of the moon
+    pub y: [i8; 5],
+    /// constant coefficient for the nutation in longitude, in 0.0001"
+    pub a: f64,
+    /// time-dependent coefficient for the nutation in longitude, in 0.0001"
+    pub b: f64,
+    /// constant coefficient for the nutation in obliquity, in 0.0001"
+    pub c: f64,
+    /// time-dependent coefficient for the nutation in obliquity, in 0.0001"
+    pub d: f64,
+}
+
+/// A collection of the Periodic Terms for the Nutation in Longitude and
+/// Obliquity as per the NREL SPA document (A.4.3)
+pub const NUTATION_PERIODIC_TERMS: &[NutationTableRow] = &[
+    NutationTableRow {
+        y: [0, 0, 0, 0, 1],
+        a: -171996_f64,
+        b: -174.2_f64,
+        c: 92025_f64,
+        d: 8.9_f64,
+    },
+    NutationTableRow {
+        y: [-2, 0, 0, 2, 2],
+        a: -13187_f64,
+        b: -1.6_f64,
+        c: 5736_f64,
+        d: -3.1_f64,
+    },
+    NutationTableRow {
+        y: [0, 0, 0, 2, 2],
+        a: -2274_f64,
+        b: -0.2_f64,
+        c: 977_f64,
+        d: -0.5_f64,
+    },
+    NutationTableRow {
+        y: [0, 0, 0, 0, 2],
+        a: 2062_f64,
+        b: 0.2_f64,
+        c: -895_f64,
+        d: 0.5_f64,
+    },
+    NutationTableRow {
+        y: [0, 1, 0, 0, 0],
+        a: 1426_f64,
+        b: -3.4_f64,
+        c: 54_f64,
+        d: -0.1_f64,
+    },
+    NutationTableRow {
+        y: [0, 0, 1, 0, 0],
+        a: 712_f64,
+        b: 0.1_f64,
+        c: -7_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [-2, 1, 0, 2, 2],
+        a: -517_f64,
+        b: 1.2_f64,
+        c: 224_f64,
+        d: -0.6_f64,
+    },
+    NutationTableRow {
+        y: [0, 0, 0, 2, 1],
+        a: -386_f64,
+        b: -0.4_f64,
+        c: 200_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, 0, 1, 2, 2],
+        a: -301_f64,
+        b: 0_f64,
+        c: 129_f64,
+        d: -0.1_f64,
+    },
+    NutationTableRow {
+        y: [-2, -1, 0, 2, 2],
+        a: 217_f64,
+        b: -0.5_f64,
+        c: -95_f64,
+        d: 0.3_f64,
+    },
+    NutationTableRow {
+        y: [-2, 0, 1, 0, 0],
+        a: -158_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [-2, 0, 0, 2, 1],
+        a: 129_f64,
+        b: 0.1_f64,
+        c: -70_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, 0, -1, 2, 2],
+        a: 123_f64,
+        b: 0_f64,
+        c: -53_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [2, 0, 0, 0, 0],
+        a: 63_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, 0, 1, 0, 1],
+        a: 63_f64,
+        b: 0.1_f64,
+        c: -33_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [2, 0, -1, 2, 2],
+        a: -59_f64,
+        b: 0_f64,
+        c: 26_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, 0, -1, 0, 1],
+        a: -58_f64,
+        b: -0.1_f64,
+        c: 32_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, 0, 1, 2, 1],
+        a: -51_f64,
+        b: 0_f64,
+        c: 27_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [-2, 0, 2, 0, 0],
+        a: 48_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, 0, -2, 2, 1],
+        a: 46_f64,
+        b: 0_f64,
+        c: -24_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [2, 0, 0, 2, 2],
+        a: -38_f64,
+        b: 0_f64,
+        c: 16_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, 0, 2, 2, 2],
+        a: -31_f64,
+        b: 0_f64,
+        c: 13_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, 0, 2, 0, 0],
+        a: 29_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [-2, 0, 1, 2, 2],
+        a: 29_f64,
+        b: 0_f64,
+        c: -12_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, 0, 0, 2, 0],
+        a: 26_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [-2, 0, 0, 2, 0],
+        a: -22_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, 0, -1, 2, 1],
+        a: 21_f64,
+        b: 0_f64,
+        c: -10_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, 2, 0, 0, 0],
+        a: 17_f64,
+        b: -0.1_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [2, 0, -1, 0, 1],
+        a: 16_f64,
+        b: 0_f64,
+        c: -8_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [-2, 2, 0, 2, 2],
+        a: -16_f64,
+        b: 0.1_f64,
+        c: 7_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, 1, 0, 0, 1],
+        a: -15_f64,
+        b: 0_f64,
+        c: 9_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [-2, 0, 1, 0, 1],
+        a: -13_f64,
+        b: 0_f64,
+        c: 7_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, -1, 0, 0, 1],
+        a: -12_f64,
+        b: 0_f64,
+        c: 6_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, 0, 2, -2, 0],
+        a: 11_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [2, 0, -1, 2, 1],
+        a: -10_f64,
+        b: 0_f64,
+        c: 5_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [2, 0, 1, 2, 2],
+        a: -8_f64,
+        b: 0_f64,
+        c: 3_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, 1, 0, 2, 2],
+        a: 7_f64,
+        b: 0_f64,
+        c: -3_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [-2, 1, 1, 0, 0],
+        a: -7_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, -1, 0, 2, 2],
+        a: -7_f64,
+        b: 0_f64,
+        c: 3_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [2, 0, 0, 2, 1],
+        a: -7_f64,
+        b: 0_f64,
+        c: 3_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [2, 0, 1, 0, 0],
+        a: 6_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [-2, 0, 2, 2, 2],
+        a: 6_f64,
+        b: 0_f64,
+        c: -3_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [-2, 0, 1, 2, 1],
+        a: 6_f64,
+        b: 0_f64,
+        c: -3_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [2, 0, -2, 0, 1],
+        a: -6_f64,
+        b: 0_f64,
+        c: 3_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [2, 0, 0, 0, 1],
+        a: -6_f64,
+        b: 0_f64,
+        c: 3_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, -1, 1, 0, 0],
+        a: 5_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [-2, -1, 0, 2, 1],
+        a: -5_f64,
+        b: 0_f64,
+        c: 3_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [-2, 0, 0, 0, 1],
+        a: -5_f64,
+        b: 0_f64,
+        c: 3_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, 0, 2, 2, 1],
+        a: -5_f64,
+        b: 0_f64,
+        c: 3_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [-2, 0, 2, 0, 1],
+        a: 4_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [-2, 1, 0, 2, 1],
+        a: 4_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, 0, 1, -2, 0],
+        a: 4_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [-1, 0, 1, 0, 0],
+        a: -4_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [-2, 1, 0, 0, 0],
+        a: -4_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [1, 0, 0, 0, 0],
+        a: -4_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, 0, 1, 2, 0],
+        a: 3_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, 0, -2, 2, 2],
+        a: -3_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [-1, -1, 1, 0, 0],
+        a: -3_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, 1, 1, 0, 0],
+        a: -3_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, -1, 1, 2, 2],
+        a: -3_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [2, -1, -1, 2, 2],
+        a: -3_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [0, 0, 3, 2, 2],
+        a: -3_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+    NutationTableRow {
+        y: [2, -1, 0, 2, 2],
+        a: -3_f64,
+        b: 0_f64,
+        c: 0_f64,
+        d: 0_f64,
+    },
+];
+
+/// Calculates the fundamental arguments (X0 to X4) used by the nutation
+/// terms, in radians
+fn nutation_arguments(jul_cent_ephemeris: f64) -> [f64; 5] {
+    let jce = jul_cent_ephemeris;
+    let polynomial = |a: f64, b: f64, c: f64, d: f64| {
+        (a + b * jce + c * jce.powi(2) + jce.powi(3) / d).to_radians()
+    };
+
+    [
+        // mean elongation of the moon from the sun
+        polynomial(297.850_36, 445_267.111_480, -0.001_914_2, 189_474_f64),
+        // mean anomaly of the sun
+        polynomial(357.527_72, 35_999.050_340, -0.000_160_3, -300_000_f64),
+        // mean anomaly of the moon
+        polynomial(134.962_98, 477_198.867_398, 0.008_697_2, 56_250_f64),
+        // moon's argument of latitude
+        polynomial(93.271_91, 483_202.017_538, -0.003_682_5, 327_270_f64),
+        // longitude of the ascending node of the moon
+        polynomial(125.044_52, -1_934.136_261, 0.002_070_8, 450_000_f64),
+    ]
+}
+
+fn nutation_term_argument(row: &NutationTableRow, x: &[f64; 5]) -> f64 {
+    row.y
+        .iter()
+        .zip(x.iter())
+        .map(|(y, x)| f64::from(*y) * x)
+        .sum()
+}
+
+/// Calculates the nutation in longitude (Δψ), in radians
+///
+/// Obtained from NREL SPA report
+///
+/// # Examples:
+/// ```
+/// use meealgi::earth::nutation_longitude;
+///
+/// // 1987 April 10, 0h TD (Meeus example 22.a)
+/// let delta_psi = nutation_longitude(-0.127_296_372_348_f64);
+/// assert_eq!(-3.788, (delta_psi.to_degrees() * 3_600_000_f64).round() / 1_000_f64);
+/// ```
+pub fn nutation_longitude(jul_cent_ephemeris: f64) -> f64 {
+    let x = nutation_arguments(jul_cent_ephemeris);
+
+    let sum = NUTATION_PERIODIC_TERMS.iter().fold(0_f64, |curr, row| {
+        curr + (row.a + row.b * jul_cent_ephemeris) * nutation_term_argument(row, &x).sin()
+    });
+
+    (sum / 36_000_000_f64).to_radians()
+}
+
+/// Calculates the nutation in obliquity (Δε), in radians
+///
+/// Obtained from NREL SPA report
+///
+/// # Examples:
+/// ```
+/// use meealgi::earth::nutation_obliquity;
+///
+/// // 1987 April 10, 0h TD (Meeus example 22.a)
+/// let delta_epsilon = nutation_obliquity(-0.127_296_372_348_f64);
+/// assert_eq!(9.443, (delta_epsilon.to_degrees() * 3_600_000_f64).round() / 1_000_f64);
+/// ```
+pub fn nutation_obliquity(jul_cent_ephemeris: f64) -> f64 {
+    let x = nutation_arguments(jul_cent_ephemeris);
+
+    let sum = NUTATION_PERIODIC_TERMS.iter().fold(0_f64, |curr, row| {
+        curr + (row.c + row.d * jul_cent_ephemeris) * nutation_term_argument(row, &x).cos()
+    });
+
+    (sum / 36_000_000_f64).to_radians()
+}
+
+/// Calculates the mean obliquity of the ecliptic (ε0), in radians
+///
+/// Obtained from NREL SPA report (valid for 10,000 years either side of
+/// J2000.0)
+///
+/// # Examples:
+/// ```
+/// use meealgi::earth::mean_obliquity;
+///
+/// // 1987 April 10, 0h TD (Meeus example 22.a)
+/// let epsilon_0 = mean_obliquity(-0.012_729_637_234_8_f64);
+/// assert_eq!(84_387.407, (epsilon_0.to_degrees() * 3_600_000_f64).round() / 1_000_f64);
+/// ```
+pub fn mean_obliquity(jul_mil_ephemeris: f64) -> f64 {
+    const COEFFICIENTS: [f64; 11] = [
+        84_381.448,
+        -4_680.93,
+        -1.55,
+        1_999.25,
+        -51.38,
+        -249.67,
+        -39.05,
+        7.12,
+        27.87,
+        5.79,
+        2.45,
+    ];
+
+    let u = jul_mil_ephemeris / 10_f64;
+    let arcseconds = COEFFICIENTS.iter().rev().fold(0_f64, |curr, coeff| {
+        curr * u + coeff
+    });
+
+    (arcseconds / 3_600_f64).to_radians()
+}
+
+/// Calculates the true obliquity of the ecliptic (ε), in radians
+///
+/// # Examples:
+/// ```
+/// use meealgi::earth::{mean_obliquity, nutation_obliquity, true_obliquity};
+///
+/// // 1987 April 10, 0h TD (Meeus example 22.a)
+/// let epsilon = true_obliquity(
+///     mean_obliquity(-0.012_729_637_234_8_f64),
+///     nutation_obliquity(-0.127_296_372_348_f64),
+/// );
+/// assert_eq!(23.443_57, (epsilon.to_degrees() * 100_000_f64).round() / 100_000_f64);
+/// ```
+pub fn true_obliquity(mean_obliquity: f64, nutation_obliquity: f64) -> f64 {
+    mean_obliquity + nutation_obliquity
+}
@@ -1,4 +1,4 @@
-#![cfg_attr(feature="cargo-clippy", allow(unreadable_literal,approx_constant))]
+#![allow(clippy::unreadable_literal, clippy::approx_constant)]
 
 /// A row from the Earth's Periodic Terms table (from the
 /// NREL SPA document)
@@ -2,6 +2,11 @@ use earth::periodic_terms;
 use utils::angles::limit_radians;
 use std::f64::consts::PI;
 
+/// Equatorial radius of the earth, in km, as used by Meeus (ch. 11)
+pub const EQUATORIAL_RADIUS: f64 = 6_378.14_f64;
+/// Kilometres in an astronomical unit
+pub const AU_IN_KM: f64 = 149_597_870.7_f64;
+
 /// Calculates the heliocentric longitude, in radians
 ///
 /// Obtained from NREL SPA report
@@ -139,4 +144,36 @@ pub fn geocentric_longitude(heliocentric_long: f64) -> f64 {
 /// ```
 pub fn geocentric_latitude(heliocentric_lat: f64) -> f64 {
     -heliocentric_lat
-}
\ No newline at end of file
+}
+
+/// Calculates the aberration correction for the sun's position, in
+/// radians, given the earth radius vector (in AU)
+///
+/// # Examples:
+/// ```
+/// use meealgi::earth::aberration_correction;
+/// assert_eq!(-20.4898_f64, aberration_correction(1_f64).to_degrees() * 3_600_f64);
+/// ```
+pub fn aberration_correction(radius_vec: f64) -> f64 {
+    (-20.4898_f64 / (3_600_f64 * radius_vec)).to_radians()
+}
+
+/// Calculates the apparent longitude of the sun, in radians, given its
+/// geocentric longitude, the nutation in longitude and the aberration
+/// correction
+///
+/// # Examples:
+/// ```
+/// use meealgi::earth::*;
+/// let jme = 0.003_792_781_991_6_f64;
+/// let lambda = apparent_sun_longitude(
+///     geocentric_longitude(heliocentric_longitude(jme)),
+///     nutation_longitude(jme * 10_f64),
+///     aberration_correction(radius_vec(jme)),
+/// );
+/// // NREL SPA example (A.5)
+/// assert_eq!(204.008_55, (lambda.to_degrees() * 100_000_f64).round() / 100_000_f64);
+/// ```
+pub fn apparent_sun_longitude(geocentric_long: f64, nutation_long: f64, aberration: f64) -> f64 {
+    limit_radians(geocentric_long + nutation_long + aberration)
+}
@@ -0,0 +1,336 @@
+use earth;
+use std::f64::consts::PI;
+use eclipse::{SolarEclipse, MOON_RADIUS, MOON_UMBRAL_RADIUS, SUN_SEMIDIAMETER};
+use moon;
+use sun;
+use time::{apparent_sidereal_time, jul_to_julc, julc_to_julm, mean_sidereal_time};
+use time::nasa::delta_t_jul;
+use utils::coordinates::Equatorial;
+
+/// Radius of the sun, in equatorial radii of the earth
+const SUN_RADIUS: f64 = SUN_SEMIDIAMETER * earth::AU_IN_KM / earth::EQUATORIAL_RADIUS;
+/// Hours either side of the reference instant over which the elements are
+/// fitted
+const FIT_HOURS: i32 = 3;
+/// Rate of the earth's rotation relative to the stars, in radians per second
+/// of time
+const SIDEREAL_RATE: f64 = 1.002_737_9_f64 * 2_f64 * PI / 86_400_f64;
+
+/// The Besselian elements of a solar eclipse, which describe the shadow of
+/// the moon in the fundamental plane (through the centre of the earth,
+/// perpendicular to the axis of the shadow)
+///
+/// Each element is a polynomial in the time (t, in hours of TD) since the
+/// reference instant; the coefficients are listed from the constant term.
+/// Distances are in equatorial radii of the earth, and angles in radians.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BesselianElements {
+    /// Julian Ephemeris Day of the reference instant (t0), the hour of TD
+    /// nearest greatest eclipse
+    pub jde: f64,
+    /// ΔT at the reference instant, in seconds, for referring μ to
+    /// Greenwich
+    pub delta_t: f64,
+    /// x coordinate of the shadow axis (towards the east)
+    pub x: [f64; 4],
+    /// y coordinate of the shadow axis (towards the north)
+    pub y: [f64; 4],
+    /// declination of the shadow axis (d)
+    pub d: [f64; 4],
+    /// hour angle of the shadow axis (μ), referred to the ephemeris
+    /// meridian (1.002738 ΔT east of Greenwich)
+    pub mu: [f64; 4],
+    /// radius of the penumbra in the fundamental plane (l1)
+    pub l1: [f64; 4],
+    /// radius of the umbra in the fundamental plane (l2), negative when
+    /// the umbra reaches the plane (for total eclipses)
+    pub l2: [f64; 4],
+    /// tangent of the half angle of the penumbral cone (f1)
+    pub tan_f1: f64,
+    /// tangent of the half angle of the umbral cone (f2)
+    pub tan_f2: f64,
+}
+
+/// The values of the Besselian elements at an instant
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct BesselianValues {
+    /// x coordinate of the shadow axis
+    pub x: f64,
+    /// y coordinate of the shadow axis
+    pub y: f64,
+    /// declination of the shadow axis
+    pub d: f64,
+    /// hour angle of the shadow axis, referred to the ephemeris meridian
+    pub mu: f64,
+    /// radius of the penumbra in the fundamental plane
+    pub l1: f64,
+    /// radius of the umbra in the fundamental plane
+    pub l2: f64,
+    /// hourly rate of change of x
+    pub dx: f64,
+    /// hourly rate of change of y
+    pub dy: f64,
+    /// hourly rate of change of d
+    pub dd: f64,
+    /// hourly rate of change of μ
+    pub dmu: f64,
+}
+
+fn evaluate(coefficients: &[f64; 4], t: f64) -> f64 {
+    coefficients.iter().rev().fold(0_f64, |curr, coeff| curr * t + coeff)
+}
+
+fn evaluate_rate(coefficients: &[f64; 4], t: f64) -> f64 {
+    coefficients[1] + 2_f64 * coefficients[2] * t + 3_f64 * coefficients[3] * t.powi(2)
+}
+
+impl BesselianElements {
+    /// Calculates the Besselian elements of a solar eclipse from the
+    /// apparent positions of the sun and moon
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use meealgi::eclipse::{solar_eclipse, BesselianElements};
+    ///
+    /// // 2017 August 21
+    /// let elements = BesselianElements::new(&solar_eclipse(218).unwrap());
+    /// assert_eq!(0.542, (elements.l1[0] * 1_000_f64).round() / 1_000_f64);
+    /// ```
+    pub fn new(eclipse: &SolarEclipse) -> BesselianElements {
+        BesselianElements::at_instant(eclipse.jde)
+    }
+
+    /// Calculates the Besselian elements for the hour of TD nearest a
+    /// Julian Ephemeris Day, from the apparent positions of the sun and
+    /// moon
+    pub fn at_instant(jde: f64) -> BesselianElements {
+        // round to the nearest hour of TD
+        let reference = ((jde - 0.5_f64) * 24_f64).round() / 24_f64 + 0.5_f64;
+
+        let samples: Vec<(f64, ShadowAxis)> = (-FIT_HOURS..FIT_HOURS + 1)
+            .map(|hour| {
+                let t = f64::from(hour);
+                (t, shadow_axis(reference + t / 24_f64))
+            })
+            .collect();
+
+        // unwrap μ, so it increases smoothly over the fitted hours
+        let mut mu = Vec::with_capacity(samples.len());
+        for &(t, ref axis) in samples.iter() {
+            let value = match mu.last() {
+                Some(&(_, previous)) => {
+                    let mut value = axis.mu;
+                    while value < previous {
+                        value += 2_f64 * PI;
+                    }
+                    value
+                }
+                None => axis.mu,
+            };
+            mu.push((t, value));
+        }
+
+        let fit = |value: &dyn Fn(&ShadowAxis) -> f64| {
+            fit_cubic(&samples
+                .iter()
+                .map(|&(t, ref axis)| (t, value(axis)))
+                .collect::<Vec<(f64, f64)>>())
+        };
+        let central = &samples[FIT_HOURS as usize].1;
+
+        BesselianElements {
+            jde: reference,
+            delta_t: delta_t_jul(reference),
+            x: fit(&|axis| axis.x),
+            y: fit(&|axis| axis.y),
+            d: fit(&|axis| axis.d),
+            mu: fit_cubic(&mu),
+            l1: fit(&|axis| axis.l1),
+            l2: fit(&|axis| axis.l2),
+            tan_f1: central.tan_f1,
+            tan_f2: central.tan_f2,
+        }
+    }
+
+    /// The values of the elements `t` hours (of TD) after the reference
+    /// instant
+    pub fn values(&self, t: f64) -> BesselianValues {
+        BesselianValues {
+            x: evaluate(&self.x, t),
+            y: evaluate(&self.y, t),
+            d: evaluate(&self.d, t),
+            mu: evaluate(&self.mu, t),
+            l1: evaluate(&self.l1, t),
+            l2: evaluate(&self.l2, t),
+            dx: evaluate_rate(&self.x, t),
+            dy: evaluate_rate(&self.y, t),
+            dd: evaluate_rate(&self.d, t),
+            dmu: evaluate_rate(&self.mu, t),
+        }
+    }
+
+    /// The hour angle of the shadow axis east of Greenwich (rather than
+    /// the ephemeris meridian), `t` hours (of TD) after the reference
+    /// instant
+    pub fn greenwich_hour_angle(&self, t: f64) -> f64 {
+        evaluate(&self.mu, t) - SIDEREAL_RATE * self.delta_t
+    }
+
+    /// The Julian Ephemeris Day `t` hours (of TD) after the reference
+    /// instant
+    pub fn jde_at(&self, t: f64) -> f64 {
+        self.jde + t / 24_f64
+    }
+}
+
+/// The position of the axis of the moon's shadow, and the moon, at an
+/// instant
+struct ShadowAxis {
+    x: f64,
+    y: f64,
+    d: f64,
+    mu: f64,
+    l1: f64,
+    l2: f64,
+    tan_f1: f64,
+    tan_f2: f64,
+}
+
+fn to_rectangular(position: &Equatorial, distance: f64) -> [f64; 3] {
+    [
+        distance * position.declination.cos() * position.right_ascension.cos(),
+        distance * position.declination.cos() * position.right_ascension.sin(),
+        distance * position.declination.sin(),
+    ]
+}
+
+/// Calculates the position of the shadow axis at a Julian Ephemeris Day
+fn shadow_axis(jde: f64) -> ShadowAxis {
+    let jce = jul_to_julc(jde);
+    let sun_position = sun::apparent_equatorial(jde);
+    let moon_position = moon::apparent_equatorial(jde);
+    let sun_distance = sun::distance(jde) * earth::AU_IN_KM / earth::EQUATORIAL_RADIUS;
+    let moon_distance = moon::distance(jce) / earth::EQUATORIAL_RADIUS;
+
+    let sun_vector = to_rectangular(&sun_position, sun_distance);
+    let moon_vector = to_rectangular(&moon_position, moon_distance);
+    let axis: Vec<f64> = sun_vector
+        .iter()
+        .zip(moon_vector.iter())
+        .map(|(sun, moon)| sun - moon)
+        .collect();
+    let axis_length = axis.iter().map(|value| value.powi(2)).sum::<f64>().sqrt();
+
+    // right ascension and declination of the shadow axis
+    let a = axis[1].atan2(axis[0]);
+    let d = (axis[2] / axis_length).asin();
+
+    let hour_angle = moon_position.right_ascension - a;
+    let x = moon_distance * moon_position.declination.cos() * hour_angle.sin();
+    let y = moon_distance *
+        (moon_position.declination.sin() * d.cos() -
+             moon_position.declination.cos() * d.sin() * hour_angle.cos());
+    let z = moon_distance *
+        (moon_position.declination.sin() * d.sin() +
+             moon_position.declination.cos() * d.cos() * hour_angle.cos());
+
+    let f1 = ((SUN_RADIUS + MOON_RADIUS) / axis_length).asin();
+    let f2 = ((SUN_RADIUS - MOON_UMBRAL_RADIUS) / axis_length).asin();
+
+    // as is conventional, μ is referred to the ephemeris meridian (the
+    // sidereal time at the instant in TD, rather than UT)
+    let sidereal_time = apparent_sidereal_time(
+        mean_sidereal_time(jde),
+        earth::nutation_longitude(jce),
+        earth::true_obliquity(
+            earth::mean_obliquity(julc_to_julm(jce)),
+            earth::nutation_obliquity(jce),
+        ),
+    );
+
+    ShadowAxis {
+        x,
+        y,
+        d,
+        mu: (sidereal_time - a).rem_euclid(2_f64 * PI),
+        l1: z * f1.tan() + MOON_RADIUS / f1.cos(),
+        l2: z * f2.tan() - MOON_UMBRAL_RADIUS / f2.cos(),
+        tan_f1: f1.tan(),
+        tan_f2: f2.tan(),
+    }
+}
+
+/// Fits a cubic polynomial to samples by least squares, returning the
+/// coefficients from the constant term
+fn fit_cubic(samples: &[(f64, f64)]) -> [f64; 4] {
+    // the normal equations, as an augmented matrix
+    let mut matrix = [[0_f64; 5]; 4];
+    for &(t, value) in samples {
+        for (row, equation) in matrix.iter_mut().enumerate() {
+            for (column, cell) in equation.iter_mut().take(4).enumerate() {
+                *cell += t.powi((row + column) as i32);
+            }
+            equation[4] += value * t.powi(row as i32);
+        }
+    }
+
+    // Gauss-Jordan elimination (the matrix is symmetric positive definite,
+    // so no pivoting is needed)
+    for pivot in 0..4 {
+        let divisor = matrix[pivot][pivot];
+        for cell in matrix[pivot].iter_mut() {
+            *cell /= divisor;
+        }
+        for row in 0..4 {
+            if row != pivot {
+                let factor = matrix[row][pivot];
+                let pivot_row = matrix[pivot];
+                for (cell, pivot_cell) in matrix[row].iter_mut().zip(pivot_row.iter()) {
+                    *cell -= factor * pivot_cell;
+                }
+            }
+        }
+    }
+
+    [matrix[0][4], matrix[1][4], matrix[2][4], matrix[3][4]]
+}
+
+#[cfg(test)]
+mod tests {
+    use eclipse::*;
+
+    #[test]
+    fn elements_of_2017_august_21() {
+        // from NASA's Besselian elements (t0 = 18h TD)
+        let elements = BesselianElements::new(&solar_eclipse(218).unwrap());
+
+        assert!((elements.jde - 2_457_987.25_f64).abs() < 0.000_001);
+        assert!((elements.x[0] + 0.129_571_f64).abs() < 0.000_5, "{:?}", elements);
+        assert!((elements.x[1] - 0.540_642_6_f64).abs() < 0.000_5);
+        assert!((elements.y[0] - 0.485_416_f64).abs() < 0.000_5);
+        assert!((elements.y[1] + 0.141_64_f64).abs() < 0.000_5);
+        assert!((elements.d[0].to_degrees() - 11.866_96_f64).abs() < 0.001);
+        assert!((elements.mu[0].to_degrees() - 89.245_43_f64).abs() < 0.01);
+        assert!((elements.mu[1].to_degrees() - 15.003_94_f64).abs() < 0.001);
+        assert!((elements.l1[0] - 0.542_093_f64).abs() < 0.000_2);
+        assert!((elements.l2[0] + 0.004_025_f64).abs() < 0.000_2);
+        assert!((elements.tan_f1 - 0.004_622_2_f64).abs() < 0.000_001);
+        assert!((elements.tan_f2 - 0.004_599_2_f64).abs() < 0.000_001);
+    }
+
+    #[test]
+    fn fit_reproduces_a_cubic() {
+        let samples: Vec<(f64, f64)> = (-3..4)
+            .map(|t| {
+                let t = f64::from(t);
+                (t, 1_f64 - 2_f64 * t + 0.5_f64 * t.powi(2) + 0.25_f64 * t.powi(3))
+            })
+            .collect();
+        let coefficients = super::fit_cubic(&samples);
+
+        for (actual, expected) in coefficients.iter().zip([1_f64, -2_f64, 0.5, 0.25].iter()) {
+            assert!((actual - expected).abs() < 1e-10);
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! GeoJSON output of eclipse paths (requires the `geojson` feature).
+
+use eclipse::{EclipsePath, PathPoint};
+use serde_json::Value;
+use std::f64::consts::PI;
+
+/// Splits a line into the runs of points without gaps in time, or jumps
+/// across the antimeridian, dropping any single points left over (as a
+/// GeoJSON line needs at least two positions)
+fn segments(line: &[PathPoint]) -> Vec<&[PathPoint]> {
+    let step = line.windows(2)
+        .map(|pair| pair[1].jde - pair[0].jde)
+        .fold(f64::INFINITY, f64::min);
+
+    let mut segments = Vec::new();
+    let mut start = 0;
+    for index in 1..line.len() {
+        let (previous, point) = (&line[index - 1], &line[index]);
+
+        if point.jde - previous.jde > 1.5_f64 * step ||
+            (point.longitude - previous.longitude).abs() > PI
+        {
+            segments.push(&line[start..index]);
+            start = index;
+        }
+    }
+    if start < line.len() {
+        segments.push(&line[start..]);
+    }
+
+    segments.retain(|segment| segment.len() >= 2);
+    segments
+}
+
+fn feature(name: &str, line: &[PathPoint]) -> Value {
+    let coordinates: Vec<Vec<Vec<f64>>> = segments(line)
+        .iter()
+        .map(|segment| {
+            segment
+                .iter()
+                .map(|point| vec![point.longitude.to_degrees(), point.latitude.to_degrees()])
+                .collect()
+        })
+        .collect();
+
+    json!({
+        "type": "Feature",
+        "properties": { "name": name },
+        "geometry": {
+            "type": "MultiLineString",
+            "coordinates": coordinates,
+        },
+    })
+}
+
+impl EclipsePath {
+    /// Converts the path into a GeoJSON `FeatureCollection`, with a
+    /// `MultiLineString` feature for each line (named by its `name`
+    /// property), in degrees
+    ///
+    /// Lines are split where they cross the antimeridian, or leave the
+    /// earth, leaving out any isolated points.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use meealgi::eclipse::{eclipse_path, solar_eclipse, BesselianElements};
+    ///
+    /// let elements = BesselianElements::new(&solar_eclipse(218).unwrap());
+    /// let geojson = eclipse_path(&elements, 5_f64).unwrap().to_geojson();
+    ///
+    /// assert_eq!("FeatureCollection", geojson["type"]);
+    /// assert_eq!("central_line", geojson["features"][0]["properties"]["name"]);
+    /// ```
+    pub fn to_geojson(&self) -> Value {
+        json!({
+            "type": "FeatureCollection",
+            "features": [
+                feature("central_line", &self.central_line),
+                feature("northern_umbral_limit", &self.northern_umbral_limit),
+                feature("southern_umbral_limit", &self.southern_umbral_limit),
+                feature("northern_penumbral_limit", &self.northern_penumbral_limit),
+                feature("southern_penumbral_limit", &self.southern_penumbral_limit),
+            ],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use eclipse::*;
+
+    fn point(jde: f64, longitude: f64) -> PathPoint {
+        PathPoint {
+            jde,
+            latitude: 0_f64,
+            longitude: longitude.to_radians(),
+        }
+    }
+
+    #[test]
+    fn splits_at_gaps_and_the_antimeridian() {
+        let line = [
+            point(0_f64, 170_f64),
+            point(0.1_f64, 175_f64),
+            point(0.2_f64, -178_f64),
+            point(0.3_f64, -173_f64),
+            point(0.6_f64, -160_f64),
+        ];
+
+        // the point after the gap is on its own, so is left out
+        let lengths: Vec<usize> = super::segments(&line).iter().map(|s| s.len()).collect();
+        assert_eq!(vec![2, 2], lengths);
+    }
+
+    #[test]
+    fn coordinates_are_longitude_first() {
+        let elements = BesselianElements::new(&solar_eclipse(218).unwrap());
+        let path = eclipse_path(&elements, 10_f64).unwrap();
+        let geojson = path.to_geojson();
+
+        let first = &geojson["features"][0]["geometry"]["coordinates"][0][0];
+        assert_eq!(path.central_line[0].longitude.to_degrees(), first[0]);
+        assert_eq!(path.central_line[0].latitude.to_degrees(), first[1]);
+
+        for feature in geojson["features"].as_array().unwrap() {
+            for segment in feature["geometry"]["coordinates"].as_array().unwrap() {
+                assert!(segment.as_array().unwrap().len() >= 2);
+            }
+        }
+    }
+}
@@ -0,0 +1,340 @@
+use chrono::{DateTime, TimeZone};
+use eclipse::{BesselianElements, SolarEclipseType};
+use std::f64::consts::PI;
+use time::nasa::{jule_to_dt, jule_to_jul};
+use utils::coordinates::Observer;
+
+/// Ratio of the polar and equatorial radii of the earth
+const EARTH_AXIS_RATIO: f64 = 0.996_647_19_f64;
+/// Iterations allowed when solving for an instant
+const MAX_ITERATIONS: usize = 20;
+
+/// A contact, or the maximum, of a solar eclipse as seen by an observer
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Contact {
+    /// Julian Ephemeris Day of the contact
+    pub jde: f64,
+    /// the sun's geometric altitude above the observer's horizon, in radians
+    /// (negative when the contact happens below the horizon)
+    pub sun_altitude: f64,
+}
+
+impl Contact {
+    /// The Julian Day (UT) of the contact
+    pub fn jul(&self) -> f64 {
+        jule_to_jul(self.jde)
+    }
+
+    /// The instant of the contact in the given timezone, or `None` if it
+    /// can't be represented by `chrono`
+    pub fn dt<Tz: TimeZone>(&self, tz: &Tz) -> Option<DateTime<Tz>> {
+        jule_to_dt(self.jde, tz)
+    }
+}
+
+/// The circumstances of a solar eclipse as seen by an observer
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LocalCircumstances {
+    /// the type of eclipse seen by the observer (partial, or total or
+    /// annular within the path of the umbra or antumbra)
+    pub eclipse_type: SolarEclipseType,
+    /// first contact (C1), when the eclipse begins
+    pub first_contact: Contact,
+    /// second contact (C2), when totality or annularity begins
+    pub second_contact: Option<Contact>,
+    /// maximum eclipse
+    pub maximum: Contact,
+    /// third contact (C3), when totality or annularity ends
+    pub third_contact: Option<Contact>,
+    /// fourth contact (C4), when the eclipse ends
+    pub fourth_contact: Contact,
+    /// fraction of the sun's diameter covered by the moon at maximum
+    /// eclipse (at least 1 for a total eclipse)
+    pub magnitude: f64,
+    /// fraction of the sun's disk covered by the moon at maximum eclipse
+    pub obscuration: f64,
+}
+
+/// The observer's position relative to the shadow at an instant, as per
+/// the Explanatory Supplement to the Astronomical Almanac
+struct LocalValues {
+    /// the observer's distance from the shadow axis, in the fundamental
+    /// plane (u, v)
+    u: f64,
+    v: f64,
+    /// hourly rate of change of u and v
+    a: f64,
+    b: f64,
+    /// radii of the penumbra and umbra at the observer
+    l1: f64,
+    l2: f64,
+    /// the observer's distance towards the sun from the fundamental plane,
+    /// and from the centre of the earth
+    zeta: f64,
+    rho: f64,
+}
+
+impl LocalValues {
+    fn n_squared(&self) -> f64 {
+        self.a.powi(2) + self.b.powi(2)
+    }
+
+    /// distance between the observer and the shadow axis
+    fn m(&self) -> f64 {
+        (self.u.powi(2) + self.v.powi(2)).sqrt()
+    }
+
+    fn sun_altitude(&self) -> f64 {
+        (self.zeta / self.rho).asin()
+    }
+}
+
+/// The geocentric coordinates (ρ sin φ', ρ cos φ') of a point at sea level
+/// at a geodetic latitude, in equatorial radii of the earth
+pub(crate) fn geocentric_coordinates(latitude: f64) -> (f64, f64) {
+    let u = (EARTH_AXIS_RATIO * latitude.tan()).atan();
+
+    (EARTH_AXIS_RATIO * u.sin(), u.cos())
+}
+
+fn local_values(elements: &BesselianElements, observer: &Observer, t: f64) -> LocalValues {
+    let values = elements.values(t);
+    let (rho_sin, rho_cos) = geocentric_coordinates(observer.latitude);
+
+    let hour_angle = elements.greenwich_hour_angle(t) + observer.longitude;
+    let xi = rho_cos * hour_angle.sin();
+    let eta = rho_sin * values.d.cos() - rho_cos * values.d.sin() * hour_angle.cos();
+    let zeta = rho_sin * values.d.sin() + rho_cos * values.d.cos() * hour_angle.cos();
+    let dxi = values.dmu * rho_cos * hour_angle.cos();
+    let deta = values.dmu * xi * values.d.sin() - zeta * values.dd;
+
+    LocalValues {
+        u: values.x - xi,
+        v: values.y - eta,
+        a: values.dx - dxi,
+        b: values.dy - deta,
+        l1: values.l1 - zeta * elements.tan_f1,
+        l2: values.l2 - zeta * elements.tan_f2,
+        zeta,
+        rho: (rho_sin.powi(2) + rho_cos.powi(2)).sqrt(),
+    }
+}
+
+/// Iterates `step` (giving a correction to the time, in hours) until it
+/// converges, returning the time
+pub(crate) fn solve<F: Fn(f64) -> Option<f64>>(start: f64, step: F) -> Option<f64> {
+    let mut t = start;
+
+    for _ in 0..MAX_ITERATIONS {
+        let correction = step(t)?;
+        t += correction;
+
+        if correction.abs() < 0.000_001 {
+            return Some(t);
+        }
+    }
+
+    None
+}
+
+/// Finds the instant the observer's distance from the shadow axis equals
+/// the radius given by `radius` (the penumbra or umbra), before (`sign` of
+/// -1) or after (1) maximum eclipse
+fn contact<R>(
+    elements: &BesselianElements,
+    observer: &Observer,
+    maximum: f64,
+    sign: f64,
+    radius: R,
+) -> Option<f64>
+where
+    R: Fn(&LocalValues) -> f64,
+{
+    solve(maximum, |t| {
+        let values = local_values(elements, observer, t);
+        let n = values.n_squared().sqrt();
+        let l = radius(&values).abs();
+        let s = (values.a * values.v - values.u * values.b) / (n * l);
+
+        if s.abs() > 1_f64 {
+            return None;
+        }
+
+        Some(
+            -(values.u * values.a + values.v * values.b) / values.n_squared() +
+                sign * l / n * (1_f64 - s.powi(2)).sqrt(),
+        )
+    })
+}
+
+/// Calculates the fraction of the area of a disk of radius 1 covered by a
+/// disk of radius `r`, with centres `c` apart
+fn covered_fraction(r: f64, c: f64) -> f64 {
+    if c >= 1_f64 + r {
+        0_f64
+    } else if c <= (1_f64 - r).abs() {
+        r.min(1_f64).powi(2)
+    } else {
+        let sun_angle = ((c.powi(2) + 1_f64 - r.powi(2)) / (2_f64 * c)).acos();
+        let moon_angle = ((c.powi(2) + r.powi(2) - 1_f64) / (2_f64 * c * r)).acos();
+        let kite = ((-c + 1_f64 + r) * (c + 1_f64 - r) * (c - 1_f64 + r) * (c + 1_f64 + r))
+            .sqrt();
+
+        (sun_angle + r.powi(2) * moon_angle - kite / 2_f64) / PI
+    }
+}
+
+/// Calculates the circumstances of a solar eclipse for an observer at sea
+/// level, or `None` if the eclipse isn't visible from the observer's
+/// position on the earth (ignoring whether the sun is above the horizon,
+/// which is given by each contact's `sun_altitude`)
+///
+/// Uses the method from the Explanatory Supplement to the Astronomical
+/// Almanac, as used for NASA's eclipse predictions.
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::eclipse::{local_circumstances, solar_eclipse, BesselianElements,
+///                        SolarEclipseType};
+/// use meealgi::utils::coordinates::Observer;
+///
+/// // 2017 August 21, from Carbondale, Illinois
+/// let elements = BesselianElements::new(&solar_eclipse(218).unwrap());
+/// let carbondale = Observer {
+///     latitude: 37.727_f64.to_radians(),
+///     longitude: -89.217_f64.to_radians(),
+/// };
+///
+/// let local = local_circumstances(&elements, &carbondale).unwrap();
+/// assert_eq!(SolarEclipseType::Total, local.eclipse_type);
+///
+/// let duration = local.third_contact.unwrap().jde - local.second_contact.unwrap().jde;
+/// assert_eq!(2.6, (duration * 1_440_f64 * 10_f64).round() / 10_f64);
+/// ```
+pub fn local_circumstances(
+    elements: &BesselianElements,
+    observer: &Observer,
+) -> Option<LocalCircumstances> {
+    let maximum = solve(0_f64, |t| {
+        let values = local_values(elements, observer, t);
+        Some(-(values.u * values.a + values.v * values.b) / values.n_squared())
+    })?;
+    let at_maximum = local_values(elements, observer, maximum);
+
+    let m = at_maximum.m();
+    if m >= at_maximum.l1 {
+        return None;
+    }
+
+    let contact_at = |t: f64| {
+        Contact {
+            jde: elements.jde_at(t),
+            sun_altitude: local_values(elements, observer, t).sun_altitude(),
+        }
+    };
+
+    let first = contact(elements, observer, maximum, -1_f64, |values| values.l1)?;
+    let fourth = contact(elements, observer, maximum, 1_f64, |values| values.l1)?;
+
+    let (eclipse_type, second, third) = if m < at_maximum.l2.abs() {
+        let eclipse_type = if at_maximum.l2 < 0_f64 {
+            SolarEclipseType::Total
+        } else {
+            SolarEclipseType::Annular
+        };
+        (
+            eclipse_type,
+            contact(elements, observer, maximum, -1_f64, |values| values.l2),
+            contact(elements, observer, maximum, 1_f64, |values| values.l2),
+        )
+    } else {
+        (SolarEclipseType::Partial, None, None)
+    };
+
+    let magnitude = (at_maximum.l1 - m) / (at_maximum.l1 + at_maximum.l2);
+    let ratio = (at_maximum.l1 - at_maximum.l2) / (at_maximum.l1 + at_maximum.l2);
+    let obscuration = covered_fraction(ratio, 2_f64 * m / (at_maximum.l1 + at_maximum.l2));
+
+    Some(LocalCircumstances {
+        eclipse_type,
+        first_contact: contact_at(first),
+        second_contact: second.map(&contact_at),
+        maximum: contact_at(maximum),
+        third_contact: third.map(&contact_at),
+        fourth_contact: contact_at(fourth),
+        magnitude,
+        obscuration,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use eclipse::*;
+    use utils::coordinates::Observer;
+
+    fn observer(latitude: f64, longitude: f64) -> Observer {
+        Observer {
+            latitude: latitude.to_radians(),
+            longitude: longitude.to_radians(),
+        }
+    }
+
+    #[test]
+    fn total_eclipse_of_2017() {
+        let elements = BesselianElements::new(&solar_eclipse(218).unwrap());
+
+        // Carbondale, Illinois, near the point of greatest duration
+        let local = local_circumstances(&elements, &observer(37.727, -89.217)).unwrap();
+        let second = local.second_contact.unwrap();
+        let third = local.third_contact.unwrap();
+
+        assert_eq!(SolarEclipseType::Total, local.eclipse_type);
+        assert!(local.magnitude > 1_f64);
+        assert_eq!(1_f64, local.obscuration);
+        assert!(local.first_contact.jde < second.jde);
+        assert!(second.jde < local.maximum.jde && local.maximum.jde < third.jde);
+        assert!(third.jde < local.fourth_contact.jde);
+
+        // about 2m40s of totality, around 18h22m UT
+        let duration = (third.jde - second.jde) * 86_400_f64;
+        assert!(duration > 150_f64 && duration < 165_f64, "{}", duration);
+        let maximum = local.maximum.dt(&Utc).unwrap() -
+            Utc.with_ymd_and_hms(2017, 8, 21, 18, 22, 0).unwrap();
+        assert!(maximum.num_seconds().abs() < 120, "{:?}", local);
+        assert!(local.maximum.sun_altitude.to_degrees() > 60_f64);
+    }
+
+    #[test]
+    fn partial_eclipse_of_2017() {
+        let elements = BesselianElements::new(&solar_eclipse(218).unwrap());
+
+        // New York City
+        let local = local_circumstances(&elements, &observer(40.713, -74.006)).unwrap();
+
+        assert_eq!(SolarEclipseType::Partial, local.eclipse_type);
+        assert!(local.second_contact.is_none() && local.third_contact.is_none());
+        assert!(local.magnitude > 0.75 && local.magnitude < 0.79, "{:?}", local);
+        assert!(local.obscuration > 0.69 && local.obscuration < 0.74, "{:?}", local);
+    }
+
+    #[test]
+    fn not_visible() {
+        let elements = BesselianElements::new(&solar_eclipse(218).unwrap());
+
+        // Sydney
+        assert!(local_circumstances(&elements, &observer(-33.87, 151.21)).is_none());
+    }
+
+    #[test]
+    fn covered_fraction() {
+        use super::covered_fraction;
+
+        assert_eq!(0_f64, covered_fraction(1_f64, 2.5_f64));
+        assert_eq!(0.25_f64, covered_fraction(0.5_f64, 0.2_f64));
+        // two equal disks, offset by their radius
+        let lens = 2_f64 / 3_f64 - 3_f64.sqrt() / (2_f64 * ::std::f64::consts::PI);
+        assert!((covered_fraction(1_f64, 1_f64) - lens).abs() < 1e-12);
+    }
+}
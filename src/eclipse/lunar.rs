@@ -0,0 +1,267 @@
+use eclipse::{LunarEclipse, MOON_RADIUS, SUN_SEMIDIAMETER};
+use moon;
+use std::f64::consts::PI;
+use sun;
+use time::jul_to_julc;
+use utils::bisection::bisect;
+
+/// Horizontal parallax of the sun at a distance of 1 AU, in radians (8.794")
+const SUN_PARALLAX: f64 = 0.000_042_634_f64;
+/// Days either side of Meeus' instant searched for greatest eclipse
+const GREATEST_WINDOW: f64 = 0.05_f64;
+/// Days either side of greatest eclipse searched for contacts (longer than
+/// the longest penumbral eclipse)
+const CONTACT_WINDOW: f64 = 0.2_f64;
+/// Iterations used to refine an instant, narrowing the window to well
+/// under a second
+const REFINEMENTS: usize = 30;
+
+/// How the earth's shadow is enlarged to allow for its atmosphere
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ShadowEnlargement {
+    /// The geometric shadow of the earth, with no enlargement
+    None,
+    /// Chauvenet's method, enlarging the radii of the shadows by 1/50 (as
+    /// used by many almanacs)
+    Chauvenet,
+    /// Danjon's method, enlarging the radius of the earth by 1/85, which
+    /// better fits observed contact times
+    Danjon,
+}
+
+/// The circumstances of a lunar eclipse, which are the same for any
+/// observer who can see the moon
+///
+/// Instants are Julian Ephemeris Days; contacts with the umbra are `None`
+/// when the eclipse doesn't reach that phase.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LunarEclipseCircumstances {
+    /// first contact with the penumbra (P1), when the eclipse begins
+    pub p1: f64,
+    /// first contact with the umbra (U1), when the partial eclipse begins
+    pub u1: Option<f64>,
+    /// second contact with the umbra (U2), when the total eclipse begins
+    pub u2: Option<f64>,
+    /// greatest eclipse, when the moon's centre is nearest the axis of the
+    /// shadow
+    pub greatest: f64,
+    /// third contact with the umbra (U3), when the total eclipse ends
+    pub u3: Option<f64>,
+    /// last contact with the umbra (U4), when the partial eclipse ends
+    pub u4: Option<f64>,
+    /// last contact with the penumbra (P4), when the eclipse ends
+    pub p4: f64,
+    /// fraction of the moon's diameter within the umbra at greatest eclipse
+    /// (negative if the moon misses the umbra)
+    pub umbral_magnitude: f64,
+    /// fraction of the moon's diameter within the penumbra at greatest
+    /// eclipse
+    pub penumbral_magnitude: f64,
+}
+
+/// The angular sizes of the moon and the earth's shadows, and the distance
+/// between their centres, as seen from the centre of the earth (in
+/// radians)
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct ShadowGeometry {
+    separation: f64,
+    moon_semidiameter: f64,
+    umbral_radius: f64,
+    penumbral_radius: f64,
+}
+
+impl ShadowGeometry {
+    fn new(jde: f64, enlargement: ShadowEnlargement) -> ShadowGeometry {
+        let moon_position = moon::apparent_equatorial(jde);
+        let moon_parallax = moon::equatorial_horizontal_parallax(moon::distance(jul_to_julc(jde)));
+
+        let sun_position = sun::apparent_equatorial(jde);
+        let sun_distance = sun::distance(jde);
+        let sun_parallax = SUN_PARALLAX / sun_distance;
+        let sun_semidiameter = SUN_SEMIDIAMETER / sun_distance;
+
+        let mut shadow = sun_position;
+        shadow.right_ascension += PI;
+        shadow.declination = -shadow.declination;
+
+        let (umbral_radius, penumbral_radius) = match enlargement {
+            ShadowEnlargement::None => (
+                moon_parallax + sun_parallax - sun_semidiameter,
+                moon_parallax + sun_parallax + sun_semidiameter,
+            ),
+            ShadowEnlargement::Chauvenet => (
+                1.02_f64 * (moon_parallax + sun_parallax - sun_semidiameter),
+                1.02_f64 * (moon_parallax + sun_parallax + sun_semidiameter),
+            ),
+            ShadowEnlargement::Danjon => (
+                1.01_f64 * moon_parallax + sun_parallax - sun_semidiameter,
+                1.01_f64 * moon_parallax + sun_parallax + sun_semidiameter,
+            ),
+        };
+
+        ShadowGeometry {
+            separation: moon_position.separation(&shadow),
+            moon_semidiameter: (MOON_RADIUS * moon_parallax.sin()).asin(),
+            umbral_radius,
+            penumbral_radius,
+        }
+    }
+
+    fn umbral_magnitude(&self) -> f64 {
+        (self.umbral_radius + self.moon_semidiameter - self.separation) /
+            (2_f64 * self.moon_semidiameter)
+    }
+
+    fn penumbral_magnitude(&self) -> f64 {
+        (self.penumbral_radius + self.moon_semidiameter - self.separation) /
+            (2_f64 * self.moon_semidiameter)
+    }
+}
+
+/// Calculates the contact times and magnitudes of a lunar eclipse from the
+/// positions of the sun and moon, and the geometry of the earth's shadow
+///
+/// Returns `None` if, with the given enlargement of the shadow, the moon
+/// misses the penumbra.
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::eclipse::{lunar_eclipse, lunar_eclipse_circumstances, ShadowEnlargement};
+///
+/// // 2018 January 31
+/// let eclipse = lunar_eclipse(223).unwrap();
+/// let circumstances = lunar_eclipse_circumstances(&eclipse, ShadowEnlargement::Danjon)
+///     .unwrap();
+///
+/// // totality lasted 76 minutes
+/// let totality = circumstances.u3.unwrap() - circumstances.u2.unwrap();
+/// assert_eq!(76_f64, (totality * 1_440_f64).round());
+/// ```
+pub fn lunar_eclipse_circumstances(
+    eclipse: &LunarEclipse,
+    enlargement: ShadowEnlargement,
+) -> Option<LunarEclipseCircumstances> {
+    let geometry = |jde: f64| ShadowGeometry::new(jde, enlargement);
+
+    // golden section search for the least separation
+    let ratio = (5_f64.sqrt() - 1_f64) / 2_f64;
+    let (mut low, mut high) = (eclipse.jde - GREATEST_WINDOW, eclipse.jde + GREATEST_WINDOW);
+    for _ in 0..REFINEMENTS {
+        let lower = high - ratio * (high - low);
+        let upper = low + ratio * (high - low);
+
+        if geometry(lower).separation < geometry(upper).separation {
+            high = upper;
+        } else {
+            low = lower;
+        }
+    }
+    let greatest = (low + high) / 2_f64;
+    let at_greatest = geometry(greatest);
+
+    // the contacts, where the moon's limb touches the edge of a shadow
+    let contacts = |radius: &dyn Fn(&ShadowGeometry) -> f64| {
+        let f = |jde: f64| {
+            let values = geometry(jde);
+            values.separation - radius(&values)
+        };
+
+        (
+            bisect(f, greatest - CONTACT_WINDOW, greatest, REFINEMENTS),
+            bisect(f, greatest, greatest + CONTACT_WINDOW, REFINEMENTS),
+        )
+    };
+
+    let (p1, p4) = contacts(&|values| values.penumbral_radius + values.moon_semidiameter);
+    let (u1, u4) = contacts(&|values| values.umbral_radius + values.moon_semidiameter);
+    let (u2, u3) = contacts(&|values| values.umbral_radius - values.moon_semidiameter);
+
+    Some(LunarEclipseCircumstances {
+        p1: p1?,
+        u1,
+        u2,
+        greatest,
+        u3,
+        u4,
+        p4: p4?,
+        umbral_magnitude: at_greatest.umbral_magnitude(),
+        penumbral_magnitude: at_greatest.penumbral_magnitude(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use eclipse::*;
+    use time::nasa::jul_to_jule;
+    use time::dt_to_jul;
+
+    fn jde(hour: u32, minute: u32, second: u32) -> f64 {
+        let jd = dt_to_jul(&Utc.with_ymd_and_hms(2018, 1, 31, hour, minute, second).unwrap());
+        jul_to_jule(jd)
+    }
+
+    #[test]
+    fn total_eclipse_of_2018_january_31() {
+        // contacts and magnitudes from NASA's eclipse web site
+        let eclipse = lunar_eclipse(223).unwrap();
+        let circumstances = lunar_eclipse_circumstances(&eclipse, ShadowEnlargement::Danjon)
+            .unwrap();
+
+        let expected = [
+            (circumstances.p1, jde(10, 51, 15)),
+            (circumstances.u1.unwrap(), jde(11, 48, 27)),
+            (circumstances.u2.unwrap(), jde(12, 51, 47)),
+            (circumstances.greatest, jde(13, 29, 50)),
+            (circumstances.u3.unwrap(), jde(14, 7, 51)),
+            (circumstances.u4.unwrap(), jde(15, 11, 11)),
+            (circumstances.p4, jde(16, 8, 27)),
+        ];
+        for &(actual, expected) in expected.iter() {
+            assert!((actual - expected).abs() * 1_440_f64 < 1_f64, "{:?}", circumstances);
+        }
+
+        assert!((circumstances.umbral_magnitude - 1.315_5_f64).abs() < 0.005);
+        assert!((circumstances.penumbral_magnitude - 2.294_1_f64).abs() < 0.005);
+    }
+
+    #[test]
+    fn partial_and_penumbral_eclipses() {
+        // 2017 August 7, partial
+        let partial = lunar_eclipse_circumstances(
+            &lunar_eclipse(217).unwrap(),
+            ShadowEnlargement::Danjon,
+        ).unwrap();
+        assert!(partial.u1.is_some() && partial.u4.is_some());
+        assert!(partial.u2.is_none() && partial.u3.is_none());
+        assert!((partial.umbral_magnitude - 0.246_f64).abs() < 0.01);
+
+        // 2017 February 11, penumbral
+        let penumbral = lunar_eclipse_circumstances(
+            &lunar_eclipse(211).unwrap(),
+            ShadowEnlargement::Danjon,
+        ).unwrap();
+        assert!(penumbral.u1.is_none() && penumbral.u4.is_none());
+        assert!(penumbral.umbral_magnitude < 0_f64);
+        assert!((penumbral.penumbral_magnitude - 0.988_f64).abs() < 0.01);
+    }
+
+    #[test]
+    fn enlargement_widens_the_shadow() {
+        let eclipse = lunar_eclipse(223).unwrap();
+        let magnitudes: Vec<f64> = [
+            ShadowEnlargement::None,
+            ShadowEnlargement::Danjon,
+            ShadowEnlargement::Chauvenet,
+        ].iter()
+            .map(|&enlargement| {
+                lunar_eclipse_circumstances(&eclipse, enlargement)
+                    .unwrap()
+                    .umbral_magnitude
+            })
+            .collect();
+
+        assert!(magnitudes[0] < magnitudes[1] && magnitudes[1] < magnitudes[2]);
+    }
+}
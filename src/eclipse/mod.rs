@@ -0,0 +1,24 @@
+//! Prediction of solar and lunar eclipses
+mod prediction;
+mod besselian;
+mod local;
+mod path;
+mod lunar;
+#[cfg(feature = "geojson")]
+mod geojson;
+
+pub use self::prediction::*;
+pub use self::besselian::*;
+pub use self::local::*;
+pub use self::path::*;
+pub use self::lunar::*;
+
+/// Semidiameter of the sun at a distance of 1 AU, in radians (959.63")
+const SUN_SEMIDIAMETER: f64 = 0.004_652_42_f64;
+/// Mean radius of the moon, in equatorial radii of the earth (also used for
+/// the penumbra in the Besselian elements)
+const MOON_RADIUS: f64 = 0.272_507_6_f64;
+/// Radius of the moon used for the umbra in the Besselian elements, in
+/// equatorial radii of the earth (smaller than the mean radius, to allow for
+/// the valleys on the limb)
+const MOON_UMBRAL_RADIUS: f64 = 0.272_281_f64;
@@ -0,0 +1,403 @@
+use chrono::{DateTime, TimeZone};
+use eclipse::{BesselianElements, BesselianValues};
+use eclipse::local::{geocentric_coordinates, solve};
+use time::nasa::{jule_to_dt, jule_to_jul};
+use utils::angles::limit_signed_radians;
+
+/// Square of the eccentricity of the earth's meridian ellipse
+const EARTH_ECCENTRICITY_SQUARED: f64 = 0.006_694_38_f64;
+/// Iterations used to place a point on a limit
+const LIMIT_ITERATIONS: usize = 5;
+/// Most instants a path may be sampled at, which bounds the memory used by
+/// very small steps
+const MAX_SAMPLES: f64 = 100_000_f64;
+
+/// A point on the path of an eclipse
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PathPoint {
+    /// Julian Ephemeris Day at which the shadow reaches the point
+    pub jde: f64,
+    /// geodetic latitude, in radians
+    pub latitude: f64,
+    /// longitude, in radians east of Greenwich
+    pub longitude: f64,
+}
+
+impl PathPoint {
+    /// The Julian Day (UT) at which the shadow reaches the point
+    pub fn jul(&self) -> f64 {
+        jule_to_jul(self.jde)
+    }
+
+    /// The instant the shadow reaches the point in the given timezone, or
+    /// `None` if it can't be represented by `chrono`
+    pub fn dt<Tz: TimeZone>(&self, tz: &Tz) -> Option<DateTime<Tz>> {
+        jule_to_dt(self.jde, tz)
+    }
+}
+
+/// The path of a solar eclipse across the earth: its central line, and the
+/// northern and southern limits of the umbra (or antumbra) and penumbra
+///
+/// Each line is a sequence of points in time order; parts of a limit may be
+/// missing where it doesn't reach the earth, and the umbral limits and
+/// central line are empty for partial eclipses.
+///
+/// The path is traced from when the penumbra first touches the earth until
+/// it leaves. The Besselian elements are only fitted over three hours either
+/// side of their reference instant, so for the longest eclipses the ends of
+/// the path are extrapolated, and less accurate.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EclipsePath {
+    /// where the axis of the shadow meets the earth
+    pub central_line: Vec<PathPoint>,
+    /// northern limit of totality (or annularity)
+    pub northern_umbral_limit: Vec<PathPoint>,
+    /// southern limit of totality (or annularity)
+    pub southern_umbral_limit: Vec<PathPoint>,
+    /// northern limit of the partial eclipse
+    pub northern_penumbral_limit: Vec<PathPoint>,
+    /// southern limit of the partial eclipse
+    pub southern_penumbral_limit: Vec<PathPoint>,
+}
+
+/// The shadow axis's declination, adjusted for the earth's flattening, and
+/// the factors relating the fundamental plane to the ellipsoid
+struct EllipsoidFactors {
+    rho1: f64,
+    sin_d: f64,
+    cos_d: f64,
+    sin_d1: f64,
+    cos_d1: f64,
+}
+
+impl EllipsoidFactors {
+    fn new(d: f64) -> EllipsoidFactors {
+        let rho1 = (1_f64 - EARTH_ECCENTRICITY_SQUARED * d.cos().powi(2)).sqrt();
+
+        EllipsoidFactors {
+            rho1,
+            sin_d: d.sin(),
+            cos_d: d.cos(),
+            sin_d1: d.sin() / rho1,
+            cos_d1: (1_f64 - EARTH_ECCENTRICITY_SQUARED).sqrt() * d.cos() / rho1,
+        }
+    }
+}
+
+/// Finds the point on the earth with coordinates ξ and η in the fundamental
+/// plane (on the side facing the moon), returning it with its ζ
+/// coordinate, or `None` if the point is off the earth
+fn surface_point(
+    elements: &BesselianElements,
+    t: f64,
+    factors: &EllipsoidFactors,
+    xi: f64,
+    eta: f64,
+) -> Option<(PathPoint, f64)> {
+    let eta1 = eta / factors.rho1;
+    let zeta1_squared = 1_f64 - xi.powi(2) - eta1.powi(2);
+    if zeta1_squared < 0_f64 {
+        return None;
+    }
+    let zeta1 = zeta1_squared.sqrt();
+
+    let sin_latitude1 = eta1 * factors.cos_d1 + zeta1 * factors.sin_d1;
+    let hour_angle = xi.atan2(zeta1 * factors.cos_d1 - eta1 * factors.sin_d1);
+    let latitude = (sin_latitude1.asin().tan() / (1_f64 - EARTH_ECCENTRICITY_SQUARED).sqrt())
+        .atan();
+    let longitude = hour_angle - elements.greenwich_hour_angle(t);
+
+    let (rho_sin, rho_cos) = geocentric_coordinates(latitude);
+    let zeta = rho_sin * factors.sin_d + rho_cos * factors.cos_d * hour_angle.cos();
+
+    Some((
+        PathPoint {
+            jde: elements.jde_at(t),
+            latitude,
+            longitude: limit_signed_radians(longitude),
+        },
+        zeta,
+    ))
+}
+
+/// Finds the point on the central line at `t` hours after the reference
+/// instant
+fn central_point(elements: &BesselianElements, t: f64) -> Option<PathPoint> {
+    let values = elements.values(t);
+    let factors = EllipsoidFactors::new(values.d);
+
+    surface_point(elements, t, &factors, values.x, values.y).map(|(point, _)| point)
+}
+
+/// Finds the point on a limit at `t` hours after the reference instant,
+/// where the observer is at the edge of the shadow (with the radius given
+/// by `radius`, from the observer's ζ) at their maximum eclipse
+///
+/// `sign` is 1 for the northern limit and -1 for the southern.
+fn limit_point<R>(
+    elements: &BesselianElements,
+    t: f64,
+    sign: f64,
+    radius: R,
+) -> Option<PathPoint>
+where
+    R: Fn(&BesselianValues, f64) -> f64,
+{
+    let values = elements.values(t);
+    let factors = EllipsoidFactors::new(values.d);
+
+    let mut zeta = 0_f64;
+    let (mut xi, mut eta) = (values.x, values.y);
+    let mut point = None;
+
+    for _ in 0..LIMIT_ITERATIONS {
+        // motion of the shadow relative to an observer at (ξ, η, ζ)
+        let dxi = values.dmu * (zeta * values.d.cos() - eta * values.d.sin());
+        let deta = values.dmu * xi * values.d.sin() - zeta * values.dd;
+        let (a, b) = (values.dx - dxi, values.dy - deta);
+        let n = (a.powi(2) + b.powi(2)).sqrt();
+
+        // the limit is perpendicular to the relative motion, on the north
+        // side when (-b, a) points north
+        let l = radius(&values, zeta).abs() * sign * a.signum();
+        xi = values.x - l * b / n;
+        eta = values.y + l * a / n;
+
+        let (found, found_zeta) = surface_point(elements, t, &factors, xi, eta)?;
+        zeta = found_zeta;
+        point = Some(found);
+    }
+
+    point
+}
+
+/// Finds the instant, in hours after the reference instant, the penumbra
+/// first (`sign` of -1) or last (1) touches the earth, when the shadow axis
+/// is 1 + l1 from the centre of the earth in the fundamental plane
+fn penumbral_contact(elements: &BesselianElements, sign: f64) -> Option<f64> {
+    solve(0_f64, |t| {
+        let values = elements.values(t);
+        let n_squared = values.dx.powi(2) + values.dy.powi(2);
+        let n = n_squared.sqrt();
+        let l = 1_f64 + values.l1;
+        let s = (values.x * values.dy - values.y * values.dx) / (n * l);
+
+        if s.abs() > 1_f64 {
+            return None;
+        }
+
+        Some(
+            -(values.x * values.dx + values.y * values.dy) / n_squared +
+                sign * l / n * (1_f64 - s.powi(2)).sqrt(),
+        )
+    })
+}
+
+/// Calculates the central line and limits of a solar eclipse, sampled every
+/// `step` minutes (of TD) while the shadow is on the earth
+///
+/// Returns `None` if `step` isn't a positive, finite number of minutes, if
+/// it would sample the path at more than 100 000 instants (a step of a few
+/// thousandths of a minute for the longest eclipses), or if the penumbra
+/// misses the earth.
+///
+/// Uses the method from the Explanatory Supplement to the Astronomical
+/// Almanac, on the earth's ellipsoid, ignoring the observer's height.
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::eclipse::{eclipse_path, solar_eclipse, BesselianElements};
+///
+/// // 2017 August 21
+/// let elements = BesselianElements::new(&solar_eclipse(218).unwrap());
+/// let path = eclipse_path(&elements, 1_f64).unwrap();
+///
+/// // the central line began in the Pacific and ended in the Atlantic
+/// let first = path.central_line.first().unwrap();
+/// let last = path.central_line.last().unwrap();
+/// assert!(first.longitude.to_degrees() < -140_f64);
+/// assert!(last.longitude.to_degrees() > -45_f64);
+/// ```
+pub fn eclipse_path(elements: &BesselianElements, step: f64) -> Option<EclipsePath> {
+    if !step.is_finite() || step <= 0_f64 {
+        return None;
+    }
+
+    let first = penumbral_contact(elements, -1_f64)?;
+    let last = penumbral_contact(elements, 1_f64)?;
+    let samples = (last - first) * 60_f64 / step;
+    if samples > MAX_SAMPLES {
+        return None;
+    }
+
+    let steps = samples.floor() as i64;
+    let times: Vec<f64> = (0..steps + 1)
+        .map(|index| first + index as f64 * step / 60_f64)
+        .collect();
+
+    let umbra = |values: &BesselianValues, zeta: f64| values.l2 - zeta * elements.tan_f2;
+    let penumbra = |values: &BesselianValues, zeta: f64| values.l1 - zeta * elements.tan_f1;
+    let limit = |sign: f64, radius: &dyn Fn(&BesselianValues, f64) -> f64| {
+        times
+            .iter()
+            .filter_map(|&t| limit_point(elements, t, sign, radius))
+            .collect::<Vec<PathPoint>>()
+    };
+
+    let central_line: Vec<PathPoint> = times
+        .iter()
+        .filter_map(|&t| central_point(elements, t))
+        .collect();
+
+    Some(EclipsePath {
+        central_line,
+        northern_umbral_limit: limit(1_f64, &umbra),
+        southern_umbral_limit: limit(-1_f64, &umbra),
+        northern_penumbral_limit: limit(1_f64, &penumbra),
+        southern_penumbral_limit: limit(-1_f64, &penumbra),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use eclipse::*;
+    use time::nasa::jule_to_jul;
+    use utils::coordinates::Observer;
+
+    fn observer(point: &PathPoint) -> Observer {
+        Observer {
+            latitude: point.latitude,
+            longitude: point.longitude,
+        }
+    }
+
+    #[test]
+    fn greatest_eclipse_of_2017() {
+        let eclipse = solar_eclipse(218).unwrap();
+        let elements = BesselianElements::new(&eclipse);
+        let path = eclipse_path(&elements, 1_f64).unwrap();
+
+        // greatest eclipse was near Hopkinsville, Kentucky (36.97°N,
+        // 87.67°W)
+        let nearest = path.central_line
+            .iter()
+            .min_by(|a, b| {
+                (a.jde - eclipse.jde).abs().partial_cmp(&(b.jde - eclipse.jde).abs()).unwrap()
+            })
+            .unwrap();
+        assert!((nearest.latitude.to_degrees() - 36.97_f64).abs() < 0.2, "{:?}", nearest);
+        assert!((nearest.longitude.to_degrees() + 87.67_f64).abs() < 0.3, "{:?}", nearest);
+    }
+
+    #[test]
+    fn points_are_consistent_with_local_circumstances() {
+        let elements = BesselianElements::new(&solar_eclipse(218).unwrap());
+        let path = eclipse_path(&elements, 10_f64).unwrap();
+
+        assert!(!path.central_line.is_empty());
+        for point in path.central_line.iter().filter(|point| point.latitude > 0.5) {
+            let local = local_circumstances(&elements, &observer(point)).unwrap();
+            assert_eq!(SolarEclipseType::Total, local.eclipse_type);
+            assert!((local.maximum.jde - point.jde).abs() < 0.000_1);
+        }
+
+        // the magnitude is exactly 1 at the limits of totality
+        let umbral_limits: Vec<&PathPoint> = path.northern_umbral_limit
+            .iter()
+            .chain(path.southern_umbral_limit.iter())
+            .collect();
+        assert!(umbral_limits.len() > 30);
+        for point in umbral_limits {
+            let local = local_circumstances(&elements, &observer(point)).unwrap();
+            assert!((local.magnitude - 1_f64).abs() < 0.000_01, "{:?}", local);
+        }
+
+        // and the eclipse is barely partial at the limits of the penumbra
+        for point in path.northern_penumbral_limit
+            .iter()
+            .chain(path.southern_penumbral_limit.iter())
+        {
+            if let Some(local) = local_circumstances(&elements, &observer(point)) {
+                assert!(local.magnitude < 0.000_01, "{:?}", local);
+            }
+        }
+    }
+
+    #[test]
+    fn limits_straddle_the_central_line() {
+        let elements = BesselianElements::new(&solar_eclipse(218).unwrap());
+        let path = eclipse_path(&elements, 10_f64).unwrap();
+
+        for central in path.central_line.iter() {
+            let matching = |limit: &Vec<PathPoint>| {
+                limit.iter().find(|point| (point.jde - central.jde).abs() < 1e-9).cloned()
+            };
+
+            if let (Some(north), Some(south)) = (
+                matching(&path.northern_umbral_limit),
+                matching(&path.southern_umbral_limit),
+            ) {
+                assert!(north.latitude > central.latitude);
+                assert!(south.latitude < central.latitude);
+                // the path of totality was about 110 km wide
+                assert!((north.latitude - south.latitude).to_degrees() < 2_f64);
+            }
+        }
+    }
+
+    #[test]
+    fn penumbral_contacts_of_2017() {
+        // the partial phases of 2017 August 21 began at 15h46m48s UT and
+        // ended at 21h04m18s UT, beyond the three hours fitted either side
+        // of 18h TD
+        let elements = BesselianElements::new(&solar_eclipse(218).unwrap());
+        let contact = |sign: f64| {
+            let t = super::penumbral_contact(&elements, sign).unwrap();
+            (jule_to_jul(elements.jde_at(t)) - 2_457_986.5_f64) * 24_f64
+        };
+
+        assert!((contact(-1_f64) - 15.78_f64).abs() < 0.01, "{}", contact(-1_f64));
+        assert!((contact(1_f64) - 21.071_7_f64).abs() < 0.01, "{}", contact(1_f64));
+    }
+
+    #[test]
+    fn rejects_invalid_steps() {
+        let elements = BesselianElements::new(&solar_eclipse(218).unwrap());
+
+        for &step in &[0_f64, -1_f64, f64::NAN, f64::INFINITY, 1e-9_f64] {
+            assert_eq!(None, eclipse_path(&elements, step));
+        }
+    }
+
+    #[test]
+    fn non_central_eclipses_have_umbral_limits() {
+        // the annular eclipse of 2014 April 29 grazed Antarctica near 131°E,
+        // without the axis of the shadow touching the earth
+        let elements = BesselianElements::new(&solar_eclipse(177).unwrap());
+        let path = eclipse_path(&elements, 1_f64).unwrap();
+
+        assert!(path.central_line.is_empty());
+        let umbral_limits: Vec<&PathPoint> = path.northern_umbral_limit
+            .iter()
+            .chain(path.southern_umbral_limit.iter())
+            .collect();
+        assert!(!umbral_limits.is_empty());
+        for point in umbral_limits {
+            assert!(point.latitude.to_degrees() < -65_f64, "{:?}", point);
+            assert!((point.longitude.to_degrees() - 129_f64).abs() < 10_f64, "{:?}", point);
+        }
+    }
+
+    #[test]
+    fn partial_eclipses_have_no_central_line() {
+        let elements = BesselianElements::new(&solar_eclipse(224).unwrap());
+        let path = eclipse_path(&elements, 10_f64).unwrap();
+
+        assert!(path.central_line.is_empty());
+        assert!(path.northern_umbral_limit.is_empty());
+        assert!(!path.southern_penumbral_limit.is_empty() ||
+            !path.northern_penumbral_limit.is_empty());
+    }
+}
@@ -0,0 +1,446 @@
+use chrono::{DateTime, TimeZone};
+use earth;
+use eclipse::{MOON_RADIUS, SUN_SEMIDIAMETER};
+use moon::{self, LunationArguments, Phase, PhaseInstant, Phases};
+use sun;
+use time::jul_to_julc;
+use time::nasa::{jule_to_dt, jule_to_jul};
+
+/// The type of a solar eclipse
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SolarEclipseType {
+    /// The moon completely covers the sun
+    Total,
+    /// The moon is too small to cover the sun, leaving a ring visible
+    Annular,
+    /// Total along some of the central line, and annular along the rest
+    Hybrid,
+    /// Only the moon's penumbra touches the earth
+    Partial,
+}
+
+/// The type of a lunar eclipse
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum LunarEclipseType {
+    /// The moon is entirely within the earth's umbra
+    Total,
+    /// The moon is partly within the earth's umbra
+    Partial,
+    /// The moon only enters the earth's penumbra
+    Penumbral,
+}
+
+/// A solar eclipse
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SolarEclipse {
+    /// the type of eclipse
+    pub eclipse_type: SolarEclipseType,
+    /// whether the axis of the moon's shadow touches the earth
+    pub central: bool,
+    /// lunation number of the new moon (see `moon::phase_jule`)
+    pub lunation: i64,
+    /// Julian Ephemeris Day of greatest eclipse
+    pub jde: f64,
+    /// least distance of the shadow axis from the centre of the earth (γ),
+    /// in equatorial radii of the earth; positive when the axis passes
+    /// north of the centre
+    pub gamma: f64,
+    /// radius of the umbral cone in the fundamental plane (u), in
+    /// equatorial radii of the earth
+    pub u: f64,
+    /// magnitude at greatest eclipse: for partial eclipses, the fraction of
+    /// the sun's diameter covered, otherwise the ratio of the apparent
+    /// diameters of the moon and sun
+    pub magnitude: f64,
+}
+
+/// A lunar eclipse
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LunarEclipse {
+    /// the type of eclipse
+    pub eclipse_type: LunarEclipseType,
+    /// lunation number of the full moon (see `moon::phase_jule`)
+    pub lunation: i64,
+    /// Julian Ephemeris Day of greatest eclipse
+    pub jde: f64,
+    /// least distance of the moon's centre from the axis of the earth's
+    /// shadow (γ), in equatorial radii of the earth; positive when the moon
+    /// passes north of the axis
+    pub gamma: f64,
+    /// radius of the moon's umbral cone in the fundamental plane (u), in
+    /// equatorial radii of the earth
+    pub u: f64,
+    /// fraction of the moon's diameter within the umbra at greatest eclipse
+    /// (negative if the moon misses the umbra)
+    pub umbral_magnitude: f64,
+    /// fraction of the moon's diameter within the penumbra at greatest
+    /// eclipse
+    pub penumbral_magnitude: f64,
+}
+
+/// A solar or lunar eclipse
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Eclipse {
+    /// A solar eclipse, at new moon
+    Solar(SolarEclipse),
+    /// A lunar eclipse, at full moon
+    Lunar(LunarEclipse),
+}
+
+impl Eclipse {
+    /// The Julian Ephemeris Day of greatest eclipse
+    pub fn jde(&self) -> f64 {
+        match *self {
+            Eclipse::Solar(ref eclipse) => eclipse.jde,
+            Eclipse::Lunar(ref eclipse) => eclipse.jde,
+        }
+    }
+
+    /// The Julian Day (UT) of greatest eclipse
+    pub fn jul(&self) -> f64 {
+        jule_to_jul(self.jde())
+    }
+
+    /// The instant of greatest eclipse in the given timezone, or `None` if
+    /// it can't be represented by `chrono`
+    pub fn dt<Tz: TimeZone>(&self, tz: &Tz) -> Option<DateTime<Tz>> {
+        jule_to_dt(self.jde(), tz)
+    }
+
+    /// γ, the least distance between the axis of the shadow and the centre
+    /// of the earth (solar) or moon (lunar), in equatorial radii of the
+    /// earth
+    pub fn gamma(&self) -> f64 {
+        match *self {
+            Eclipse::Solar(ref eclipse) => eclipse.gamma,
+            Eclipse::Lunar(ref eclipse) => eclipse.gamma,
+        }
+    }
+}
+
+/// The quantities from Meeus (ch. 54) that describe an eclipse: the
+/// instant of greatest eclipse, γ and u
+struct EclipseQuantities {
+    jde: f64,
+    gamma: f64,
+    u: f64,
+}
+
+/// Calculates the instant of greatest eclipse, γ and u at the new or full
+/// moon `k` lunations after the new moon of 2000 January 6, or `None` if
+/// the moon is too far from a node for an eclipse to be possible
+fn eclipse_quantities(k: f64) -> Option<EclipseQuantities> {
+    let t = k / 1_236.85_f64;
+
+    let LunationArguments {
+        eccentricity: e,
+        m,
+        m_prime,
+        f,
+        omega,
+    } = moon::lunation_arguments(k);
+    if f.sin().abs() > 0.36_f64 {
+        return None;
+    }
+
+    let mean = moon::mean_phase_jule(k);
+    let f1 = f - 0.026_65_f64.to_radians() * omega.sin();
+    let a1 = (299.77_f64 + 0.107_408_f64 * k - 0.009_173_f64 * t.powi(2)).to_radians();
+
+    let (anomaly_term, sun_anomaly_term) = if k.fract() == 0_f64 {
+        (-0.407_5_f64, 0.172_1_f64)
+    } else {
+        (-0.406_5_f64, 0.172_7_f64)
+    };
+    let jde = mean + anomaly_term * m_prime.sin() + sun_anomaly_term * e * m.sin() +
+        0.016_1_f64 * (2_f64 * m_prime).sin() - 0.009_7_f64 * (2_f64 * f1).sin() +
+        0.007_3_f64 * e * (m_prime - m).sin() - 0.005_f64 * e * (m_prime + m).sin() -
+        0.002_3_f64 * (m_prime - 2_f64 * f1).sin() + 0.002_1_f64 * e * (2_f64 * m).sin() +
+        0.001_2_f64 * (m_prime + 2_f64 * f1).sin() +
+        0.000_6_f64 * e * (2_f64 * m_prime + m).sin() - 0.000_4_f64 * (3_f64 * m_prime).sin() -
+        0.000_3_f64 * e * (m + 2_f64 * f1).sin() + 0.000_3_f64 * a1.sin() -
+        0.000_2_f64 * e * (m - 2_f64 * f1).sin() -
+        0.000_2_f64 * e * (2_f64 * m_prime - m).sin() - 0.000_2_f64 * omega.sin();
+
+    let p = 0.207_f64 * e * m.sin() + 0.002_4_f64 * e * (2_f64 * m).sin() -
+        0.039_2_f64 * m_prime.sin() + 0.011_6_f64 * (2_f64 * m_prime).sin() -
+        0.007_3_f64 * e * (m_prime + m).sin() + 0.006_7_f64 * e * (m_prime - m).sin() +
+        0.011_8_f64 * (2_f64 * f1).sin();
+    let q = 5.220_7_f64 - 0.004_8_f64 * e * m.cos() + 0.002_f64 * e * (2_f64 * m).cos() -
+        0.329_9_f64 * m_prime.cos() - 0.006_f64 * e * (m_prime + m).cos() +
+        0.004_1_f64 * e * (m_prime - m).cos();
+    let w = f1.cos().abs();
+
+    let gamma = (p * f1.cos() + q * f1.sin()) * (1_f64 - 0.004_8_f64 * w);
+    let u = 0.005_9_f64 + 0.004_6_f64 * e * m.cos() - 0.018_2_f64 * m_prime.cos() +
+        0.000_4_f64 * (2_f64 * m_prime).cos() - 0.000_5_f64 * (m + m_prime).cos();
+
+    Some(EclipseQuantities { jde, gamma, u })
+}
+
+/// Calculates the ratio of the apparent diameters of the moon and sun, as
+/// seen from where the shadow axis is nearest the centre of the earth
+fn central_magnitude(jde: f64, gamma: f64) -> f64 {
+    let axis_height = (1_f64 - gamma.powi(2)).max(0_f64).sqrt() * earth::EQUATORIAL_RADIUS;
+    let moon_distance = moon::distance(jul_to_julc(jde)) - axis_height;
+    let moon_semidiameter = (MOON_RADIUS * earth::EQUATORIAL_RADIUS / moon_distance).asin();
+
+    moon_semidiameter / (SUN_SEMIDIAMETER / sun::distance(jde))
+}
+
+/// Determines whether there is a solar eclipse at a new moon, and if so its
+/// type, instant of greatest eclipse, γ and magnitude
+///
+/// Lunations are numbered from the new moon of 2000 January 6 (see
+/// `moon::phase_jule`). Uses the method from Meeus (ch. 54), with the
+/// magnitudes of central eclipses from the apparent diameters of the moon
+/// and sun.
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::eclipse::{solar_eclipse, SolarEclipseType};
+///
+/// // Meeus example 54.a: 1993 May 21
+/// let eclipse = solar_eclipse(-82).unwrap();
+/// assert_eq!(SolarEclipseType::Partial, eclipse.eclipse_type);
+/// assert_eq!(1.134_8, (eclipse.gamma * 10_000_f64).round() / 10_000_f64);
+/// assert_eq!(0.740, (eclipse.magnitude * 1_000_f64).round() / 1_000_f64);
+/// ```
+pub fn solar_eclipse(lunation: i64) -> Option<SolarEclipse> {
+    let EclipseQuantities { jde, gamma, u } = eclipse_quantities(lunation as f64)?;
+    let distance = gamma.abs();
+
+    if distance > 1.543_3_f64 + u {
+        return None;
+    }
+
+    let central = distance < 0.997_2_f64;
+    let eclipse_type = if central {
+        if u < 0_f64 {
+            SolarEclipseType::Total
+        } else if u > 0.004_7_f64 || u >= 0.004_64_f64 * (1_f64 - gamma.powi(2)).sqrt() {
+            SolarEclipseType::Annular
+        } else {
+            SolarEclipseType::Hybrid
+        }
+    } else if distance < 0.997_2_f64 + u.abs() {
+        // a non-central total or annular eclipse, where the edge of the
+        // umbral (or antumbral) cone touches the earth near a pole
+        if u < 0_f64 {
+            SolarEclipseType::Total
+        } else {
+            SolarEclipseType::Annular
+        }
+    } else {
+        SolarEclipseType::Partial
+    };
+
+    let magnitude = if eclipse_type == SolarEclipseType::Partial {
+        (1.543_3_f64 + u - distance) / (0.546_1_f64 + 2_f64 * u)
+    } else {
+        central_magnitude(jde, gamma)
+    };
+
+    Some(SolarEclipse {
+        eclipse_type,
+        central,
+        lunation,
+        jde,
+        gamma,
+        u,
+        magnitude,
+    })
+}
+
+/// Determines whether there is a lunar eclipse at the full moon of a
+/// lunation, and if so its type, instant of greatest eclipse, γ and
+/// magnitudes
+///
+/// Lunations are numbered from the new moon of 2000 January 6 (see
+/// `moon::phase_jule`). Uses the method from Meeus (ch. 54).
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::eclipse::{lunar_eclipse, LunarEclipseType};
+///
+/// // Meeus example 54.b: 1973 June 15
+/// let eclipse = lunar_eclipse(-329).unwrap();
+/// assert_eq!(LunarEclipseType::Penumbral, eclipse.eclipse_type);
+/// assert_eq!(0.46, (eclipse.penumbral_magnitude * 100_f64).round() / 100_f64);
+/// ```
+pub fn lunar_eclipse(lunation: i64) -> Option<LunarEclipse> {
+    let EclipseQuantities { jde, gamma, u } = eclipse_quantities(lunation as f64 + 0.5_f64)?;
+    let distance = gamma.abs();
+
+    let penumbral_magnitude = (1.557_3_f64 + u - distance) / 0.545_f64;
+    let umbral_magnitude = (1.012_8_f64 - u - distance) / 0.545_f64;
+
+    let eclipse_type = if penumbral_magnitude <= 0_f64 {
+        return None;
+    } else if umbral_magnitude <= 0_f64 {
+        LunarEclipseType::Penumbral
+    } else if umbral_magnitude < 1_f64 {
+        LunarEclipseType::Partial
+    } else {
+        LunarEclipseType::Total
+    };
+
+    Some(LunarEclipse {
+        eclipse_type,
+        lunation,
+        jde,
+        gamma,
+        u,
+        umbral_magnitude,
+        penumbral_magnitude,
+    })
+}
+
+/// Determines whether there is an eclipse at a moon phase: a solar eclipse
+/// at new moon, or a lunar eclipse at full moon
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::eclipse::{eclipse, Eclipse, SolarEclipseType};
+/// use meealgi::moon::{nearest_phase, Phase};
+///
+/// // the new moon of 2017 August 21
+/// let new_moon = nearest_phase(2_457_986.5_f64, Phase::NewMoon);
+/// match eclipse(&new_moon) {
+///     Some(Eclipse::Solar(solar)) => assert_eq!(SolarEclipseType::Total, solar.eclipse_type),
+///     _ => panic!("expected a solar eclipse"),
+/// }
+/// ```
+pub fn eclipse(instant: &PhaseInstant) -> Option<Eclipse> {
+    match instant.phase {
+        Phase::NewMoon => solar_eclipse(instant.lunation).map(Eclipse::Solar),
+        Phase::FullMoon => lunar_eclipse(instant.lunation).map(Eclipse::Lunar),
+        Phase::FirstQuarter | Phase::LastQuarter => None,
+    }
+}
+
+/// An iterator over the solar and lunar eclipses between two instants, in
+/// order
+#[derive(Debug, Clone)]
+pub struct Eclipses {
+    phases: Phases,
+}
+
+impl Iterator for Eclipses {
+    type Item = Eclipse;
+
+    fn next(&mut self) -> Option<Eclipse> {
+        loop {
+            if let Some(eclipse) = eclipse(&self.phases.next()?) {
+                return Some(eclipse);
+            }
+        }
+    }
+}
+
+/// Iterates over the solar and lunar eclipses from one Julian Day (UT,
+/// inclusive) to another (exclusive)
+///
+/// Eclipses are included by the instant of the new or full moon, which is
+/// within a few hours of greatest eclipse.
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::eclipse::{eclipses, Eclipse};
+///
+/// // 2017 had two solar and two lunar eclipses
+/// let all: Vec<Eclipse> = eclipses(2_457_754.5_f64, 2_458_119.5_f64).collect();
+/// assert_eq!(4, all.len());
+/// ```
+pub fn eclipses(start_jd: f64, end_jd: f64) -> Eclipses {
+    Eclipses { phases: moon::phases(start_jd, end_jd) }
+}
+
+#[cfg(test)]
+mod tests {
+    use eclipse::*;
+
+    #[test]
+    fn meeus_examples() {
+        // example 54.a: 1993 May 21, 14h TD
+        let solar = solar_eclipse(-82).unwrap();
+        assert!((solar.jde - 2_449_129.097_9_f64).abs() < 0.000_1);
+        assert!((solar.gamma - 1.134_8_f64).abs() < 0.000_1);
+        assert!((solar.u - 0.009_7_f64).abs() < 0.000_1);
+        assert!(!solar.central);
+
+        // example 54.b: 1973 June 15, 20h TD
+        let lunar = lunar_eclipse(-329).unwrap();
+        assert!((lunar.jde - 2_441_849.368_7_f64).abs() < 0.000_1);
+        assert!((lunar.gamma + 1.324_9_f64).abs() < 0.000_1);
+        assert!((lunar.penumbral_magnitude - 0.462_f64).abs() < 0.001);
+        assert!(lunar.umbral_magnitude < 0_f64);
+    }
+
+    #[test]
+    fn solar_eclipse_types() {
+        // types and γ from NASA's Five Millennium Canon of Solar Eclipses
+        let expected = [
+            (212, SolarEclipseType::Annular, -0.457_8_f64, 0.992_f64),
+            (218, SolarEclipseType::Total, 0.436_7_f64, 1.031_f64),
+            (224, SolarEclipseType::Partial, -1.211_7_f64, 0.599_f64),
+            (288, SolarEclipseType::Hybrid, -0.395_2_f64, 1.013_f64),
+        ];
+
+        for &(lunation, eclipse_type, gamma, magnitude) in expected.iter() {
+            let eclipse = solar_eclipse(lunation).unwrap();
+
+            assert_eq!(eclipse_type, eclipse.eclipse_type);
+            assert!((eclipse.gamma - gamma).abs() < 0.003, "{:?}", eclipse);
+            assert!((eclipse.magnitude - magnitude).abs() < 0.003, "{:?}", eclipse);
+        }
+    }
+
+    #[test]
+    fn lunar_eclipse_types() {
+        // types and magnitudes from NASA's Five Millennium Canon of Lunar
+        // Eclipses
+        let expected = [
+            (211, LunarEclipseType::Penumbral, 0.988_f64, -0.035_f64),
+            (217, LunarEclipseType::Partial, 1.289_f64, 0.246_f64),
+            (223, LunarEclipseType::Total, 2.294_f64, 1.316_f64),
+        ];
+
+        for &(lunation, eclipse_type, penumbral, umbral) in expected.iter() {
+            let eclipse = lunar_eclipse(lunation).unwrap();
+
+            assert_eq!(eclipse_type, eclipse.eclipse_type);
+            assert!((eclipse.penumbral_magnitude - penumbral).abs() < 0.02, "{:?}", eclipse);
+            assert!((eclipse.umbral_magnitude - umbral).abs() < 0.02, "{:?}", eclipse);
+        }
+    }
+
+    #[test]
+    fn no_eclipse_away_from_nodes() {
+        // the new and full moons of 2017 October
+        assert!(solar_eclipse(220).is_none());
+        assert!(lunar_eclipse(219).is_none());
+    }
+
+    #[test]
+    fn eclipses_per_decade() {
+        let all: Vec<Eclipse> = eclipses(2_451_545_f64, 2_451_545_f64 + 3_652.5_f64).collect();
+        let solar = all.iter()
+            .filter(|eclipse| match **eclipse {
+                Eclipse::Solar(_) => true,
+                Eclipse::Lunar(_) => false,
+            })
+            .count();
+
+        // 2000 to 2009 had 22 solar and 24 lunar eclipses
+        assert_eq!(22, solar);
+        assert_eq!(24, all.len() - solar);
+        for pair in all.windows(2) {
+            assert!(pair[0].jde() < pair[1].jde());
+        }
+    }
+}
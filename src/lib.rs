@@ -1,5 +1,5 @@
 #![warn(missing_docs)]
-#![cfg_attr(feature="cargo-clippy", deny(clippy))]
+#![deny(clippy::all)]
 
 //! Implementation of a selection of Jean Meeus' astronomy algorithms.
 //!
@@ -8,11 +8,19 @@
 //! * "Astronomical Algorithms, Second Edition" by Jean Meeus
 
 extern crate chrono;
+#[cfg(feature = "chrono-tz")]
+extern crate chrono_tz;
+#[cfg(feature = "geojson")]
+#[macro_use]
+extern crate serde_json;
 
 #[cfg(test)]
-#[macro_use]
 extern crate spectral;
 
 pub mod time;
 pub mod earth;
 pub mod utils;
+pub mod sun;
+pub mod moon;
+pub mod eclipse;
+pub mod planet;
@@ -0,0 +1,263 @@
+use chrono::{DateTime, TimeZone};
+use moon::parallax_distance;
+use moon::periodic_terms::{self, MoonApsisTableRow};
+use time::nasa::{jule_to_dt, jule_to_jul};
+use utils::cycles::{nearest_event, CycleEvents};
+
+/// Julian Ephemeris Day of the mean perigee of 1999 December 22 (anomalistic
+/// month 0)
+const APSIS_EPOCH: f64 = 2_451_534.669_8_f64;
+/// Mean length of an anomalistic month, in days
+const ANOMALISTIC_MONTH: f64 = 27.554_549_89_f64;
+
+/// The point in the moon's orbit nearest to, or furthest from, the earth
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Apsis {
+    /// Perigee, when the moon is nearest to the earth
+    Perigee,
+    /// Apogee, when the moon is furthest from the earth
+    Apogee,
+}
+
+impl Apsis {
+    /// Both of the apsides, in the order they occur during an anomalistic
+    /// month
+    pub const ALL: [Apsis; 2] = [Apsis::Perigee, Apsis::Apogee];
+
+    /// The fraction of an anomalistic month at which the apsis occurs
+    pub fn fraction(self) -> f64 {
+        match self {
+            Apsis::Perigee => 0_f64,
+            Apsis::Apogee => 0.5_f64,
+        }
+    }
+}
+
+/// The instant of, and the moon's distance at, a perigee or apogee
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ApsisInstant {
+    /// the apsis
+    pub apsis: Apsis,
+    /// anomalistic month number, counting from the perigee of 1999
+    /// December 22 (Meeus' k, without the fraction for the apsis)
+    pub month: i64,
+    /// Julian Ephemeris Day of the apsis
+    pub jde: f64,
+    /// the moon's equatorial horizontal parallax at the apsis, in radians
+    pub parallax: f64,
+}
+
+impl ApsisInstant {
+    /// Calculates the instant of an apsis during an anomalistic month
+    pub fn new(month: i64, apsis: Apsis) -> ApsisInstant {
+        let (jde, parallax) = apsis_jule_parallax(month, apsis);
+
+        ApsisInstant {
+            apsis,
+            month,
+            jde,
+            parallax,
+        }
+    }
+
+    /// The Julian Day (UT) of the apsis
+    pub fn jul(&self) -> f64 {
+        jule_to_jul(self.jde)
+    }
+
+    /// The instant of the apsis in the given timezone, or `None` if it
+    /// can't be represented by `chrono`
+    pub fn dt<Tz: TimeZone>(&self, tz: &Tz) -> Option<DateTime<Tz>> {
+        jule_to_dt(self.jde, tz)
+    }
+
+    /// The distance between the centres of the earth and moon at the apsis,
+    /// in km
+    pub fn distance(&self) -> f64 {
+        parallax_distance(self.parallax)
+    }
+}
+
+fn sum_apsis_terms<F: Fn(f64) -> f64>(
+    terms: &[MoonApsisTableRow],
+    args: &[f64; 3],
+    t: f64,
+    function: F,
+) -> f64 {
+    terms.iter().fold(0_f64, |curr, row| {
+        let argument: f64 = row.args
+            .iter()
+            .zip(args.iter())
+            .map(|(multiple, arg)| f64::from(*multiple) * arg)
+            .sum();
+
+        curr + (row.coefficient + row.t * t) * function(argument)
+    })
+}
+
+/// Calculates the Julian Ephemeris Day of, and the moon's equatorial
+/// horizontal parallax (in radians) at, an apsis
+fn apsis_jule_parallax(month: i64, apsis: Apsis) -> (f64, f64) {
+    let k = month as f64 + apsis.fraction();
+    let t = k / 1_325.55_f64;
+
+    let mean = APSIS_EPOCH + ANOMALISTIC_MONTH * k - 0.000_669_1_f64 * t.powi(2) -
+        0.000_001_098_f64 * t.powi(3) + 0.000_000_005_2_f64 * t.powi(4);
+
+    let d = (171.917_9_f64 + 335.910_604_6_f64 * k - 0.010_038_3_f64 * t.powi(2) -
+                 0.000_011_56_f64 * t.powi(3) + 0.000_000_055_f64 * t.powi(4))
+        .to_radians();
+    let m = (347.347_7_f64 + 27.157_772_1_f64 * k - 0.000_813_f64 * t.powi(2) -
+                 0.000_001_f64 * t.powi(3))
+        .to_radians();
+    let f = (316.610_9_f64 + 364.528_791_1_f64 * k - 0.012_505_3_f64 * t.powi(2) -
+                 0.000_014_8_f64 * t.powi(3))
+        .to_radians();
+    let args = [d, m, f];
+
+    let (time_terms, parallax_terms) = match apsis {
+        Apsis::Perigee => (
+            periodic_terms::PERIGEE_TERMS,
+            periodic_terms::PERIGEE_PARALLAX_TERMS,
+        ),
+        Apsis::Apogee => (
+            periodic_terms::APOGEE_TERMS,
+            periodic_terms::APOGEE_PARALLAX_TERMS,
+        ),
+    };
+
+    let jde = mean + sum_apsis_terms(time_terms, &args, t, f64::sin);
+    let parallax = sum_apsis_terms(parallax_terms, &args, t, f64::cos) / 3_600_f64;
+
+    (jde, parallax.to_radians())
+}
+
+/// Calculates the Julian Ephemeris Day of an apsis during an anomalistic
+/// month
+///
+/// Anomalistic months are numbered from the perigee of 1999 December 22.
+/// Uses the method from Meeus (ch. 50), accurate to within about half an
+/// hour for perigees and a few minutes for apogees.
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::moon::{apsis_jule, Apsis};
+///
+/// // Meeus example 50.a: 1988 October 7, 20h30m TD
+/// let jde = apsis_jule(-149, Apsis::Apogee);
+/// assert_eq!(2_447_442.354_3, (jde * 10_000_f64).round() / 10_000_f64);
+/// ```
+pub fn apsis_jule(month: i64, apsis: Apsis) -> f64 {
+    apsis_jule_parallax(month, apsis).0
+}
+
+/// Finds the instant of an apsis nearest to a Julian Day (UT)
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::moon::{nearest_apsis, Apsis};
+///
+/// // the "supermoon" of 2016 November 14
+/// let perigee = nearest_apsis(2_457_706.5_f64, Apsis::Perigee);
+/// assert_eq!(356_511, perigee.distance().round() as i64);
+/// ```
+pub fn nearest_apsis(jd: f64, apsis: Apsis) -> ApsisInstant {
+    let estimate = ((jd - APSIS_EPOCH) / ANOMALISTIC_MONTH - apsis.fraction()).round() as i64;
+
+    nearest_event(estimate, jd, |month| ApsisInstant::new(month, apsis), |instant| instant.jde)
+}
+
+/// An iterator over the lunar perigees and apogees between two instants, in
+/// order
+#[derive(Debug, Clone)]
+pub struct Apsides {
+    events: CycleEvents<Apsis>,
+}
+
+impl Iterator for Apsides {
+    type Item = ApsisInstant;
+
+    fn next(&mut self) -> Option<ApsisInstant> {
+        self.events.next_event(ApsisInstant::new, |instant| instant.jde)
+    }
+}
+
+/// Iterates over the lunar perigees and apogees from one Julian Day (UT,
+/// inclusive) to another (exclusive)
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::moon::{apsides, Apsis};
+///
+/// // the closest perigee of 2017 was on May 26
+/// let closest = apsides(2_457_754.5_f64, 2_458_119.5_f64)
+///     .filter(|instant| instant.apsis == Apsis::Perigee)
+///     .min_by_key(|instant| instant.distance().round() as i64)
+///     .unwrap();
+/// assert_eq!(357_209, closest.distance().round() as i64);
+/// ```
+pub fn apsides(start_jd: f64, end_jd: f64) -> Apsides {
+    let month = ((start_jd - APSIS_EPOCH) / ANOMALISTIC_MONTH).floor() as i64 - 1;
+
+    Apsides { events: CycleEvents::new(&Apsis::ALL, month, start_jd, end_jd) }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use moon::*;
+    use time::dt_to_jul;
+
+    #[test]
+    fn meeus_example() {
+        // example 50.a: 1988 October 7, 20h30m TD, parallax 3240.679"
+        let apogee = ApsisInstant::new(-149, Apsis::Apogee);
+
+        assert!((apogee.jde - 2_447_442.354_3_f64).abs() < 0.000_1);
+        assert!((apogee.parallax.to_degrees() * 3_600_f64 - 3_240.679_f64).abs() < 0.001);
+        assert!((apogee.distance() - 405_977_f64).abs() < 1_f64);
+    }
+
+    #[test]
+    fn apsides_of_2016() {
+        // published instants and distances, to the minute and kilometre
+        let expected = [
+            (Apsis::Perigee, Utc.with_ymd_and_hms(2016, 10, 16, 23, 37, 0), 357_861_f64),
+            (Apsis::Apogee, Utc.with_ymd_and_hms(2016, 10, 31, 19, 28, 0), 406_660_f64),
+            (Apsis::Perigee, Utc.with_ymd_and_hms(2016, 11, 14, 11, 22, 0), 356_509_f64),
+            (Apsis::Apogee, Utc.with_ymd_and_hms(2016, 11, 27, 20, 9, 0), 406_555_f64),
+        ];
+
+        let start = dt_to_jul(&Utc.with_ymd_and_hms(2016, 10, 10, 0, 0, 0).unwrap());
+        let end = dt_to_jul(&Utc.with_ymd_and_hms(2016, 12, 1, 0, 0, 0).unwrap());
+        let actual: Vec<ApsisInstant> = apsides(start, end).collect();
+
+        assert_eq!(expected.len(), actual.len());
+        for (&(apsis, instant, distance), found) in expected.iter().zip(actual.iter()) {
+            assert_eq!(apsis, found.apsis);
+
+            let difference = found.dt(&Utc).unwrap() - instant.unwrap();
+            assert!(difference.num_minutes().abs() <= 30, "{:?}", found);
+            assert!((found.distance() - distance).abs() < 20_f64, "{:?}", found);
+        }
+    }
+
+    #[test]
+    fn apsides_alternate() {
+        let all: Vec<ApsisInstant> = apsides(2_451_545_f64, 2_451_545_f64 + 3_652.5_f64).collect();
+
+        for pair in all.windows(2) {
+            assert!(pair[0].apsis != pair[1].apsis);
+            assert!(pair[1].jde - pair[0].jde > 11_f64 && pair[1].jde - pair[0].jde < 17_f64);
+        }
+        for instant in all.iter() {
+            match instant.apsis {
+                Apsis::Perigee => assert!(instant.distance() < 371_000_f64),
+                Apsis::Apogee => assert!(instant.distance() > 404_000_f64),
+            }
+        }
+    }
+}
@@ -0,0 +1,285 @@
+use chrono::{DateTime, TimeZone};
+use moon::apparent_equatorial;
+use moon::periodic_terms::{self, MoonDeclinationTableRow};
+use time::nasa::{jule_to_dt, jule_to_jul};
+use utils::cycles::{nearest_event, CycleEvents};
+
+/// Mean length of a tropical month, in days
+const TROPICAL_MONTH: f64 = 27.321_582_247_f64;
+/// Half the width of the window, in days, searched around Meeus' instant
+/// for the extreme of the moon's position
+const REFINEMENT_WINDOW: f64 = 0.05_f64;
+/// Number of golden section iterations, narrowing the window to well under a
+/// second
+const REFINEMENTS: usize = 30;
+
+/// The direction of one of the moon's greatest declinations
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Declination {
+    /// Greatest northern declination
+    Northern,
+    /// Greatest southern declination
+    Southern,
+}
+
+impl Declination {
+    /// Both of the directions, in the order the moon reaches them during a
+    /// tropical month
+    pub const ALL: [Declination; 2] = [Declination::Southern, Declination::Northern];
+
+    /// Julian Ephemeris Day of the mean greatest declination in month 0
+    fn epoch(self) -> f64 {
+        match self {
+            Declination::Northern => 2_451_562.589_7_f64,
+            Declination::Southern => 2_451_548.928_9_f64,
+        }
+    }
+}
+
+/// The instant of, and the moon's value of, one of its greatest
+/// declinations
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DeclinationInstant {
+    /// the direction of the declination
+    pub direction: Declination,
+    /// tropical month number, counting from 2000 January (Meeus' k)
+    pub month: i64,
+    /// Julian Ephemeris Day of the greatest declination
+    pub jde: f64,
+    /// the moon's apparent declination, in radians
+    pub declination: f64,
+}
+
+impl DeclinationInstant {
+    /// Calculates the instant of a greatest declination during a tropical
+    /// month
+    pub fn new(month: i64, direction: Declination) -> DeclinationInstant {
+        let jde = greatest_declination_jule(month, direction);
+
+        DeclinationInstant {
+            direction,
+            month,
+            jde,
+            declination: apparent_equatorial(jde).declination,
+        }
+    }
+
+    /// The Julian Day (UT) of the greatest declination
+    pub fn jul(&self) -> f64 {
+        jule_to_jul(self.jde)
+    }
+
+    /// The instant of the greatest declination in the given timezone, or
+    /// `None` if it can't be represented by `chrono`
+    pub fn dt<Tz: TimeZone>(&self, tz: &Tz) -> Option<DateTime<Tz>> {
+        jule_to_dt(self.jde, tz)
+    }
+}
+
+/// Calculates the Julian Ephemeris Day of the moon's greatest northern or
+/// southern declination during a tropical month
+///
+/// Tropical months are numbered from 2000 January, when the greatest
+/// southern declination was on January 6 and the northern on January 19.
+/// Uses the method from Meeus (ch. 52), which is accurate to within about
+/// half an hour, refined against the moon's apparent position (as Meeus'
+/// periodic terms are approximate, and the declination changes very slowly
+/// near its extremes).
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::moon::{greatest_declination_jule, Declination};
+///
+/// // Meeus example 52.a: 1988 December 22, 20h01m TD
+/// let jde = greatest_declination_jule(-148, Declination::Northern);
+/// assert_eq!(2_447_518.334, (jde * 1_000_f64).round() / 1_000_f64);
+/// ```
+pub fn greatest_declination_jule(month: i64, direction: Declination) -> f64 {
+    let estimate = mean_greatest_declination_jule(month, direction);
+    let sign = match direction {
+        Declination::Northern => 1_f64,
+        Declination::Southern => -1_f64,
+    };
+
+    // golden section search for the extreme of the declination
+    let ratio = (5_f64.sqrt() - 1_f64) / 2_f64;
+    let declination = |jde: f64| sign * apparent_equatorial(jde).declination;
+    let (mut low, mut high) = (estimate - REFINEMENT_WINDOW, estimate + REFINEMENT_WINDOW);
+
+    for _ in 0..REFINEMENTS {
+        let lower = high - ratio * (high - low);
+        let upper = low + ratio * (high - low);
+
+        if declination(lower) > declination(upper) {
+            high = upper;
+        } else {
+            low = lower;
+        }
+    }
+
+    (low + high) / 2_f64
+}
+
+/// Calculates Meeus' estimate of the Julian Ephemeris Day of the moon's
+/// greatest declination during a tropical month
+fn mean_greatest_declination_jule(month: i64, direction: Declination) -> f64 {
+    let k = month as f64;
+    let t = k / 1_336.86_f64;
+
+    let mean = direction.epoch() + TROPICAL_MONTH * k + 0.000_119_804_f64 * t.powi(2) -
+        0.000_000_141_f64 * t.powi(3);
+
+    let (d, m, m_prime, f, terms) = match direction {
+        Declination::Northern => (
+            152.202_9_f64,
+            14.859_1_f64,
+            4.688_1_f64,
+            325.886_7_f64,
+            periodic_terms::NORTHERN_DECLINATION_TERMS,
+        ),
+        Declination::Southern => (
+            345.667_6_f64,
+            1.395_1_f64,
+            186.21_f64,
+            145.163_3_f64,
+            periodic_terms::SOUTHERN_DECLINATION_TERMS,
+        ),
+    };
+
+    let d = (d + 333.070_554_6_f64 * k - 0.000_421_4_f64 * t.powi(2) +
+                 0.000_000_11_f64 * t.powi(3))
+        .to_radians();
+    let m = (m + 26.928_159_2_f64 * k - 0.000_035_5_f64 * t.powi(2) -
+                 0.000_000_1_f64 * t.powi(3))
+        .to_radians();
+    let m_prime = (m_prime + 356.956_279_4_f64 * k + 0.010_306_6_f64 * t.powi(2) +
+                       0.000_012_51_f64 * t.powi(3))
+        .to_radians();
+    let f = (f + 1.446_780_7_f64 * k - 0.002_069_f64 * t.powi(2) -
+                 0.000_002_15_f64 * t.powi(3))
+        .to_radians();
+
+    mean + sum_declination_terms(terms, &[d, m, m_prime, f], t)
+}
+
+fn sum_declination_terms(terms: &[MoonDeclinationTableRow], args: &[f64; 4], t: f64) -> f64 {
+    let eccentricity = 1_f64 - 0.002_516_f64 * t - 0.000_007_4_f64 * t.powi(2);
+
+    terms.iter().fold(0_f64, |curr, row| {
+        let argument: f64 = row.args
+            .iter()
+            .zip(args.iter())
+            .map(|(multiple, arg)| f64::from(*multiple) * arg)
+            .sum();
+        let function = if row.cosine {
+            argument.cos()
+        } else {
+            argument.sin()
+        };
+
+        curr + row.coefficient * eccentricity.powi(row.e) * function
+    })
+}
+
+/// Finds the moon's greatest declination in a direction nearest to a Julian
+/// Day (UT)
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::moon::{nearest_greatest_declination, Declination};
+///
+/// // 2017 August
+/// let north = nearest_greatest_declination(2_457_966.5_f64, Declination::Northern);
+/// assert_eq!(19.4, (north.declination.to_degrees() * 10_f64).round() / 10_f64);
+/// ```
+pub fn nearest_greatest_declination(jd: f64, direction: Declination) -> DeclinationInstant {
+    let estimate = ((jd - direction.epoch()) / TROPICAL_MONTH).round() as i64;
+
+    nearest_event(
+        estimate,
+        jd,
+        |month| DeclinationInstant::new(month, direction),
+        |instant| instant.jde,
+    )
+}
+
+/// An iterator over the moon's greatest northern and southern declinations
+/// between two instants, in order
+#[derive(Debug, Clone)]
+pub struct GreatestDeclinations {
+    events: CycleEvents<Declination>,
+}
+
+impl Iterator for GreatestDeclinations {
+    type Item = DeclinationInstant;
+
+    fn next(&mut self) -> Option<DeclinationInstant> {
+        self.events.next_event(DeclinationInstant::new, |instant| instant.jde)
+    }
+}
+
+/// Iterates over the moon's greatest northern and southern declinations
+/// from one Julian Day (UT, inclusive) to another (exclusive)
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::moon::greatest_declinations;
+///
+/// // the major lunar standstill of 2006, when the declination reached its
+/// // largest values of the 18.6 year cycle
+/// let largest = greatest_declinations(2_453_736.5_f64, 2_454_101.5_f64)
+///     .map(|instant| instant.declination.to_degrees().abs())
+///     .fold(0_f64, f64::max);
+/// assert!(largest > 28.7);
+/// ```
+pub fn greatest_declinations(start_jd: f64, end_jd: f64) -> GreatestDeclinations {
+    let month = ((start_jd - Declination::Southern.epoch()) / TROPICAL_MONTH).floor() as i64 - 1;
+
+    GreatestDeclinations { events: CycleEvents::new(&Declination::ALL, month, start_jd, end_jd) }
+}
+
+#[cfg(test)]
+mod tests {
+    use moon::*;
+
+    #[test]
+    fn meeus_examples() {
+        // example 52.a: 1988 December 22, 20h02m TD, δ = 28.1562° (Meeus'
+        // declination uses an approximate series)
+        let north = DeclinationInstant::new(-148, Declination::Northern);
+        assert!((north.jde - 2_447_518.334_6_f64).abs() < 0.001);
+        assert!((north.declination.to_degrees() - 28.156_2_f64).abs() < 0.005);
+
+        // example 52.b: 2049 April 21, 14h TD, δ = -22.1384°
+        let south = DeclinationInstant::new(659, Declination::Southern);
+        assert!((south.jde - 2_469_553.083_4_f64).abs() < 0.02);
+        assert!((south.declination.to_degrees() + 22.138_4_f64).abs() < 0.02);
+    }
+
+    #[test]
+    fn declination_is_greatest() {
+        for instant in greatest_declinations(2_451_545_f64, 2_451_545_f64 + 3_652.5_f64) {
+            let declination = instant.declination.abs();
+
+            for &offset in [-0.001_f64, 0.001_f64].iter() {
+                let other = apparent_equatorial(instant.jde + offset).declination;
+                assert!(other.abs() < declination, "{:?}", instant);
+                assert_eq!(other.signum(), instant.declination.signum());
+            }
+        }
+    }
+
+    #[test]
+    fn alternate() {
+        let all: Vec<DeclinationInstant> =
+            greatest_declinations(2_451_545_f64, 2_451_545_f64 + 365.25_f64).collect();
+
+        for pair in all.windows(2) {
+            assert!(pair[0].direction != pair[1].direction);
+            assert!(pair[1].jde - pair[0].jde > 12_f64 && pair[1].jde - pair[0].jde < 15.5_f64);
+        }
+    }
+}
@@ -0,0 +1,151 @@
+use earth;
+use moon;
+use sun;
+use utils::angles::limit_radians;
+use utils::coordinates::Equatorial;
+
+/// The moon's illumination, as seen from the centre of the earth
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Illumination {
+    /// geocentric elongation of the moon from the sun (ψ), in radians
+    pub elongation: f64,
+    /// phase angle (i), in radians
+    pub phase_angle: f64,
+    /// illuminated fraction of the disk (k), from 0 to 1
+    pub illuminated_fraction: f64,
+    /// position angle of the midpoint of the bright limb (χ), in radians
+    /// eastward from north
+    pub bright_limb_position_angle: f64,
+}
+
+/// Calculates the moon's phase angle (i), in radians, given its geocentric
+/// elongation from the sun, and the distances to the sun and moon (in the
+/// same units)
+///
+/// Obtained from Meeus (ch. 48)
+///
+/// # Examples:
+/// ```
+/// use meealgi::moon::phase_angle;
+///
+/// // Meeus example 48.a
+/// let i = phase_angle(110.792_9_f64.to_radians(), 149_971_520_f64, 368_410_f64);
+/// assert_eq!(69.075_6, (i.to_degrees() * 10_000_f64).round() / 10_000_f64);
+/// ```
+pub fn phase_angle(elongation: f64, sun_distance: f64, moon_distance: f64) -> f64 {
+    (sun_distance * elongation.sin()).atan2(moon_distance - sun_distance * elongation.cos())
+}
+
+/// Calculates the illuminated fraction of the moon's disk (k), from 0 to 1,
+/// given its phase angle
+///
+/// # Examples:
+/// ```
+/// use meealgi::moon::illuminated_fraction;
+///
+/// // Meeus example 48.a
+/// let k = illuminated_fraction(69.075_6_f64.to_radians());
+/// assert_eq!(0.678_6, (k * 10_000_f64).round() / 10_000_f64);
+/// ```
+pub fn illuminated_fraction(phase_angle: f64) -> f64 {
+    (1_f64 + phase_angle.cos()) / 2_f64
+}
+
+/// Calculates the position angle of the midpoint of the moon's bright limb
+/// (χ), in radians eastward from north, given the equatorial positions of
+/// the sun and moon
+///
+/// Obtained from Meeus (ch. 48)
+///
+/// # Examples:
+/// ```
+/// use meealgi::moon::bright_limb_position_angle;
+/// use meealgi::utils::coordinates::Equatorial;
+///
+/// // Meeus example 48.a
+/// let sun = Equatorial {
+///     right_ascension: 20.657_9_f64.to_radians(),
+///     declination: 8.696_4_f64.to_radians(),
+/// };
+/// let moon = Equatorial {
+///     right_ascension: 134.688_5_f64.to_radians(),
+///     declination: 13.768_4_f64.to_radians(),
+/// };
+/// let chi = bright_limb_position_angle(&sun, &moon);
+/// assert_eq!(285.0, (chi.to_degrees() * 10_f64).round() / 10_f64);
+/// ```
+pub fn bright_limb_position_angle(sun: &Equatorial, moon: &Equatorial) -> f64 {
+    let ra_difference = sun.right_ascension - moon.right_ascension;
+
+    limit_radians(
+        (sun.declination.cos() * ra_difference.sin()).atan2(
+            sun.declination.sin() * moon.declination.cos() -
+                sun.declination.cos() * moon.declination.sin() * ra_difference.cos(),
+        ),
+    )
+}
+
+/// Calculates the moon's illumination for a given Julian Ephemeris Day
+///
+/// # Examples:
+/// ```
+/// use meealgi::moon::illumination;
+///
+/// // 1992 April 12, 0h TD (Meeus example 48.a)
+/// let illumination = illumination(2_448_724.5_f64);
+/// assert_eq!(0.679, (illumination.illuminated_fraction * 1_000_f64).round() / 1_000_f64);
+/// ```
+pub fn illumination(jde: f64) -> Illumination {
+    let sun_position = sun::apparent_equatorial(jde);
+    let moon_position = moon::apparent_equatorial(jde);
+
+    let elongation = sun_position.separation(&moon_position);
+    let phase_angle = phase_angle(
+        elongation,
+        sun::distance(jde) * earth::AU_IN_KM,
+        moon::distance(::time::jul_to_julc(jde)),
+    );
+
+    Illumination {
+        elongation,
+        phase_angle,
+        illuminated_fraction: illuminated_fraction(phase_angle),
+        bright_limb_position_angle: bright_limb_position_angle(&sun_position, &moon_position),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use moon::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn meeus_example() {
+        // 1992 April 12, 0h TD (Meeus example 48.a)
+        let illumination = illumination(2_448_724.5_f64);
+
+        assert!((illumination.elongation.to_degrees() - 110.792_9).abs() < 0.001);
+        assert!((illumination.phase_angle.to_degrees() - 69.075_6).abs() < 0.001);
+        assert!((illumination.bright_limb_position_angle.to_degrees() - 285.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn follows_the_phases() {
+        for lunation in 200..210 {
+            let new_moon = illumination(phase_jule(lunation, Phase::NewMoon));
+            let first_quarter = illumination(phase_jule(lunation, Phase::FirstQuarter));
+            let full_moon = illumination(phase_jule(lunation, Phase::FullMoon));
+            let last_quarter = illumination(phase_jule(lunation, Phase::LastQuarter));
+
+            assert!(new_moon.illuminated_fraction < 0.01);
+            assert!((first_quarter.illuminated_fraction - 0.5).abs() < 0.01);
+            assert!(full_moon.illuminated_fraction > 0.99);
+            assert!((last_quarter.illuminated_fraction - 0.5).abs() < 0.01);
+
+            // the bright limb faces west (towards the evening sun) while
+            // waxing, and east while waning
+            assert!(first_quarter.bright_limb_position_angle > PI);
+            assert!(last_quarter.bright_limb_position_angle < PI);
+        }
+    }
+}
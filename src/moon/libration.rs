@@ -0,0 +1,195 @@
+use earth;
+use moon;
+use time::{jul_to_julc, julc_to_julm};
+use utils::angles::limit_signed_radians;
+
+/// Inclination of the mean lunar equator to the ecliptic (I), in radians
+/// (1.54242°)
+const EQUATOR_INCLINATION: f64 = 0.026_920_249_f64;
+
+/// The librations of the moon, and the position angle of its axis, as seen
+/// from the centre of the earth
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Libration {
+    /// total libration in longitude (l), in radians
+    pub longitude: f64,
+    /// total libration in latitude (b), in radians
+    pub latitude: f64,
+    /// optical libration in longitude (l'), in radians
+    pub optical_longitude: f64,
+    /// optical libration in latitude (b'), in radians
+    pub optical_latitude: f64,
+    /// position angle of the moon's axis of rotation (P), in radians
+    /// eastward from north
+    pub position_angle: f64,
+}
+
+impl Libration {
+    /// The physical libration in longitude (l''), in radians
+    pub fn physical_longitude(&self) -> f64 {
+        self.longitude - self.optical_longitude
+    }
+
+    /// The physical libration in latitude (b''), in radians
+    pub fn physical_latitude(&self) -> f64 {
+        self.latitude - self.optical_latitude
+    }
+}
+
+/// Calculates the moon's optical librations in longitude (l') and latitude
+/// (b'), in radians, given its geocentric longitude (referred to the mean
+/// equinox of the date) and latitude, the longitude of its mean ascending
+/// node and its argument of latitude
+///
+/// Also returns the angle A, needed for the physical librations.
+fn optical_libration(
+    longitude: f64,
+    latitude: f64,
+    node_longitude: f64,
+    argument_of_latitude: f64,
+) -> (f64, f64, f64) {
+    let w = longitude - node_longitude;
+
+    let a = (w.sin() * latitude.cos() * EQUATOR_INCLINATION.cos() -
+                 latitude.sin() * EQUATOR_INCLINATION.sin())
+        .atan2(w.cos() * latitude.cos());
+    let l = limit_signed_radians(a - argument_of_latitude);
+    let b = (-w.sin() * latitude.cos() * EQUATOR_INCLINATION.sin() -
+                 latitude.sin() * EQUATOR_INCLINATION.cos())
+        .asin();
+
+    (l, b, a)
+}
+
+/// Calculates the quantities ρ, σ and τ describing the physical libration,
+/// in radians, as per Meeus (ch. 53)
+fn physical_libration_quantities(jce: f64) -> (f64, f64, f64) {
+    let d = moon::mean_elongation(jce);
+    let m = moon::sun_mean_anomaly(jce);
+    let m_prime = moon::mean_anomaly(jce);
+    let f = moon::argument_of_latitude(jce);
+    let omega = moon::ascending_node_longitude(jce);
+    let e = 1_f64 - 0.002_516_f64 * jce - 0.000_007_4_f64 * jce.powi(2);
+    let k1 = (119.75_f64 + 131.849_f64 * jce).to_radians();
+    let k2 = (72.56_f64 + 20.186_f64 * jce).to_radians();
+
+    let rho = -0.027_52_f64 * m_prime.cos() - 0.022_45_f64 * f.sin() +
+        0.006_84_f64 * (m_prime - 2_f64 * f).cos() - 0.002_93_f64 * (2_f64 * f).cos() -
+        0.000_85_f64 * (2_f64 * f - 2_f64 * d).cos() -
+        0.000_54_f64 * (m_prime - 2_f64 * d).cos() - 0.000_2_f64 * (m_prime + f).sin() -
+        0.000_2_f64 * (m_prime + 2_f64 * f).cos() - 0.000_2_f64 * (m_prime - f).cos() +
+        0.000_14_f64 * (m_prime + 2_f64 * f - 2_f64 * d).cos();
+
+    let sigma = -0.028_16_f64 * m_prime.sin() + 0.022_44_f64 * f.cos() -
+        0.006_82_f64 * (m_prime - 2_f64 * f).sin() - 0.002_79_f64 * (2_f64 * f).sin() -
+        0.000_83_f64 * (2_f64 * f - 2_f64 * d).sin() +
+        0.000_69_f64 * (m_prime - 2_f64 * d).sin() + 0.000_4_f64 * (m_prime + f).cos() -
+        0.000_25_f64 * (2_f64 * m_prime).sin() - 0.000_23_f64 * (m_prime + 2_f64 * f).sin() +
+        0.000_2_f64 * (m_prime - f).cos() + 0.000_19_f64 * (m_prime - f).sin() +
+        0.000_13_f64 * (m_prime + 2_f64 * f - 2_f64 * d).sin() -
+        0.000_1_f64 * (m_prime - 3_f64 * f).cos();
+
+    let tau = 0.025_2_f64 * e * m.sin() + 0.004_73_f64 * (2_f64 * m_prime - 2_f64 * f).sin() -
+        0.004_67_f64 * m_prime.sin() + 0.003_96_f64 * k1.sin() +
+        0.002_76_f64 * (2_f64 * m_prime - 2_f64 * d).sin() + 0.001_96_f64 * omega.sin() -
+        0.001_83_f64 * (m_prime - f).cos() + 0.001_15_f64 * (m_prime - 2_f64 * d).sin() -
+        0.000_96_f64 * (m_prime - d).sin() + 0.000_46_f64 * (2_f64 * f - 2_f64 * d).sin() -
+        0.000_39_f64 * (m_prime - f).sin() - 0.000_32_f64 * (m_prime - m - d).sin() +
+        0.000_27_f64 * (2_f64 * m_prime - m - 2_f64 * d).sin() + 0.000_23_f64 * k2.sin() -
+        0.000_14_f64 * (2_f64 * d).sin() + 0.000_14_f64 * (2_f64 * m_prime - 2_f64 * f).cos() -
+        0.000_12_f64 * (m_prime - 2_f64 * f).sin() - 0.000_12_f64 * (2_f64 * m_prime).sin() +
+        0.000_11_f64 * (2_f64 * m_prime - 2_f64 * m - 2_f64 * d).sin();
+
+    (rho.to_radians(), sigma.to_radians(), tau.to_radians())
+}
+
+/// Calculates the moon's librations and the position angle of its axis for
+/// a given Julian Ephemeris Day
+///
+/// The librations are the selenographic longitude and latitude of the
+/// centre of the moon's disk as seen from the centre of the earth; positive
+/// values mean more of the moon's western limb (longitude) or northern limb
+/// (latitude) is visible.
+///
+/// Obtained from Meeus (ch. 53)
+///
+/// # Examples:
+/// ```
+/// use meealgi::moon::libration;
+///
+/// // 1992 April 12, 0h TD (Meeus example 53.a)
+/// let libration = libration(2_448_724.5_f64);
+/// assert_eq!(-1.23, (libration.longitude.to_degrees() * 100_f64).round() / 100_f64);
+/// assert_eq!(4.20, (libration.latitude.to_degrees() * 100_f64).round() / 100_f64);
+/// assert_eq!(15.08, (libration.position_angle.to_degrees() * 100_f64).round() / 100_f64);
+/// ```
+pub fn libration(jde: f64) -> Libration {
+    let jce = jul_to_julc(jde);
+    let node_longitude = moon::ascending_node_longitude(jce);
+    let nutation_longitude = earth::nutation_longitude(jce);
+    let obliquity = earth::true_obliquity(
+        earth::mean_obliquity(julc_to_julm(jce)),
+        earth::nutation_obliquity(jce),
+    );
+
+    let latitude = moon::geocentric_latitude(jce);
+    let (optical_longitude, optical_latitude, a) = optical_libration(
+        moon::geocentric_longitude(jce),
+        latitude,
+        node_longitude,
+        moon::argument_of_latitude(jce),
+    );
+
+    let (rho, sigma, tau) = physical_libration_quantities(jce);
+    let physical_longitude = -tau + (rho * a.cos() + sigma * a.sin()) * optical_latitude.tan();
+    let physical_latitude = sigma * a.cos() - rho * a.sin();
+    let total_latitude = optical_latitude + physical_latitude;
+
+    // position angle of the axis
+    let v = node_longitude + nutation_longitude + sigma / EQUATOR_INCLINATION.sin();
+    let x = (EQUATOR_INCLINATION + rho).sin() * v.sin();
+    let y = (EQUATOR_INCLINATION + rho).sin() * v.cos() * obliquity.cos() -
+        (EQUATOR_INCLINATION + rho).cos() * obliquity.sin();
+    let omega = x.atan2(y);
+    let right_ascension = moon::apparent_equatorial(jde).right_ascension;
+    let position_angle = ((x.powi(2) + y.powi(2)).sqrt() * (right_ascension - omega).cos() /
+                              total_latitude.cos())
+        .asin();
+
+    Libration {
+        longitude: optical_longitude + physical_longitude,
+        latitude: total_latitude,
+        optical_longitude,
+        optical_latitude,
+        position_angle,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use moon::*;
+
+    #[test]
+    fn meeus_example() {
+        // example 53.a: 1992 April 12, 0h TD
+        let libration = libration(2_448_724.5_f64);
+
+        assert!((libration.optical_longitude.to_degrees() + 1.206_f64).abs() < 0.001);
+        assert!((libration.optical_latitude.to_degrees() - 4.194_f64).abs() < 0.001);
+        assert!((libration.physical_longitude().to_degrees() + 0.025_f64).abs() < 0.001);
+        assert!((libration.physical_latitude().to_degrees() - 0.006_f64).abs() < 0.001);
+        assert!((libration.position_angle.to_degrees() - 15.08_f64).abs() < 0.01);
+    }
+
+    #[test]
+    fn within_bounds() {
+        // the librations never exceed about 8° in longitude and 7° in latitude
+        for day in 0..400 {
+            let libration = libration(2_451_545_f64 + f64::from(day));
+
+            assert!(libration.longitude.to_degrees().abs() < 8.5);
+            assert!(libration.latitude.to_degrees().abs() < 7.5);
+            assert!(libration.position_angle.to_degrees().abs() < 25_f64);
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//! Constants and helpers for calculations involving the moon, such as its
+//! position, phases, illumination, rising and setting, perigee and apogee,
+//! node passages, greatest declinations and libration
+mod periodic_terms;
+mod terms;
+mod phases;
+mod position;
+mod illumination;
+mod rise_set;
+mod apsides;
+mod nodes;
+mod declinations;
+mod libration;
+
+pub use self::periodic_terms::*;
+pub use self::terms::*;
+pub use self::phases::*;
+pub use self::position::*;
+pub use self::illumination::*;
+pub use self::rise_set::*;
+pub use self::apsides::*;
+pub use self::nodes::*;
+pub use self::declinations::*;
+pub use self::libration::*;
@@ -0,0 +1,202 @@
+use chrono::{DateTime, TimeZone};
+use moon::periodic_terms;
+use time::nasa::{jule_to_dt, jule_to_jul};
+use utils::cycles::{nearest_event, CycleEvents};
+
+/// Julian Ephemeris Day of the mean passage of the ascending node of 2000
+/// January 25 (draconic month 0)
+const NODE_EPOCH: f64 = 2_451_565.161_9_f64;
+/// Mean length of a draconic month, in days
+const DRACONIC_MONTH: f64 = 27.212_220_817_f64;
+
+/// A node of the moon's orbit, where it crosses the ecliptic
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Node {
+    /// Ascending node, where the moon crosses the ecliptic moving north
+    Ascending,
+    /// Descending node, where the moon crosses the ecliptic moving south
+    Descending,
+}
+
+impl Node {
+    /// Both of the nodes, in the order the moon passes them during a
+    /// draconic month
+    pub const ALL: [Node; 2] = [Node::Ascending, Node::Descending];
+
+    /// The fraction of a draconic month at which the moon passes the node
+    pub fn fraction(self) -> f64 {
+        match self {
+            Node::Ascending => 0_f64,
+            Node::Descending => 0.5_f64,
+        }
+    }
+}
+
+/// The instant of a passage of the moon through a node of its orbit
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct NodeInstant {
+    /// the node
+    pub node: Node,
+    /// draconic month number, counting from the ascending node of 2000
+    /// January 25 (Meeus' k, without the fraction for the node)
+    pub month: i64,
+    /// Julian Ephemeris Day of the passage
+    pub jde: f64,
+}
+
+impl NodeInstant {
+    /// Calculates the instant the moon passes a node during a draconic month
+    pub fn new(month: i64, node: Node) -> NodeInstant {
+        NodeInstant {
+            node,
+            month,
+            jde: node_jule(month, node),
+        }
+    }
+
+    /// The Julian Day (UT) of the passage
+    pub fn jul(&self) -> f64 {
+        jule_to_jul(self.jde)
+    }
+
+    /// The instant of the passage in the given timezone, or `None` if it
+    /// can't be represented by `chrono`
+    pub fn dt<Tz: TimeZone>(&self, tz: &Tz) -> Option<DateTime<Tz>> {
+        jule_to_dt(self.jde, tz)
+    }
+}
+
+/// Calculates the Julian Ephemeris Day the moon passes a node of its orbit
+/// during a draconic month
+///
+/// Draconic months are numbered from the ascending node of 2000 January 25.
+/// Uses the method from Meeus (ch. 51), accurate to within a few minutes.
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::moon::{node_jule, Node};
+///
+/// // Meeus example 51.a: 1987 May 23, 6h26m TD
+/// let jde = node_jule(-170, Node::Ascending);
+/// assert_eq!(2_446_938.768_0, (jde * 10_000_f64).round() / 10_000_f64);
+/// ```
+pub fn node_jule(month: i64, node: Node) -> f64 {
+    let k = month as f64 + node.fraction();
+    let t = k / 1_342.23_f64;
+
+    let mean = NODE_EPOCH + DRACONIC_MONTH * k + 0.000_276_2_f64 * t.powi(2) +
+        0.000_000_021_f64 * t.powi(3) - 0.000_000_000_088_f64 * t.powi(4);
+
+    let d = (183.638_f64 + 331.737_356_82_f64 * k + 0.001_485_2_f64 * t.powi(2) +
+                 0.000_002_09_f64 * t.powi(3) - 0.000_000_01_f64 * t.powi(4))
+        .to_radians();
+    let m = (17.400_6_f64 + 26.820_372_5_f64 * k + 0.000_118_6_f64 * t.powi(2) +
+                 0.000_000_06_f64 * t.powi(3))
+        .to_radians();
+    let m_prime = (38.377_6_f64 + 355.527_473_13_f64 * k + 0.012_349_9_f64 * t.powi(2) +
+                       0.000_014_627_f64 * t.powi(3) -
+                       0.000_000_069_f64 * t.powi(4))
+        .to_radians();
+    let omega = 123.976_7_f64 - 1.440_989_56_f64 * k + 0.002_060_8_f64 * t.powi(2) +
+        0.000_002_14_f64 * t.powi(3) - 0.000_000_016_f64 * t.powi(4);
+    let v = 299.75_f64 + 132.85_f64 * t - 0.009_173_f64 * t.powi(2);
+    let p = omega + 272.75_f64 - 2.3_f64 * t;
+    let args = [d, m, m_prime, omega.to_radians()];
+
+    let eccentricity = 1_f64 - 0.002_516_f64 * t - 0.000_007_4_f64 * t.powi(2);
+
+    let periodic = periodic_terms::NODE_TERMS.iter().fold(0_f64, |curr, row| {
+        let argument: f64 = row.args
+            .iter()
+            .zip(args.iter())
+            .map(|(multiple, arg)| f64::from(*multiple) * arg)
+            .sum();
+
+        curr + row.coefficient * eccentricity.powi(row.e) * argument.sin()
+    });
+
+    mean + periodic + 0.000_3_f64 * v.to_radians().sin() + 0.000_3_f64 * p.to_radians().sin()
+}
+
+/// Finds the passage of the moon through a node nearest to a Julian Day
+/// (UT)
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::moon::{nearest_node, Node};
+///
+/// // the new moon of 2017 August 21 (a total solar eclipse) was near the
+/// // ascending node
+/// let node = nearest_node(2_457_987.27_f64, Node::Ascending);
+/// assert!((node.jul() - 2_457_987.27_f64).abs() < 2_f64);
+/// ```
+pub fn nearest_node(jd: f64, node: Node) -> NodeInstant {
+    let estimate = ((jd - NODE_EPOCH) / DRACONIC_MONTH - node.fraction()).round() as i64;
+
+    nearest_event(estimate, jd, |month| NodeInstant::new(month, node), |instant| instant.jde)
+}
+
+/// An iterator over the passages of the moon through the nodes of its orbit
+/// between two instants, in order
+#[derive(Debug, Clone)]
+pub struct NodePassages {
+    events: CycleEvents<Node>,
+}
+
+impl Iterator for NodePassages {
+    type Item = NodeInstant;
+
+    fn next(&mut self) -> Option<NodeInstant> {
+        self.events.next_event(NodeInstant::new, |instant| instant.jde)
+    }
+}
+
+/// Iterates over the passages of the moon through the nodes of its orbit
+/// from one Julian Day (UT, inclusive) to another (exclusive)
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::moon::node_passages;
+///
+/// // the passages during 2017
+/// let count = node_passages(2_457_754.5_f64, 2_458_119.5_f64).count();
+/// assert_eq!(27, count);
+/// ```
+pub fn node_passages(start_jd: f64, end_jd: f64) -> NodePassages {
+    let month = ((start_jd - NODE_EPOCH) / DRACONIC_MONTH).floor() as i64 - 1;
+
+    NodePassages { events: CycleEvents::new(&Node::ALL, month, start_jd, end_jd) }
+}
+
+#[cfg(test)]
+mod tests {
+    use moon::*;
+    use time::jul_to_julc;
+
+    #[test]
+    fn meeus_example() {
+        // example 51.a: 1987 May 23, 6h26m TD
+        let jde = node_jule(-170, Node::Ascending);
+        assert!((jde - 2_446_938.768_03_f64).abs() < 0.000_01);
+    }
+
+    #[test]
+    fn latitude_is_zero_at_nodes() {
+        for instant in node_passages(2_451_545_f64, 2_451_545_f64 + 3_652.5_f64) {
+            let jce = jul_to_julc(instant.jde);
+            let latitude = geocentric_latitude(jce);
+            let later = geocentric_latitude(jul_to_julc(instant.jde + 0.1_f64));
+
+            // within a few minutes of the moon crossing the ecliptic
+            // (where its latitude changes by about 0.01° a minute)
+            assert!(latitude.to_degrees().abs() < 0.05, "{:?}", instant);
+            match instant.node {
+                Node::Ascending => assert!(later > latitude),
+                Node::Descending => assert!(later < latitude),
+            }
+        }
+    }
+}
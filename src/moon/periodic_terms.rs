@@ -0,0 +1,2561 @@
+#![allow(clippy::unreadable_literal)]
+
+/// A row from the Periodic Terms for the Longitude and Distance of the Moon
+/// table (Meeus table 47.A)
+#[derive(Debug, Copy, Clone)]
+pub struct MoonLongitudeDistanceRow {
+    /// multiples of the mean elongation of the moon (D), the sun's mean
+    /// anomaly (M), the moon's mean anomaly (M') and the moon's argument of
+    /// latitude (F)
+    pub args: [i8; 4],
+    /// coefficient of the sine of the argument for the longitude, in
+    /// 0.000001 degrees
+    pub l: f64,
+    /// coefficient of the cosine of the argument for the distance, in
+    /// 0.001 km
+    pub r: f64,
+}
+
+/// A row from the Periodic Terms for the Latitude of the Moon table (Meeus
+/// table 47.B)
+#[derive(Debug, Copy, Clone)]
+pub struct MoonLatitudeRow {
+    /// multiples of the mean elongation of the moon (D), the sun's mean
+    /// anomaly (M), the moon's mean anomaly (M') and the moon's argument of
+    /// latitude (F)
+    pub args: [i8; 4],
+    /// coefficient of the sine of the argument for the latitude, in
+    /// 0.000001 degrees
+    pub b: f64,
+}
+
+/// A collection of the Periodic Terms for the Longitude and Distance of the
+/// Moon as per Meeus (table 47.A)
+pub const MOON_LONGITUDE_DISTANCE_TERMS: &[MoonLongitudeDistanceRow] = &[
+    MoonLongitudeDistanceRow {
+        args: [0, 0, 1, 0],
+        l: 6288774_f64,
+        r: -20905355_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, 0, -1, 0],
+        l: 1274027_f64,
+        r: -3699111_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, 0, 0, 0],
+        l: 658314_f64,
+        r: -2955968_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [0, 0, 2, 0],
+        l: 213618_f64,
+        r: -569925_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [0, 1, 0, 0],
+        l: -185116_f64,
+        r: 48888_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [0, 0, 0, 2],
+        l: -114332_f64,
+        r: -3149_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, 0, -2, 0],
+        l: 58793_f64,
+        r: 246158_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, -1, -1, 0],
+        l: 57066_f64,
+        r: -152138_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, 0, 1, 0],
+        l: 53322_f64,
+        r: -170733_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, -1, 0, 0],
+        l: 45758_f64,
+        r: -204586_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [0, 1, -1, 0],
+        l: -40923_f64,
+        r: -129620_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [1, 0, 0, 0],
+        l: -34720_f64,
+        r: 108743_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [0, 1, 1, 0],
+        l: -30383_f64,
+        r: 104755_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, 0, 0, -2],
+        l: 15327_f64,
+        r: 10321_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [0, 0, 1, 2],
+        l: -12528_f64,
+        r: 0_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [0, 0, 1, -2],
+        l: 10980_f64,
+        r: 79661_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [4, 0, -1, 0],
+        l: 10675_f64,
+        r: -34782_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [0, 0, 3, 0],
+        l: 10034_f64,
+        r: -23210_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [4, 0, -2, 0],
+        l: 8548_f64,
+        r: -21636_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, 1, -1, 0],
+        l: -7888_f64,
+        r: 24208_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, 1, 0, 0],
+        l: -6766_f64,
+        r: 30824_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [1, 0, -1, 0],
+        l: -5163_f64,
+        r: -8379_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [1, 1, 0, 0],
+        l: 4987_f64,
+        r: -16675_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, -1, 1, 0],
+        l: 4036_f64,
+        r: -12831_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, 0, 2, 0],
+        l: 3994_f64,
+        r: -10445_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [4, 0, 0, 0],
+        l: 3861_f64,
+        r: -11650_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, 0, -3, 0],
+        l: 3665_f64,
+        r: 14403_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [0, 1, -2, 0],
+        l: -2689_f64,
+        r: -7003_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, 0, -1, 2],
+        l: -2602_f64,
+        r: 0_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, -1, -2, 0],
+        l: 2390_f64,
+        r: 10056_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [1, 0, 1, 0],
+        l: -2348_f64,
+        r: 6322_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, -2, 0, 0],
+        l: 2236_f64,
+        r: -9884_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [0, 1, 2, 0],
+        l: -2120_f64,
+        r: 5751_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [0, 2, 0, 0],
+        l: -2069_f64,
+        r: 0_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, -2, -1, 0],
+        l: 2048_f64,
+        r: -4950_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, 0, 1, -2],
+        l: -1773_f64,
+        r: 4130_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, 0, 0, 2],
+        l: -1595_f64,
+        r: 0_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [4, -1, -1, 0],
+        l: 1215_f64,
+        r: -3958_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [0, 0, 2, 2],
+        l: -1110_f64,
+        r: 0_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [3, 0, -1, 0],
+        l: -892_f64,
+        r: 3258_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, 1, 1, 0],
+        l: -810_f64,
+        r: 2616_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [4, -1, -2, 0],
+        l: 759_f64,
+        r: -1897_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [0, 2, -1, 0],
+        l: -713_f64,
+        r: -2117_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, 2, -1, 0],
+        l: -700_f64,
+        r: 2354_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, 1, -2, 0],
+        l: 691_f64,
+        r: 0_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, -1, 0, -2],
+        l: 596_f64,
+        r: 0_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [4, 0, 1, 0],
+        l: 549_f64,
+        r: -1423_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [0, 0, 4, 0],
+        l: 537_f64,
+        r: -1117_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [4, -1, 0, 0],
+        l: 520_f64,
+        r: -1571_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [1, 0, -2, 0],
+        l: -487_f64,
+        r: -1739_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, 1, 0, -2],
+        l: -399_f64,
+        r: 0_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [0, 0, 2, -2],
+        l: -381_f64,
+        r: -4421_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [1, 1, 1, 0],
+        l: 351_f64,
+        r: 0_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [3, 0, -2, 0],
+        l: -340_f64,
+        r: 0_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [4, 0, -3, 0],
+        l: 330_f64,
+        r: 0_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, -1, 2, 0],
+        l: 327_f64,
+        r: 0_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [0, 2, 1, 0],
+        l: -323_f64,
+        r: 1165_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [1, 1, -1, 0],
+        l: 299_f64,
+        r: 0_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, 0, 3, 0],
+        l: 294_f64,
+        r: 0_f64,
+    },
+    MoonLongitudeDistanceRow {
+        args: [2, 0, -1, -2],
+        l: 0_f64,
+        r: 8752_f64,
+    },
+];
+
+/// A collection of the Periodic Terms for the Latitude of the Moon as per
+/// Meeus (table 47.B)
+pub const MOON_LATITUDE_TERMS: &[MoonLatitudeRow] = &[
+    MoonLatitudeRow {
+        args: [0, 0, 0, 1],
+        b: 5128122_f64,
+    },
+    MoonLatitudeRow {
+        args: [0, 0, 1, 1],
+        b: 280602_f64,
+    },
+    MoonLatitudeRow {
+        args: [0, 0, 1, -1],
+        b: 277693_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, 0, 0, -1],
+        b: 173237_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, 0, -1, 1],
+        b: 55413_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, 0, -1, -1],
+        b: 46271_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, 0, 0, 1],
+        b: 32573_f64,
+    },
+    MoonLatitudeRow {
+        args: [0, 0, 2, 1],
+        b: 17198_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, 0, 1, -1],
+        b: 9266_f64,
+    },
+    MoonLatitudeRow {
+        args: [0, 0, 2, -1],
+        b: 8822_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, -1, 0, -1],
+        b: 8216_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, 0, -2, -1],
+        b: 4324_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, 0, 1, 1],
+        b: 4200_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, 1, 0, -1],
+        b: -3359_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, -1, -1, 1],
+        b: 2463_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, -1, 0, 1],
+        b: 2211_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, -1, -1, -1],
+        b: 2065_f64,
+    },
+    MoonLatitudeRow {
+        args: [0, 1, -1, -1],
+        b: -1870_f64,
+    },
+    MoonLatitudeRow {
+        args: [4, 0, -1, -1],
+        b: 1828_f64,
+    },
+    MoonLatitudeRow {
+        args: [0, 1, 0, 1],
+        b: -1794_f64,
+    },
+    MoonLatitudeRow {
+        args: [0, 0, 0, 3],
+        b: -1749_f64,
+    },
+    MoonLatitudeRow {
+        args: [0, 1, -1, 1],
+        b: -1565_f64,
+    },
+    MoonLatitudeRow {
+        args: [1, 0, 0, 1],
+        b: -1491_f64,
+    },
+    MoonLatitudeRow {
+        args: [0, 1, 1, 1],
+        b: -1475_f64,
+    },
+    MoonLatitudeRow {
+        args: [0, 1, 1, -1],
+        b: -1410_f64,
+    },
+    MoonLatitudeRow {
+        args: [0, 1, 0, -1],
+        b: -1344_f64,
+    },
+    MoonLatitudeRow {
+        args: [1, 0, 0, -1],
+        b: -1335_f64,
+    },
+    MoonLatitudeRow {
+        args: [0, 0, 3, 1],
+        b: 1107_f64,
+    },
+    MoonLatitudeRow {
+        args: [4, 0, 0, -1],
+        b: 1021_f64,
+    },
+    MoonLatitudeRow {
+        args: [4, 0, -1, 1],
+        b: 833_f64,
+    },
+    MoonLatitudeRow {
+        args: [0, 0, 1, -3],
+        b: 777_f64,
+    },
+    MoonLatitudeRow {
+        args: [4, 0, -2, 1],
+        b: 671_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, 0, 0, -3],
+        b: 607_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, 0, 2, -1],
+        b: 596_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, -1, 1, -1],
+        b: 491_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, 0, -2, 1],
+        b: -451_f64,
+    },
+    MoonLatitudeRow {
+        args: [0, 0, 3, -1],
+        b: 439_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, 0, 2, 1],
+        b: 422_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, 0, -3, -1],
+        b: 421_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, 1, -1, 1],
+        b: -366_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, 1, 0, 1],
+        b: -351_f64,
+    },
+    MoonLatitudeRow {
+        args: [4, 0, 0, 1],
+        b: 331_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, -1, 1, 1],
+        b: 315_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, -2, 0, -1],
+        b: 302_f64,
+    },
+    MoonLatitudeRow {
+        args: [0, 0, 1, 3],
+        b: -283_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, 1, 1, -1],
+        b: -229_f64,
+    },
+    MoonLatitudeRow {
+        args: [1, 1, 0, -1],
+        b: 223_f64,
+    },
+    MoonLatitudeRow {
+        args: [1, 1, 0, 1],
+        b: 223_f64,
+    },
+    MoonLatitudeRow {
+        args: [0, 1, -2, -1],
+        b: -220_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, 1, -1, -1],
+        b: -220_f64,
+    },
+    MoonLatitudeRow {
+        args: [1, 0, 1, 1],
+        b: -185_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, -1, -2, -1],
+        b: 181_f64,
+    },
+    MoonLatitudeRow {
+        args: [0, 1, 2, 1],
+        b: -177_f64,
+    },
+    MoonLatitudeRow {
+        args: [4, 0, -2, -1],
+        b: 176_f64,
+    },
+    MoonLatitudeRow {
+        args: [4, -1, -1, -1],
+        b: 166_f64,
+    },
+    MoonLatitudeRow {
+        args: [1, 0, 1, -1],
+        b: -164_f64,
+    },
+    MoonLatitudeRow {
+        args: [4, 0, 1, -1],
+        b: 132_f64,
+    },
+    MoonLatitudeRow {
+        args: [1, 0, -1, -1],
+        b: -119_f64,
+    },
+    MoonLatitudeRow {
+        args: [4, -1, 0, -1],
+        b: 115_f64,
+    },
+    MoonLatitudeRow {
+        args: [2, -2, 0, 1],
+        b: 107_f64,
+    },
+];
+
+/// A row from Meeus' table of periodic corrections to the instant of a
+/// moon phase (ch. 49)
+#[derive(Debug, Copy, Clone)]
+pub struct MoonPhaseTableRow {
+    /// multiples of the sun's mean anomaly (M), the moon's mean anomaly
+    /// (M'), the moon's argument of latitude (F) and the longitude of the
+    /// ascending node of the moon's orbit (Ω)
+    pub args: [i8; 4],
+    /// power of the eccentricity correction (E) applied to the term
+    pub e: i32,
+    /// coefficient of the sine of the argument, in days
+    pub coefficient: f64,
+}
+
+/// A row from Meeus' table of planetary arguments, used for the additional
+/// corrections to the instant of a moon phase (ch. 49)
+#[derive(Debug, Copy, Clone)]
+pub struct MoonPhasePlanetaryRow {
+    /// constant term of the argument, in degrees
+    pub a: f64,
+    /// rate of the argument per lunation, in degrees
+    pub b: f64,
+    /// coefficient of the square of the time (T²) in the argument, in degrees
+    pub c: f64,
+    /// coefficient of the sine of the argument, in days
+    pub coefficient: f64,
+}
+
+/// Periodic corrections to the instant of new moon as per Meeus (ch. 49)
+pub const NEW_MOON_TERMS: &[MoonPhaseTableRow] = &[
+    MoonPhaseTableRow {
+        args: [0, 1, 0, 0],
+        e: 0,
+        coefficient: -0.407_20_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 0, 0, 0],
+        e: 1,
+        coefficient: 0.172_41_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 2, 0, 0],
+        e: 0,
+        coefficient: 0.016_08_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 0, 2, 0],
+        e: 0,
+        coefficient: 0.010_39_f64,
+    },
+    MoonPhaseTableRow {
+        args: [-1, 1, 0, 0],
+        e: 1,
+        coefficient: 0.007_39_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 1, 0, 0],
+        e: 1,
+        coefficient: -0.005_14_f64,
+    },
+    MoonPhaseTableRow {
+        args: [2, 0, 0, 0],
+        e: 2,
+        coefficient: 0.002_08_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 1, -2, 0],
+        e: 0,
+        coefficient: -0.001_11_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 1, 2, 0],
+        e: 0,
+        coefficient: -0.000_57_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 2, 0, 0],
+        e: 1,
+        coefficient: 0.000_56_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 3, 0, 0],
+        e: 0,
+        coefficient: -0.000_42_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 0, 2, 0],
+        e: 1,
+        coefficient: 0.000_42_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 0, -2, 0],
+        e: 1,
+        coefficient: 0.000_38_f64,
+    },
+    MoonPhaseTableRow {
+        args: [-1, 2, 0, 0],
+        e: 1,
+        coefficient: -0.000_24_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 0, 0, 1],
+        e: 0,
+        coefficient: -0.000_17_f64,
+    },
+    MoonPhaseTableRow {
+        args: [2, 1, 0, 0],
+        e: 0,
+        coefficient: -0.000_07_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 2, -2, 0],
+        e: 0,
+        coefficient: 0.000_04_f64,
+    },
+    MoonPhaseTableRow {
+        args: [3, 0, 0, 0],
+        e: 0,
+        coefficient: 0.000_04_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 1, -2, 0],
+        e: 0,
+        coefficient: 0.000_03_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 2, 2, 0],
+        e: 0,
+        coefficient: 0.000_03_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 1, 2, 0],
+        e: 0,
+        coefficient: -0.000_03_f64,
+    },
+    MoonPhaseTableRow {
+        args: [-1, 1, 2, 0],
+        e: 0,
+        coefficient: 0.000_03_f64,
+    },
+    MoonPhaseTableRow {
+        args: [-1, 1, -2, 0],
+        e: 0,
+        coefficient: -0.000_02_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 3, 0, 0],
+        e: 0,
+        coefficient: -0.000_02_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 4, 0, 0],
+        e: 0,
+        coefficient: 0.000_02_f64,
+    },
+];
+
+/// Periodic corrections to the instant of full moon as per Meeus (ch. 49)
+pub const FULL_MOON_TERMS: &[MoonPhaseTableRow] = &[
+    MoonPhaseTableRow {
+        args: [0, 1, 0, 0],
+        e: 0,
+        coefficient: -0.406_14_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 0, 0, 0],
+        e: 1,
+        coefficient: 0.173_02_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 2, 0, 0],
+        e: 0,
+        coefficient: 0.016_14_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 0, 2, 0],
+        e: 0,
+        coefficient: 0.010_43_f64,
+    },
+    MoonPhaseTableRow {
+        args: [-1, 1, 0, 0],
+        e: 1,
+        coefficient: 0.007_34_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 1, 0, 0],
+        e: 1,
+        coefficient: -0.005_15_f64,
+    },
+    MoonPhaseTableRow {
+        args: [2, 0, 0, 0],
+        e: 2,
+        coefficient: 0.002_09_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 1, -2, 0],
+        e: 0,
+        coefficient: -0.001_11_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 1, 2, 0],
+        e: 0,
+        coefficient: -0.000_57_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 2, 0, 0],
+        e: 1,
+        coefficient: 0.000_56_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 3, 0, 0],
+        e: 0,
+        coefficient: -0.000_42_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 0, 2, 0],
+        e: 1,
+        coefficient: 0.000_42_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 0, -2, 0],
+        e: 1,
+        coefficient: 0.000_38_f64,
+    },
+    MoonPhaseTableRow {
+        args: [-1, 2, 0, 0],
+        e: 1,
+        coefficient: -0.000_24_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 0, 0, 1],
+        e: 0,
+        coefficient: -0.000_17_f64,
+    },
+    MoonPhaseTableRow {
+        args: [2, 1, 0, 0],
+        e: 0,
+        coefficient: -0.000_07_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 2, -2, 0],
+        e: 0,
+        coefficient: 0.000_04_f64,
+    },
+    MoonPhaseTableRow {
+        args: [3, 0, 0, 0],
+        e: 0,
+        coefficient: 0.000_04_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 1, -2, 0],
+        e: 0,
+        coefficient: 0.000_03_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 2, 2, 0],
+        e: 0,
+        coefficient: 0.000_03_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 1, 2, 0],
+        e: 0,
+        coefficient: -0.000_03_f64,
+    },
+    MoonPhaseTableRow {
+        args: [-1, 1, 2, 0],
+        e: 0,
+        coefficient: 0.000_03_f64,
+    },
+    MoonPhaseTableRow {
+        args: [-1, 1, -2, 0],
+        e: 0,
+        coefficient: -0.000_02_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 3, 0, 0],
+        e: 0,
+        coefficient: -0.000_02_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 4, 0, 0],
+        e: 0,
+        coefficient: 0.000_02_f64,
+    },
+];
+
+/// Periodic corrections to the instants of the first and last quarters as
+/// per Meeus (ch. 49)
+pub const QUARTER_MOON_TERMS: &[MoonPhaseTableRow] = &[
+    MoonPhaseTableRow {
+        args: [0, 1, 0, 0],
+        e: 0,
+        coefficient: -0.628_01_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 0, 0, 0],
+        e: 1,
+        coefficient: 0.171_72_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 1, 0, 0],
+        e: 1,
+        coefficient: -0.011_83_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 2, 0, 0],
+        e: 0,
+        coefficient: 0.008_62_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 0, 2, 0],
+        e: 0,
+        coefficient: 0.008_04_f64,
+    },
+    MoonPhaseTableRow {
+        args: [-1, 1, 0, 0],
+        e: 1,
+        coefficient: 0.004_54_f64,
+    },
+    MoonPhaseTableRow {
+        args: [2, 0, 0, 0],
+        e: 2,
+        coefficient: 0.002_04_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 1, -2, 0],
+        e: 0,
+        coefficient: -0.001_80_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 1, 2, 0],
+        e: 0,
+        coefficient: -0.000_70_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 3, 0, 0],
+        e: 0,
+        coefficient: -0.000_40_f64,
+    },
+    MoonPhaseTableRow {
+        args: [-1, 2, 0, 0],
+        e: 1,
+        coefficient: -0.000_34_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 0, 2, 0],
+        e: 1,
+        coefficient: 0.000_32_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 0, -2, 0],
+        e: 1,
+        coefficient: 0.000_32_f64,
+    },
+    MoonPhaseTableRow {
+        args: [2, 1, 0, 0],
+        e: 2,
+        coefficient: -0.000_28_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 2, 0, 0],
+        e: 1,
+        coefficient: 0.000_27_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 0, 0, 1],
+        e: 0,
+        coefficient: -0.000_17_f64,
+    },
+    MoonPhaseTableRow {
+        args: [-1, 1, -2, 0],
+        e: 0,
+        coefficient: -0.000_05_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 2, 2, 0],
+        e: 0,
+        coefficient: 0.000_04_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 1, 2, 0],
+        e: 0,
+        coefficient: -0.000_04_f64,
+    },
+    MoonPhaseTableRow {
+        args: [-2, 1, 0, 0],
+        e: 0,
+        coefficient: 0.000_04_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 1, -2, 0],
+        e: 0,
+        coefficient: 0.000_03_f64,
+    },
+    MoonPhaseTableRow {
+        args: [3, 0, 0, 0],
+        e: 0,
+        coefficient: 0.000_03_f64,
+    },
+    MoonPhaseTableRow {
+        args: [0, 2, -2, 0],
+        e: 0,
+        coefficient: 0.000_02_f64,
+    },
+    MoonPhaseTableRow {
+        args: [-1, 1, 2, 0],
+        e: 0,
+        coefficient: 0.000_02_f64,
+    },
+    MoonPhaseTableRow {
+        args: [1, 3, 0, 0],
+        e: 0,
+        coefficient: -0.000_02_f64,
+    },
+];
+
+/// Additional corrections to the instants of all moon phases as per Meeus
+/// (ch. 49)
+pub const MOON_PHASE_PLANETARY_TERMS: &[MoonPhasePlanetaryRow] = &[
+    MoonPhasePlanetaryRow {
+        a: 299.77_f64,
+        b: 0.107_408_f64,
+        c: -0.009_173_f64,
+        coefficient: 0.000_325_f64,
+    },
+    MoonPhasePlanetaryRow {
+        a: 251.88_f64,
+        b: 0.016_321_f64,
+        c: 0_f64,
+        coefficient: 0.000_165_f64,
+    },
+    MoonPhasePlanetaryRow {
+        a: 251.83_f64,
+        b: 26.651_886_f64,
+        c: 0_f64,
+        coefficient: 0.000_164_f64,
+    },
+    MoonPhasePlanetaryRow {
+        a: 349.42_f64,
+        b: 36.412_478_f64,
+        c: 0_f64,
+        coefficient: 0.000_126_f64,
+    },
+    MoonPhasePlanetaryRow {
+        a: 84.66_f64,
+        b: 18.206_239_f64,
+        c: 0_f64,
+        coefficient: 0.000_110_f64,
+    },
+    MoonPhasePlanetaryRow {
+        a: 141.74_f64,
+        b: 53.303_771_f64,
+        c: 0_f64,
+        coefficient: 0.000_062_f64,
+    },
+    MoonPhasePlanetaryRow {
+        a: 207.14_f64,
+        b: 2.453_732_f64,
+        c: 0_f64,
+        coefficient: 0.000_060_f64,
+    },
+    MoonPhasePlanetaryRow {
+        a: 154.84_f64,
+        b: 7.306_860_f64,
+        c: 0_f64,
+        coefficient: 0.000_056_f64,
+    },
+    MoonPhasePlanetaryRow {
+        a: 34.52_f64,
+        b: 27.261_239_f64,
+        c: 0_f64,
+        coefficient: 0.000_047_f64,
+    },
+    MoonPhasePlanetaryRow {
+        a: 207.19_f64,
+        b: 0.121_824_f64,
+        c: 0_f64,
+        coefficient: 0.000_042_f64,
+    },
+    MoonPhasePlanetaryRow {
+        a: 291.34_f64,
+        b: 1.844_379_f64,
+        c: 0_f64,
+        coefficient: 0.000_040_f64,
+    },
+    MoonPhasePlanetaryRow {
+        a: 161.72_f64,
+        b: 24.198_154_f64,
+        c: 0_f64,
+        coefficient: 0.000_037_f64,
+    },
+    MoonPhasePlanetaryRow {
+        a: 239.56_f64,
+        b: 25.513_099_f64,
+        c: 0_f64,
+        coefficient: 0.000_035_f64,
+    },
+    MoonPhasePlanetaryRow {
+        a: 331.55_f64,
+        b: 3.592_518_f64,
+        c: 0_f64,
+        coefficient: 0.000_023_f64,
+    },
+];
+
+/// A row from Meeus' tables of periodic terms for the instants of, and the
+/// moon's parallax at, perigee and apogee (ch. 50)
+#[derive(Debug, Copy, Clone)]
+pub struct MoonApsisTableRow {
+    /// multiples of the moon's mean elongation (D), the sun's mean anomaly
+    /// (M) and the moon's argument of latitude (F)
+    pub args: [i8; 3],
+    /// coefficient of the sine (for instants, in days) or cosine (for
+    /// parallaxes, in arcseconds) of the argument
+    pub coefficient: f64,
+    /// coefficient of the time (T) multiplying the same function
+    pub t: f64,
+}
+
+/// Periodic corrections to the instant of perigee as per Meeus (ch. 50)
+pub const PERIGEE_TERMS: &[MoonApsisTableRow] = &[
+    MoonApsisTableRow {
+        args: [2, 0, 0],
+        coefficient: -1.676_9_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [4, 0, 0],
+        coefficient: 0.458_9_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [6, 0, 0],
+        coefficient: -0.185_6_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [8, 0, 0],
+        coefficient: 0.088_3_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, -1, 0],
+        coefficient: -0.077_3_f64,
+        t: 0.000_19_f64,
+    },
+    MoonApsisTableRow {
+        args: [0, 1, 0],
+        coefficient: 0.050_2_f64,
+        t: -0.000_13_f64,
+    },
+    MoonApsisTableRow {
+        args: [10, 0, 0],
+        coefficient: -0.046_0_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [4, -1, 0],
+        coefficient: 0.042_2_f64,
+        t: -0.000_11_f64,
+    },
+    MoonApsisTableRow {
+        args: [6, -1, 0],
+        coefficient: -0.025_6_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [12, 0, 0],
+        coefficient: 0.025_3_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [1, 0, 0],
+        coefficient: 0.023_7_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [8, -1, 0],
+        coefficient: 0.016_2_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [14, 0, 0],
+        coefficient: -0.014_5_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [0, 0, 2],
+        coefficient: 0.012_9_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [3, 0, 0],
+        coefficient: -0.011_2_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [10, -1, 0],
+        coefficient: -0.010_4_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [16, 0, 0],
+        coefficient: 0.008_6_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [12, -1, 0],
+        coefficient: 0.006_9_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [5, 0, 0],
+        coefficient: 0.006_6_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, 0, 2],
+        coefficient: -0.005_3_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [18, 0, 0],
+        coefficient: -0.005_2_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [14, -1, 0],
+        coefficient: -0.004_6_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [7, 0, 0],
+        coefficient: -0.004_1_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, 1, 0],
+        coefficient: 0.004_0_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [20, 0, 0],
+        coefficient: 0.003_2_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [1, 1, 0],
+        coefficient: -0.003_2_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [16, -1, 0],
+        coefficient: 0.003_1_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [4, 1, 0],
+        coefficient: -0.002_9_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [9, 0, 0],
+        coefficient: 0.002_7_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [4, 0, 2],
+        coefficient: 0.002_7_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, -2, 0],
+        coefficient: -0.002_7_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [4, -2, 0],
+        coefficient: 0.002_4_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [6, -2, 0],
+        coefficient: -0.002_1_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [22, 0, 0],
+        coefficient: -0.002_1_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [18, -1, 0],
+        coefficient: -0.002_1_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [6, 1, 0],
+        coefficient: 0.001_9_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [11, 0, 0],
+        coefficient: -0.001_8_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [8, 1, 0],
+        coefficient: -0.001_4_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [4, 0, -2],
+        coefficient: -0.001_4_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [6, 0, 2],
+        coefficient: -0.001_4_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [3, 1, 0],
+        coefficient: 0.001_4_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [5, 1, 0],
+        coefficient: -0.001_4_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [13, 0, 0],
+        coefficient: 0.001_3_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [20, -1, 0],
+        coefficient: 0.001_3_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [3, 2, 0],
+        coefficient: 0.001_1_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [4, -2, 2],
+        coefficient: -0.001_1_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [1, 2, 0],
+        coefficient: -0.001_0_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [22, -1, 0],
+        coefficient: -0.000_9_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [0, 0, 4],
+        coefficient: -0.000_8_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [6, 0, -2],
+        coefficient: 0.000_8_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, 1, -2],
+        coefficient: 0.000_8_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [0, 2, 0],
+        coefficient: 0.000_7_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [0, -1, 2],
+        coefficient: 0.000_7_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, 0, 4],
+        coefficient: 0.000_7_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [0, -2, 2],
+        coefficient: -0.000_6_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, 2, -2],
+        coefficient: -0.000_6_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [24, 0, 0],
+        coefficient: 0.000_6_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [4, 0, -4],
+        coefficient: 0.000_5_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, 2, 0],
+        coefficient: 0.000_5_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [1, -1, 0],
+        coefficient: -0.000_4_f64,
+        t: 0_f64,
+    },
+];
+
+/// Periodic corrections to the instant of apogee as per Meeus (ch. 50)
+pub const APOGEE_TERMS: &[MoonApsisTableRow] = &[
+    MoonApsisTableRow {
+        args: [2, 0, 0],
+        coefficient: 0.439_2_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [4, 0, 0],
+        coefficient: 0.068_4_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [0, 1, 0],
+        coefficient: 0.045_6_f64,
+        t: -0.000_11_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, -1, 0],
+        coefficient: 0.042_6_f64,
+        t: -0.000_11_f64,
+    },
+    MoonApsisTableRow {
+        args: [0, 0, 2],
+        coefficient: 0.021_2_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [1, 0, 0],
+        coefficient: -0.018_9_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [6, 0, 0],
+        coefficient: 0.014_4_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [4, -1, 0],
+        coefficient: 0.011_3_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, 0, 2],
+        coefficient: 0.004_7_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [1, 1, 0],
+        coefficient: 0.003_6_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [8, 0, 0],
+        coefficient: 0.003_5_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [6, -1, 0],
+        coefficient: 0.003_4_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, 0, -2],
+        coefficient: -0.003_4_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, -2, 0],
+        coefficient: 0.002_2_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [3, 0, 0],
+        coefficient: -0.001_7_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [4, 0, 2],
+        coefficient: 0.001_3_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [8, -1, 0],
+        coefficient: 0.001_1_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [4, -2, 0],
+        coefficient: 0.001_0_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [10, 0, 0],
+        coefficient: 0.000_9_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [3, 1, 0],
+        coefficient: 0.000_7_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [0, 2, 0],
+        coefficient: 0.000_6_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, 1, 0],
+        coefficient: 0.000_5_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, 2, 0],
+        coefficient: 0.000_5_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [6, 0, 2],
+        coefficient: 0.000_4_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [6, -2, 0],
+        coefficient: 0.000_4_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [10, -1, 0],
+        coefficient: 0.000_4_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [5, 0, 0],
+        coefficient: -0.000_4_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [4, 0, -2],
+        coefficient: -0.000_4_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [0, 1, 2],
+        coefficient: 0.000_3_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [12, 0, 0],
+        coefficient: 0.000_3_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, -1, 2],
+        coefficient: 0.000_3_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [1, -1, 0],
+        coefficient: -0.000_3_f64,
+        t: 0_f64,
+    },
+];
+
+/// Periodic terms for the moon's parallax at perigee as per Meeus (ch. 50)
+pub const PERIGEE_PARALLAX_TERMS: &[MoonApsisTableRow] = &[
+    MoonApsisTableRow {
+        args: [0, 0, 0],
+        coefficient: 3_629.215_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, 0, 0],
+        coefficient: 63.224_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [4, 0, 0],
+        coefficient: -6.990_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, -1, 0],
+        coefficient: 2.834_f64,
+        t: -0.007_1_f64,
+    },
+    MoonApsisTableRow {
+        args: [6, 0, 0],
+        coefficient: 1.927_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [1, 0, 0],
+        coefficient: -1.263_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [8, 0, 0],
+        coefficient: -0.702_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [0, 1, 0],
+        coefficient: 0.696_f64,
+        t: -0.001_7_f64,
+    },
+    MoonApsisTableRow {
+        args: [0, 0, 2],
+        coefficient: -0.690_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [4, -1, 0],
+        coefficient: -0.629_f64,
+        t: 0.001_6_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, 0, -2],
+        coefficient: -0.392_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [10, 0, 0],
+        coefficient: 0.297_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [6, -1, 0],
+        coefficient: 0.260_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [3, 0, 0],
+        coefficient: 0.201_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, 1, 0],
+        coefficient: -0.161_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [1, 1, 0],
+        coefficient: 0.157_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [12, 0, 0],
+        coefficient: -0.138_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [8, -1, 0],
+        coefficient: -0.127_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, 0, 2],
+        coefficient: 0.104_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, -2, 0],
+        coefficient: 0.104_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [5, 0, 0],
+        coefficient: -0.079_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [14, 0, 0],
+        coefficient: 0.068_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [10, -1, 0],
+        coefficient: 0.067_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [4, 1, 0],
+        coefficient: 0.054_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [12, -1, 0],
+        coefficient: -0.038_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [4, -2, 0],
+        coefficient: -0.038_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [7, 0, 0],
+        coefficient: 0.037_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [4, 0, 2],
+        coefficient: -0.037_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [16, 0, 0],
+        coefficient: -0.035_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [3, 1, 0],
+        coefficient: -0.030_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [1, -1, 0],
+        coefficient: 0.029_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [6, 1, 0],
+        coefficient: -0.025_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [0, 2, 0],
+        coefficient: 0.023_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [14, -1, 0],
+        coefficient: 0.023_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, 2, 0],
+        coefficient: -0.023_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [6, -2, 0],
+        coefficient: 0.022_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, -1, -2],
+        coefficient: -0.021_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [9, 0, 0],
+        coefficient: -0.020_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [18, 0, 0],
+        coefficient: 0.019_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [6, 0, 2],
+        coefficient: 0.017_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [0, -1, 2],
+        coefficient: 0.014_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [16, -1, 0],
+        coefficient: -0.014_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [4, 0, -2],
+        coefficient: 0.013_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [8, 1, 0],
+        coefficient: 0.012_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [11, 0, 0],
+        coefficient: 0.011_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [5, 1, 0],
+        coefficient: 0.010_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [20, 0, 0],
+        coefficient: -0.010_f64,
+        t: 0_f64,
+    },
+];
+
+/// Periodic terms for the moon's parallax at apogee as per Meeus (ch. 50)
+pub const APOGEE_PARALLAX_TERMS: &[MoonApsisTableRow] = &[
+    MoonApsisTableRow {
+        args: [0, 0, 0],
+        coefficient: 3_245.251_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, 0, 0],
+        coefficient: -9.147_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [1, 0, 0],
+        coefficient: -0.841_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [0, 0, 2],
+        coefficient: 0.697_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [0, 1, 0],
+        coefficient: -0.656_f64,
+        t: 0.001_6_f64,
+    },
+    MoonApsisTableRow {
+        args: [4, 0, 0],
+        coefficient: 0.355_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, -1, 0],
+        coefficient: 0.159_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [1, 1, 0],
+        coefficient: 0.127_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [4, -1, 0],
+        coefficient: 0.065_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [6, 0, 0],
+        coefficient: 0.052_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, 1, 0],
+        coefficient: 0.043_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, 0, 2],
+        coefficient: 0.031_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, 0, -2],
+        coefficient: -0.023_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, -2, 0],
+        coefficient: 0.022_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [2, 2, 0],
+        coefficient: 0.019_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [0, 2, 0],
+        coefficient: -0.016_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [6, -1, 0],
+        coefficient: 0.014_f64,
+        t: 0_f64,
+    },
+    MoonApsisTableRow {
+        args: [8, 0, 0],
+        coefficient: 0.010_f64,
+        t: 0_f64,
+    },
+];
+
+/// A row from Meeus' table of periodic corrections to the instant of a
+/// passage of the moon through a node of its orbit (ch. 51)
+#[derive(Debug, Copy, Clone)]
+pub struct MoonNodeTableRow {
+    /// multiples of the moon's mean elongation (D), the sun's mean anomaly
+    /// (M), the moon's mean anomaly (M') and the longitude of the ascending
+    /// node of the moon's orbit (Ω)
+    pub args: [i8; 4],
+    /// power of the eccentricity correction (E) applied to the term
+    pub e: i32,
+    /// coefficient of the sine of the argument, in days
+    pub coefficient: f64,
+}
+
+/// A row from Meeus' tables of periodic corrections to the instant of the
+/// moon's greatest declination (ch. 52)
+#[derive(Debug, Copy, Clone)]
+pub struct MoonDeclinationTableRow {
+    /// multiples of the moon's mean elongation (D), the sun's mean anomaly
+    /// (M), the moon's mean anomaly (M') and the moon's argument of latitude
+    /// (F)
+    pub args: [i8; 4],
+    /// power of the eccentricity correction (E) applied to the term
+    pub e: i32,
+    /// coefficient of the argument's sine (or cosine), in days
+    pub coefficient: f64,
+    /// whether the term is a cosine, rather than a sine, of the argument
+    pub cosine: bool,
+}
+
+/// Periodic corrections to the instant of a passage of the moon through a
+/// node of its orbit as per Meeus (ch. 51)
+pub const NODE_TERMS: &[MoonNodeTableRow] = &[
+    MoonNodeTableRow {
+        args: [0, 0, 1, 0],
+        e: 0,
+        coefficient: -0.472_1_f64,
+    },
+    MoonNodeTableRow {
+        args: [2, 0, 0, 0],
+        e: 0,
+        coefficient: -0.164_9_f64,
+    },
+    MoonNodeTableRow {
+        args: [2, 0, -1, 0],
+        e: 0,
+        coefficient: -0.086_8_f64,
+    },
+    MoonNodeTableRow {
+        args: [2, 0, 1, 0],
+        e: 0,
+        coefficient: 0.008_4_f64,
+    },
+    MoonNodeTableRow {
+        args: [2, -1, 0, 0],
+        e: 1,
+        coefficient: -0.008_3_f64,
+    },
+    MoonNodeTableRow {
+        args: [2, -1, -1, 0],
+        e: 1,
+        coefficient: -0.003_9_f64,
+    },
+    MoonNodeTableRow {
+        args: [0, 0, 2, 0],
+        e: 0,
+        coefficient: 0.003_4_f64,
+    },
+    MoonNodeTableRow {
+        args: [2, 0, -2, 0],
+        e: 0,
+        coefficient: -0.003_1_f64,
+    },
+    MoonNodeTableRow {
+        args: [2, 1, 0, 0],
+        e: 1,
+        coefficient: 0.003_0_f64,
+    },
+    MoonNodeTableRow {
+        args: [0, 1, -1, 0],
+        e: 1,
+        coefficient: 0.002_8_f64,
+    },
+    MoonNodeTableRow {
+        args: [0, 1, 0, 0],
+        e: 1,
+        coefficient: 0.002_6_f64,
+    },
+    MoonNodeTableRow {
+        args: [4, 0, 0, 0],
+        e: 0,
+        coefficient: 0.002_5_f64,
+    },
+    MoonNodeTableRow {
+        args: [1, 0, 0, 0],
+        e: 0,
+        coefficient: 0.002_4_f64,
+    },
+    MoonNodeTableRow {
+        args: [0, 1, 1, 0],
+        e: 1,
+        coefficient: 0.002_2_f64,
+    },
+    MoonNodeTableRow {
+        args: [0, 0, 0, 1],
+        e: 0,
+        coefficient: 0.001_7_f64,
+    },
+    MoonNodeTableRow {
+        args: [4, 0, -1, 0],
+        e: 0,
+        coefficient: 0.001_4_f64,
+    },
+    MoonNodeTableRow {
+        args: [2, 1, -1, 0],
+        e: 1,
+        coefficient: 0.000_5_f64,
+    },
+    MoonNodeTableRow {
+        args: [2, -1, 1, 0],
+        e: 1,
+        coefficient: 0.000_4_f64,
+    },
+    MoonNodeTableRow {
+        args: [2, -2, 0, 0],
+        e: 1,
+        coefficient: -0.000_3_f64,
+    },
+    MoonNodeTableRow {
+        args: [4, -1, 0, 0],
+        e: 1,
+        coefficient: 0.000_3_f64,
+    },
+];
+
+/// Periodic corrections to the instant of greatest northern declination
+/// as per Meeus (ch. 52)
+pub const NORTHERN_DECLINATION_TERMS: &[MoonDeclinationTableRow] = &[
+    MoonDeclinationTableRow {
+        args: [0, 0, 0, 1],
+        e: 0,
+        coefficient: 0.897_5_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 1, 0],
+        e: 0,
+        coefficient: -0.472_6_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 0, 2],
+        e: 0,
+        coefficient: -0.103_0_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, -1, 0],
+        e: 0,
+        coefficient: -0.097_6_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 1, -1],
+        e: 0,
+        coefficient: -0.046_2_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 1, 1],
+        e: 0,
+        coefficient: -0.046_1_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, 0, 0],
+        e: 0,
+        coefficient: -0.043_8_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 1, 0, 0],
+        e: 1,
+        coefficient: 0.016_2_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 0, 3],
+        e: 0,
+        coefficient: -0.015_7_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 1, 2],
+        e: 0,
+        coefficient: 0.014_5_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, 0, -1],
+        e: 0,
+        coefficient: 0.013_6_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, -1, -1],
+        e: 0,
+        coefficient: -0.009_5_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, -1, 1],
+        e: 0,
+        coefficient: -0.009_1_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, 0, 1],
+        e: 0,
+        coefficient: -0.008_9_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 2, 0],
+        e: 0,
+        coefficient: 0.007_5_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 1, -2],
+        e: 0,
+        coefficient: -0.006_8_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 2, -1],
+        e: 0,
+        coefficient: 0.006_1_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 1, 3],
+        e: 0,
+        coefficient: -0.004_7_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [2, -1, -1, 0],
+        e: 1,
+        coefficient: -0.004_3_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 1, -2],
+        e: 0,
+        coefficient: -0.004_0_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, -2, 0],
+        e: 0,
+        coefficient: -0.003_7_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 0, 1],
+        e: 0,
+        coefficient: 0.003_1_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, 1, 0],
+        e: 0,
+        coefficient: 0.003_0_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 1, 2],
+        e: 0,
+        coefficient: -0.002_9_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [2, -1, 0, 0],
+        e: 1,
+        coefficient: -0.002_7_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 1, -1, 0],
+        e: 1,
+        coefficient: -0.002_4_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 1, -3],
+        e: 0,
+        coefficient: -0.002_1_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 2, 1],
+        e: 0,
+        coefficient: 0.001_9_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, -2, -1],
+        e: 0,
+        coefficient: 0.001_8_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 0, 3],
+        e: 0,
+        coefficient: 0.001_8_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 1, 3],
+        e: 0,
+        coefficient: 0.001_7_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 2, 0],
+        e: 0,
+        coefficient: 0.001_7_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, -1, 0],
+        e: 0,
+        coefficient: -0.001_4_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, 1, 1],
+        e: 0,
+        coefficient: 0.001_3_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 1, 0],
+        e: 0,
+        coefficient: 0.001_3_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 3, 1],
+        e: 0,
+        coefficient: 0.001_2_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, -1, 1],
+        e: 0,
+        coefficient: 0.001_1_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, -2, 0],
+        e: 0,
+        coefficient: -0.001_1_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [1, 0, 0, 1],
+        e: 0,
+        coefficient: 0.001_0_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 1, 1, 0],
+        e: 1,
+        coefficient: 0.001_0_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, 0, -2],
+        e: 0,
+        coefficient: -0.000_9_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 2, 1],
+        e: 0,
+        coefficient: 0.000_7_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 3, 1],
+        e: 0,
+        coefficient: -0.000_7_f64,
+        cosine: true,
+    },
+];
+
+/// Periodic corrections to the instant of greatest southern declination
+/// as per Meeus (ch. 52)
+pub const SOUTHERN_DECLINATION_TERMS: &[MoonDeclinationTableRow] = &[
+    MoonDeclinationTableRow {
+        args: [0, 0, 0, 1],
+        e: 0,
+        coefficient: -0.897_5_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 1, 0],
+        e: 0,
+        coefficient: -0.472_6_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 0, 2],
+        e: 0,
+        coefficient: -0.103_0_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, -1, 0],
+        e: 0,
+        coefficient: -0.097_6_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 1, -1],
+        e: 0,
+        coefficient: 0.054_1_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 1, 1],
+        e: 0,
+        coefficient: 0.051_6_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, 0, 0],
+        e: 0,
+        coefficient: -0.043_8_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 1, 0, 0],
+        e: 1,
+        coefficient: 0.011_2_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 0, 3],
+        e: 0,
+        coefficient: 0.015_7_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 1, 2],
+        e: 0,
+        coefficient: 0.002_3_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, 0, -1],
+        e: 0,
+        coefficient: -0.013_6_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, -1, -1],
+        e: 0,
+        coefficient: 0.011_0_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, -1, 1],
+        e: 0,
+        coefficient: 0.009_1_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, 0, 1],
+        e: 0,
+        coefficient: 0.008_9_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 2, 0],
+        e: 0,
+        coefficient: 0.007_5_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 1, -2],
+        e: 0,
+        coefficient: -0.003_0_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 2, -1],
+        e: 0,
+        coefficient: -0.006_1_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 1, 3],
+        e: 0,
+        coefficient: -0.004_7_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [2, -1, -1, 0],
+        e: 1,
+        coefficient: -0.004_3_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 1, -2],
+        e: 0,
+        coefficient: 0.004_0_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, -2, 0],
+        e: 0,
+        coefficient: -0.003_7_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 0, 1],
+        e: 0,
+        coefficient: -0.003_1_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, 1, 0],
+        e: 0,
+        coefficient: 0.003_0_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 1, 2],
+        e: 0,
+        coefficient: 0.002_9_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [2, -1, 0, 0],
+        e: 1,
+        coefficient: -0.002_9_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 1, -1, 0],
+        e: 1,
+        coefficient: -0.002_7_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 1, -3],
+        e: 0,
+        coefficient: 0.002_4_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 2, 1],
+        e: 0,
+        coefficient: -0.002_1_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, -2, -1],
+        e: 0,
+        coefficient: -0.001_9_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 0, 3],
+        e: 0,
+        coefficient: -0.000_6_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 1, 3],
+        e: 0,
+        coefficient: -0.001_8_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 2, 0],
+        e: 0,
+        coefficient: -0.001_7_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, -1, 0],
+        e: 0,
+        coefficient: 0.001_7_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, 1, 1],
+        e: 0,
+        coefficient: -0.001_4_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 1, 0],
+        e: 0,
+        coefficient: -0.001_3_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 3, 1],
+        e: 0,
+        coefficient: -0.001_2_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, -1, 1],
+        e: 0,
+        coefficient: -0.001_1_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, -2, 0],
+        e: 0,
+        coefficient: 0.001_1_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [1, 0, 0, 1],
+        e: 0,
+        coefficient: -0.001_0_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 1, 1, 0],
+        e: 1,
+        coefficient: -0.001_0_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [2, 0, 0, -2],
+        e: 0,
+        coefficient: 0.000_9_f64,
+        cosine: false,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 2, 1],
+        e: 0,
+        coefficient: -0.000_7_f64,
+        cosine: true,
+    },
+    MoonDeclinationTableRow {
+        args: [0, 0, 3, 1],
+        e: 0,
+        coefficient: 0.000_7_f64,
+        cosine: true,
+    },
+];
@@ -0,0 +1,323 @@
+use chrono::{DateTime, TimeZone};
+use moon::periodic_terms::{self, MoonPhaseTableRow};
+use time::nasa::{jule_to_dt, jule_to_jul};
+use utils::cycles::{nearest_event, CycleEvents};
+
+/// Julian Ephemeris Day of the mean new moon of 2000 January 6 (lunation 0)
+pub(crate) const LUNATION_EPOCH: f64 = 2_451_550.097_66_f64;
+/// Mean length of a synodic month, in days
+pub(crate) const SYNODIC_MONTH: f64 = 29.530_588_861_f64;
+
+/// A principal phase of the moon
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Phase {
+    /// New moon, when the moon and sun have the same apparent longitude
+    NewMoon,
+    /// First quarter, when the moon is 90° east of the sun
+    FirstQuarter,
+    /// Full moon, when the moon is opposite the sun
+    FullMoon,
+    /// Last quarter, when the moon is 90° west of the sun
+    LastQuarter,
+}
+
+impl Phase {
+    /// All of the phases, in the order they occur during a lunation
+    pub const ALL: [Phase; 4] = [
+        Phase::NewMoon,
+        Phase::FirstQuarter,
+        Phase::FullMoon,
+        Phase::LastQuarter,
+    ];
+
+    /// The fraction of a lunation at which the phase occurs
+    pub fn fraction(self) -> f64 {
+        match self {
+            Phase::NewMoon => 0_f64,
+            Phase::FirstQuarter => 0.25_f64,
+            Phase::FullMoon => 0.5_f64,
+            Phase::LastQuarter => 0.75_f64,
+        }
+    }
+}
+
+/// The instant of a moon phase
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PhaseInstant {
+    /// the phase
+    pub phase: Phase,
+    /// lunation number, counting from the new moon of 2000 January 6 (Meeus'
+    /// k, without the fraction for the phase)
+    pub lunation: i64,
+    /// Julian Ephemeris Day of the phase
+    pub jde: f64,
+}
+
+impl PhaseInstant {
+    /// Calculates the instant of a phase during a lunation
+    pub fn new(lunation: i64, phase: Phase) -> PhaseInstant {
+        PhaseInstant {
+            phase,
+            lunation,
+            jde: phase_jule(lunation, phase),
+        }
+    }
+
+    /// The Julian Day (UT) of the phase
+    pub fn jul(&self) -> f64 {
+        jule_to_jul(self.jde)
+    }
+
+    /// The instant of the phase in the given timezone, or `None` if it
+    /// can't be represented by `chrono`
+    pub fn dt<Tz: TimeZone>(&self, tz: &Tz) -> Option<DateTime<Tz>> {
+        jule_to_dt(self.jde, tz)
+    }
+}
+
+/// Meeus' arguments for a phase `k` lunations after the new moon of 2000
+/// January 6, in radians
+pub(crate) struct LunationArguments {
+    /// eccentricity of the earth's orbit, relative to its value in 2000 (E)
+    pub(crate) eccentricity: f64,
+    /// mean anomaly of the sun (M)
+    pub(crate) m: f64,
+    /// mean anomaly of the moon (M′)
+    pub(crate) m_prime: f64,
+    /// argument of latitude of the moon (F)
+    pub(crate) f: f64,
+    /// longitude of the ascending node of the moon's orbit (Ω)
+    pub(crate) omega: f64,
+}
+
+/// Calculates the Julian Ephemeris Day of the mean phase `k` lunations
+/// after the new moon of 2000 January 6
+///
+/// Obtained from Meeus (ch. 49)
+pub(crate) fn mean_phase_jule(k: f64) -> f64 {
+    let t = k / 1_236.85_f64;
+
+    LUNATION_EPOCH + SYNODIC_MONTH * k + 0.000_154_37_f64 * t.powi(2) -
+        0.000_000_150_f64 * t.powi(3) + 0.000_000_000_73_f64 * t.powi(4)
+}
+
+/// Calculates Meeus' arguments for the phase `k` lunations after the new
+/// moon of 2000 January 6
+///
+/// Obtained from Meeus (ch. 49)
+pub(crate) fn lunation_arguments(k: f64) -> LunationArguments {
+    let t = k / 1_236.85_f64;
+
+    LunationArguments {
+        eccentricity: 1_f64 - 0.002_516_f64 * t - 0.000_007_4_f64 * t.powi(2),
+        m: (2.553_4_f64 + 29.105_356_70_f64 * k - 0.000_001_4_f64 * t.powi(2) -
+                0.000_000_11_f64 * t.powi(3))
+            .to_radians(),
+        m_prime: (201.564_3_f64 + 385.816_935_28_f64 * k + 0.010_758_2_f64 * t.powi(2) +
+                      0.000_012_38_f64 * t.powi(3) -
+                      0.000_000_058_f64 * t.powi(4))
+            .to_radians(),
+        f: (160.710_8_f64 + 390.670_502_84_f64 * k - 0.001_611_8_f64 * t.powi(2) -
+                0.000_002_27_f64 * t.powi(3) + 0.000_000_011_f64 * t.powi(4))
+            .to_radians(),
+        omega: (124.774_6_f64 - 1.563_755_88_f64 * k + 0.002_067_2_f64 * t.powi(2) +
+                    0.000_002_15_f64 * t.powi(3))
+            .to_radians(),
+    }
+}
+
+fn sum_phase_terms(
+    terms: &[MoonPhaseTableRow],
+    args: &[f64; 4],
+    eccentricity: f64,
+) -> f64 {
+    terms.iter().fold(0_f64, |curr, row| {
+        let argument: f64 = row.args
+            .iter()
+            .zip(args.iter())
+            .map(|(multiple, arg)| f64::from(*multiple) * arg)
+            .sum();
+
+        curr + row.coefficient * eccentricity.powi(row.e) * argument.sin()
+    })
+}
+
+/// Calculates the Julian Ephemeris Day of a moon phase during a lunation
+///
+/// Lunations are numbered from the new moon of 2000 January 6 (so the new
+/// moons of 2017 are lunations 211 to 222). Uses the method from Meeus
+/// (ch. 49), accurate to within a few seconds for several centuries
+/// either side of 2000.
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::moon::{phase_jule, Phase};
+///
+/// // Meeus example 49.a: 1977 February 18, 3h37m42s TD
+/// let jde = phase_jule(-283, Phase::NewMoon);
+/// assert_eq!(2_443_192.651_18, (jde * 100_000_f64).round() / 100_000_f64);
+/// ```
+pub fn phase_jule(lunation: i64, phase: Phase) -> f64 {
+    let k = lunation as f64 + phase.fraction();
+    let t = k / 1_236.85_f64;
+
+    let mean = mean_phase_jule(k);
+    let LunationArguments {
+        eccentricity,
+        m,
+        m_prime,
+        f,
+        omega,
+    } = lunation_arguments(k);
+    let args = [m, m_prime, f, omega];
+
+    let periodic = match phase {
+        Phase::NewMoon => sum_phase_terms(periodic_terms::NEW_MOON_TERMS, &args, eccentricity),
+        Phase::FullMoon => sum_phase_terms(periodic_terms::FULL_MOON_TERMS, &args, eccentricity),
+        Phase::FirstQuarter | Phase::LastQuarter => {
+            let w = 0.003_06_f64 - 0.000_38_f64 * eccentricity * m.cos() +
+                0.000_26_f64 * m_prime.cos() - 0.000_02_f64 * (m_prime - m).cos() +
+                0.000_02_f64 * (m_prime + m).cos() + 0.000_02_f64 * (2_f64 * f).cos();
+            let w = if phase == Phase::FirstQuarter { w } else { -w };
+
+            sum_phase_terms(periodic_terms::QUARTER_MOON_TERMS, &args, eccentricity) + w
+        }
+    };
+
+    let planetary = periodic_terms::MOON_PHASE_PLANETARY_TERMS.iter().fold(
+        0_f64,
+        |curr, row| {
+            curr + row.coefficient * (row.a + row.b * k + row.c * t.powi(2)).to_radians().sin()
+        },
+    );
+
+    mean + periodic + planetary
+}
+
+/// Finds the instant of a moon phase nearest to a Julian Day (UT)
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::moon::{nearest_phase, Phase};
+///
+/// // 2017 August 21
+/// let new_moon = nearest_phase(2_457_986.5_f64, Phase::NewMoon);
+/// assert_eq!(218, new_moon.lunation);
+/// ```
+pub fn nearest_phase(jd: f64, phase: Phase) -> PhaseInstant {
+    let estimate = ((jd - LUNATION_EPOCH) / SYNODIC_MONTH - phase.fraction()).round() as i64;
+
+    nearest_event(
+        estimate,
+        jd,
+        |lunation| PhaseInstant::new(lunation, phase),
+        |instant| instant.jde,
+    )
+}
+
+/// An iterator over the moon phases between two instants, in order
+#[derive(Debug, Clone)]
+pub struct Phases {
+    events: CycleEvents<Phase>,
+}
+
+impl Iterator for Phases {
+    type Item = PhaseInstant;
+
+    fn next(&mut self) -> Option<PhaseInstant> {
+        self.events.next_event(PhaseInstant::new, |instant| instant.jde)
+    }
+}
+
+/// Iterates over the moon phases from one Julian Day (UT, inclusive) to
+/// another (exclusive)
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::moon::{phases, Phase};
+///
+/// // all of the phases in 2017
+/// let full_moons = phases(2_457_754.5_f64, 2_458_119.5_f64)
+///     .filter(|instant| instant.phase == Phase::FullMoon)
+///     .count();
+/// assert_eq!(12, full_moons);
+/// ```
+pub fn phases(start_jd: f64, end_jd: f64) -> Phases {
+    let lunation = ((start_jd - LUNATION_EPOCH) / SYNODIC_MONTH).floor() as i64 - 1;
+
+    Phases { events: CycleEvents::new(&Phase::ALL, lunation, start_jd, end_jd) }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use moon::*;
+    use time::dt_to_jul;
+
+    #[test]
+    fn meeus_examples() {
+        // example 49.a: 1977 February 18, 3h37m42s TD
+        let new_moon = phase_jule(-283, Phase::NewMoon);
+        assert!((new_moon - 2_443_192.651_18_f64).abs() < 0.000_01);
+
+        // example 49.b: 2044 January 21, 23h47m TD
+        let last_quarter = phase_jule(544, Phase::LastQuarter);
+        assert!((last_quarter - 2_467_636.491_86_f64).abs() < 0.000_1);
+    }
+
+    #[test]
+    fn phases_of_august_2017() {
+        // instants from the US Naval Observatory, to the minute
+        let expected = [
+            (Phase::LastQuarter, Utc.with_ymd_and_hms(2017, 8, 15, 1, 15, 0)),
+            (Phase::NewMoon, Utc.with_ymd_and_hms(2017, 8, 21, 18, 30, 0)),
+            (Phase::FirstQuarter, Utc.with_ymd_and_hms(2017, 8, 29, 8, 13, 0)),
+            (Phase::FullMoon, Utc.with_ymd_and_hms(2017, 9, 6, 7, 3, 0)),
+        ];
+
+        let start = dt_to_jul(&Utc.with_ymd_and_hms(2017, 8, 10, 0, 0, 0).unwrap());
+        let end = dt_to_jul(&Utc.with_ymd_and_hms(2017, 9, 10, 0, 0, 0).unwrap());
+        let actual: Vec<PhaseInstant> = phases(start, end).collect();
+
+        assert_eq!(expected.len(), actual.len());
+        for (&(phase, instant), found) in expected.iter().zip(actual.iter()) {
+            assert_eq!(phase, found.phase);
+
+            let difference = found.dt(&Utc).unwrap() - instant.unwrap();
+            assert!(difference.num_seconds().abs() <= 60, "{:?}", found);
+        }
+    }
+
+    #[test]
+    fn nearest_phase_finds_each_phase() {
+        let jd = dt_to_jul(&Utc.with_ymd_and_hms(2017, 8, 25, 0, 0, 0).unwrap());
+
+        for &phase in Phase::ALL.iter() {
+            let instant = nearest_phase(jd, phase);
+
+            assert_eq!(phase, instant.phase);
+            assert!((instant.jul() - jd).abs() <= 29.6_f64 / 2_f64);
+        }
+    }
+
+    #[test]
+    fn handles_nan() {
+        assert_eq!(Phase::NewMoon, nearest_phase(f64::NAN, Phase::NewMoon).phase);
+        assert_eq!(0, phases(2_451_545_f64, f64::NAN).count());
+    }
+
+    #[test]
+    fn phases_are_ordered() {
+        let all: Vec<PhaseInstant> = phases(2_451_545_f64, 2_451_545_f64 + 3_652.5_f64).collect();
+
+        for pair in all.windows(2) {
+            assert!(pair[0].jde < pair[1].jde);
+            assert!(pair[1].jde - pair[0].jde > 6_f64 && pair[1].jde - pair[0].jde < 8.5_f64);
+        }
+        // roughly 124 lunations per decade
+        assert!(all.len() >= 4 * 123 && all.len() <= 4 * 125);
+    }
+}
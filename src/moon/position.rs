@@ -0,0 +1,51 @@
+use earth;
+use moon;
+use time::{jul_to_julc, julc_to_julm};
+use utils::coordinates::{Ecliptic, Equatorial};
+
+/// Calculates the apparent geocentric ecliptic position of the moon for a
+/// given Julian Ephemeris Day
+///
+/// # Examples:
+/// ```
+/// use meealgi::moon::apparent_ecliptic;
+///
+/// // 1992 April 12, 0h TD (Meeus example 47.a)
+/// let position = apparent_ecliptic(2_448_724.5_f64);
+/// assert_eq!(133.167_26, (position.longitude.to_degrees() * 1e5).round() / 1e5);
+/// ```
+pub fn apparent_ecliptic(jde: f64) -> Ecliptic {
+    let jce = jul_to_julc(jde);
+
+    Ecliptic {
+        longitude: moon::apparent_longitude(
+            moon::geocentric_longitude(jce),
+            earth::nutation_longitude(jce),
+        ),
+        latitude: moon::geocentric_latitude(jce),
+    }
+}
+
+/// Calculates the apparent geocentric equatorial position of the moon for
+/// a given Julian Ephemeris Day
+///
+/// # Examples:
+/// ```
+/// use meealgi::moon::apparent_equatorial;
+///
+/// // 1992 April 12, 0h TD (Meeus example 47.a)
+/// let position = apparent_equatorial(2_448_724.5_f64);
+/// let alpha = position.right_ascension.to_degrees();
+/// let delta = position.declination.to_degrees();
+/// assert_eq!(134.688_47, (alpha * 1e5).round() / 1e5);
+/// assert_eq!(13.768_37, (delta * 1e5).round() / 1e5);
+/// ```
+pub fn apparent_equatorial(jde: f64) -> Equatorial {
+    let jce = jul_to_julc(jde);
+    let jme = julc_to_julm(jce);
+
+    apparent_ecliptic(jde).to_equatorial(earth::true_obliquity(
+        earth::mean_obliquity(jme),
+        earth::nutation_obliquity(jce),
+    ))
+}
@@ -0,0 +1,223 @@
+use chrono::{DateTime, Duration, NaiveDate, TimeZone};
+use earth;
+use moon;
+use time::{apparent_sidereal_time, dt_to_jul, jul_to_dt, jul_to_julc, julc_to_julm,
+           mean_sidereal_time};
+use time::nasa::jul_to_jule;
+use utils::coordinates::Observer;
+use utils::rise_set::{find_rise_transit_set, RiseTransitSet};
+
+/// Atmospheric refraction at the horizon, in radians (34')
+const HORIZON_REFRACTION: f64 = 0.009_890_199_f64;
+
+/// Calculates the altitude of the moon's centre when its upper limb appears
+/// on the horizon (h0), in radians, given its equatorial horizontal
+/// parallax
+///
+/// Accounts for the parallax, the moon's semidiameter and atmospheric
+/// refraction, as per Meeus (ch. 15).
+///
+/// # Examples:
+/// ```
+/// use meealgi::moon::standard_altitude;
+///
+/// let h0 = standard_altitude(0.95_f64.to_radians());
+/// assert_eq!(0.124, (h0.to_degrees() * 1_000_f64).round() / 1_000_f64);
+/// ```
+pub fn standard_altitude(parallax: f64) -> f64 {
+    0.727_5_f64 * parallax - HORIZON_REFRACTION
+}
+
+/// The moon's altitude above its standard altitude, and its hour angle, for
+/// an observer at a Julian Day (UT)
+fn moon_altitude_hour_angle(jd: f64, observer: &Observer) -> (f64, f64) {
+    let jde = jul_to_jule(jd);
+    let jce = jul_to_julc(jde);
+
+    let position = moon::apparent_equatorial(jde);
+    let parallax = moon::equatorial_horizontal_parallax(moon::distance(jce));
+    let sidereal_time = apparent_sidereal_time(
+        mean_sidereal_time(jd),
+        earth::nutation_longitude(jce),
+        earth::true_obliquity(
+            earth::mean_obliquity(julc_to_julm(jce)),
+            earth::nutation_obliquity(jce),
+        ),
+    );
+
+    (
+        position.altitude(sidereal_time, observer) - standard_altitude(parallax),
+        position.hour_angle(sidereal_time, observer),
+    )
+}
+
+/// Finds the first moonrise, moon transit and moonset between two Julian
+/// Days (UT)
+///
+/// Any event that doesn't occur in the period (such as the moon not rising
+/// on a given day, or not setting at high latitudes) is `None`.
+///
+/// # Examples:
+/// ```
+/// use meealgi::moon::rise_transit_set_jul;
+/// use meealgi::utils::coordinates::Observer;
+///
+/// let greenwich = Observer {
+///     latitude: 51.477_8_f64.to_radians(),
+///     longitude: 0_f64,
+/// };
+/// // 2017 August 30
+/// let events = rise_transit_set_jul(2_457_995.5_f64, 2_457_996.5_f64, &greenwich);
+/// assert!(events.transit.is_some());
+/// ```
+pub fn rise_transit_set_jul(start_jd: f64, end_jd: f64, observer: &Observer) -> RiseTransitSet<f64> {
+    find_rise_transit_set(start_jd, end_jd, |jd| moon_altitude_hour_angle(jd, observer))
+}
+
+/// The first instant of a local date in a timezone
+fn local_midnight<Tz: TimeZone>(date: NaiveDate, tz: &Tz) -> Option<DateTime<Tz>> {
+    let midnight = date.and_hms_opt(0, 0, 0)?;
+
+    // midnight is skipped by some daylight saving transitions
+    tz.from_local_datetime(&midnight)
+        .earliest()
+        .or_else(|| tz.from_local_datetime(&(midnight + Duration::hours(1))).earliest())
+}
+
+/// Finds the moonrise, moon transit and moonset on a local date for an
+/// observer, in the given timezone
+///
+/// # Examples:
+/// ```
+/// # extern crate chrono;
+/// # extern crate meealgi;
+/// # use chrono::{FixedOffset, NaiveDate};
+/// # use meealgi::moon::rise_transit_set;
+/// # use meealgi::utils::coordinates::Observer;
+/// # fn main() {
+/// let sydney = Observer {
+///     latitude: -33.86_f64.to_radians(),
+///     longitude: 151.21_f64.to_radians(),
+/// };
+/// let aest = FixedOffset::east_opt(10 * 3600).unwrap();
+/// let date = NaiveDate::from_ymd_opt(2017, 8, 30).unwrap();
+///
+/// let events = rise_transit_set(date, &sydney, &aest);
+/// assert_eq!(date, events.transit.unwrap().date_naive());
+/// # }
+/// ```
+pub fn rise_transit_set<Tz: TimeZone>(
+    date: NaiveDate,
+    observer: &Observer,
+    tz: &Tz,
+) -> RiseTransitSet<DateTime<Tz>> {
+    let start = local_midnight(date, tz);
+    let end = date.succ_opt().and_then(|next| local_midnight(next, tz));
+
+    match (start, end) {
+        (Some(start), Some(end)) => {
+            rise_transit_set_jul(dt_to_jul(&start), dt_to_jul(&end), observer)
+                .and_then(|jd| jul_to_dt(jd, tz))
+        }
+        _ => RiseTransitSet {
+            rise: None,
+            transit: None,
+            set: None,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, Utc};
+    use moon::*;
+    use super::moon_altitude_hour_angle;
+    use utils::coordinates::Observer;
+
+    fn greenwich() -> Observer {
+        Observer {
+            latitude: 51.477_8_f64.to_radians(),
+            longitude: 0_f64,
+        }
+    }
+
+    #[test]
+    fn altitude_is_standard_at_rise_and_set() {
+        let start = 2_457_995.5_f64;
+
+        for day in 0..30 {
+            let jd = start + f64::from(day);
+            let events = rise_transit_set_jul(jd, jd + 1_f64, &greenwich());
+
+            for event in [events.rise, events.set].iter().filter_map(|event| *event) {
+                assert!(event >= jd && event < jd + 1_f64);
+
+                let (altitude, _) = moon_altitude_hour_angle(event, &greenwich());
+                assert!(altitude.abs() < 0.000_1, "{} {}", event, altitude);
+            }
+
+            if let Some(transit) = events.transit {
+                let (_, hour_angle) = moon_altitude_hour_angle(transit, &greenwich());
+                assert!(hour_angle.sin().abs() < 0.000_1);
+            }
+        }
+    }
+
+    #[test]
+    fn skips_missing_events() {
+        // the moon transits roughly 50 minutes later each day, so skips a
+        // day roughly once a lunation (and likewise rises and sets)
+        let mut missing = [0, 0, 0];
+
+        for day in 0..60 {
+            let date = NaiveDate::from_ymd_opt(2017, 1, 1).unwrap() + ::chrono::Duration::days(day);
+            let events = rise_transit_set(date, &greenwich(), &Utc);
+
+            missing[0] += events.rise.is_none() as usize;
+            missing[1] += events.transit.is_none() as usize;
+            missing[2] += events.set.is_none() as usize;
+        }
+
+        for count in missing.iter() {
+            assert!(*count >= 1 && *count <= 3, "{:?}", missing);
+        }
+    }
+
+    #[test]
+    fn events_before_gregorian_reform() {
+        // dates before 1582 October 15 are in the Julian calendar
+        let date = NaiveDate::from_ymd_opt(1000, 3, 1).unwrap();
+        let events = rise_transit_set(date, &greenwich(), &Utc);
+
+        let found: Vec<_> = [events.rise, events.transit, events.set]
+            .iter()
+            .filter_map(|event| *event)
+            .collect();
+        assert!(!found.is_empty());
+        for event in found {
+            assert_eq!(date, event.date_naive(), "{}", event);
+        }
+    }
+
+    #[test]
+    fn circumpolar_at_high_latitudes() {
+        let svalbard = Observer {
+            latitude: 78.22_f64.to_radians(),
+            longitude: 15.65_f64.to_radians(),
+        };
+
+        // over a month, the moon spends days always above (and always
+        // below) the horizon, but still transits
+        let mut never_rises_or_sets = 0;
+        for day in 0..30 {
+            let jd = 2_457_754.5_f64 + f64::from(day);
+            let events = rise_transit_set_jul(jd, jd + 1_f64, &svalbard);
+
+            if events.rise.is_none() && events.set.is_none() {
+                never_rises_or_sets += 1;
+            }
+        }
+
+        assert!(never_rises_or_sets >= 10, "{}", never_rises_or_sets);
+    }
+}
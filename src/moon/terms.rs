@@ -0,0 +1,284 @@
+use earth;
+use moon::periodic_terms;
+use utils::angles::limit_radians;
+
+/// Mean distance between the centres of the earth and moon, in km
+const MEAN_DISTANCE: f64 = 385_000.56_f64;
+
+fn polynomial(coefficients: &[f64], jul_cent_ephemeris: f64) -> f64 {
+    coefficients.iter().rev().fold(0_f64, |curr, coeff| {
+        curr * jul_cent_ephemeris + coeff
+    })
+}
+
+/// Calculates the moon's mean longitude (L'), in radians
+///
+/// Obtained from Meeus (ch. 47)
+pub fn mean_longitude(jul_cent_ephemeris: f64) -> f64 {
+    limit_radians(
+        polynomial(
+            &[
+                218.316_447_7,
+                481_267.881_234_21,
+                -0.001_578_6,
+                1_f64 / 538_841_f64,
+                -1_f64 / 65_194_000_f64,
+            ],
+            jul_cent_ephemeris,
+        ).to_radians(),
+    )
+}
+
+/// Calculates the mean elongation of the moon (D), in radians
+///
+/// Obtained from Meeus (ch. 47)
+pub fn mean_elongation(jul_cent_ephemeris: f64) -> f64 {
+    limit_radians(
+        polynomial(
+            &[
+                297.850_192_1,
+                445_267.111_403_4,
+                -0.001_881_9,
+                1_f64 / 545_868_f64,
+                -1_f64 / 113_065_000_f64,
+            ],
+            jul_cent_ephemeris,
+        ).to_radians(),
+    )
+}
+
+/// Calculates the sun's mean anomaly (M), in radians
+///
+/// Obtained from Meeus (ch. 47)
+pub fn sun_mean_anomaly(jul_cent_ephemeris: f64) -> f64 {
+    limit_radians(
+        polynomial(
+            &[
+                357.529_109_2,
+                35_999.050_290_9,
+                -0.000_153_6,
+                1_f64 / 24_490_000_f64,
+            ],
+            jul_cent_ephemeris,
+        ).to_radians(),
+    )
+}
+
+/// Calculates the moon's mean anomaly (M'), in radians
+///
+/// Obtained from Meeus (ch. 47)
+pub fn mean_anomaly(jul_cent_ephemeris: f64) -> f64 {
+    limit_radians(
+        polynomial(
+            &[
+                134.963_396_4,
+                477_198.867_505_5,
+                0.008_741_4,
+                1_f64 / 69_699_f64,
+                -1_f64 / 14_712_000_f64,
+            ],
+            jul_cent_ephemeris,
+        ).to_radians(),
+    )
+}
+
+/// Calculates the moon's argument of latitude (F), in radians
+///
+/// Obtained from Meeus (ch. 47)
+pub fn argument_of_latitude(jul_cent_ephemeris: f64) -> f64 {
+    limit_radians(
+        polynomial(
+            &[
+                93.272_095_0,
+                483_202.017_523_3,
+                -0.003_653_9,
+                -1_f64 / 3_526_000_f64,
+                1_f64 / 863_310_000_f64,
+            ],
+            jul_cent_ephemeris,
+        ).to_radians(),
+    )
+}
+
+/// Calculates the longitude of the moon's mean ascending node (Ω), in
+/// radians
+///
+/// Obtained from Meeus (ch. 47)
+pub fn ascending_node_longitude(jul_cent_ephemeris: f64) -> f64 {
+    limit_radians(
+        polynomial(
+            &[
+                125.044_547_9,
+                -1_934.136_289_1,
+                0.002_075_4,
+                1_f64 / 467_441_f64,
+                -1_f64 / 60_616_000_f64,
+            ],
+            jul_cent_ephemeris,
+        ).to_radians(),
+    )
+}
+
+/// Calculates the eccentricity correction (E) for terms involving the sun's
+/// mean anomaly
+fn eccentricity_correction(jul_cent_ephemeris: f64) -> f64 {
+    polynomial(&[1_f64, -0.002_516, -0.000_007_4], jul_cent_ephemeris)
+}
+
+/// Calculates D, M, M' and F, the arguments of the periodic terms
+fn term_fundamentals(jul_cent_ephemeris: f64) -> [f64; 4] {
+    [
+        mean_elongation(jul_cent_ephemeris),
+        sun_mean_anomaly(jul_cent_ephemeris),
+        mean_anomaly(jul_cent_ephemeris),
+        argument_of_latitude(jul_cent_ephemeris),
+    ]
+}
+
+/// Sums a periodic term's argument, and finds its eccentricity correction
+fn term_argument(args: &[i8; 4], fundamentals: &[f64; 4], eccentricity: f64) -> (f64, f64) {
+    let argument = args.iter()
+        .zip(fundamentals.iter())
+        .map(|(multiple, fundamental)| f64::from(*multiple) * fundamental)
+        .sum();
+
+    (argument, eccentricity.powi(i32::from(args[1].abs())))
+}
+
+/// Sums the periodic terms for the longitude (Σl) and distance (Σr)
+fn longitude_distance_sums(jul_cent_ephemeris: f64) -> (f64, f64) {
+    let fundamentals = term_fundamentals(jul_cent_ephemeris);
+    let eccentricity = eccentricity_correction(jul_cent_ephemeris);
+
+    periodic_terms::MOON_LONGITUDE_DISTANCE_TERMS.iter().fold(
+        (0_f64, 0_f64),
+        |(l, r), row| {
+            let (argument, correction) = term_argument(&row.args, &fundamentals, eccentricity);
+            (
+                l + row.l * correction * argument.sin(),
+                r + row.r * correction * argument.cos(),
+            )
+        },
+    )
+}
+
+/// Calculates the moon's geocentric longitude (λ, referred to the mean
+/// equinox of the date), in radians
+///
+/// Obtained from Meeus (ch. 47)
+///
+/// # Examples:
+/// ```
+/// use meealgi::moon::geocentric_longitude;
+///
+/// // 1992 April 12, 0h TD (Meeus example 47.a)
+/// let lambda = geocentric_longitude(-0.077_221_081_451_f64);
+/// assert_eq!(133.162_655, (lambda.to_degrees() * 1_000_000_f64).round() / 1_000_000_f64);
+/// ```
+pub fn geocentric_longitude(jul_cent_ephemeris: f64) -> f64 {
+    let jce = jul_cent_ephemeris;
+    let l_prime = mean_longitude(jce);
+    let f = argument_of_latitude(jce);
+    let a1 = (119.75_f64 + 131.849_f64 * jce).to_radians();
+    let a2 = (53.09_f64 + 479_264.290_f64 * jce).to_radians();
+
+    let sum = longitude_distance_sums(jce).0 + 3_958_f64 * a1.sin() + 1_962_f64 * (l_prime - f).sin() +
+        318_f64 * a2.sin();
+
+    limit_radians(l_prime + (sum / 1_000_000_f64).to_radians())
+}
+
+/// Calculates the moon's geocentric latitude (β), in radians
+///
+/// Obtained from Meeus (ch. 47)
+///
+/// # Examples:
+/// ```
+/// use meealgi::moon::geocentric_latitude;
+///
+/// // 1992 April 12, 0h TD (Meeus example 47.a)
+/// let beta = geocentric_latitude(-0.077_221_081_451_f64);
+/// assert_eq!(-3.229_126, (beta.to_degrees() * 1_000_000_f64).round() / 1_000_000_f64);
+/// ```
+pub fn geocentric_latitude(jul_cent_ephemeris: f64) -> f64 {
+    let jce = jul_cent_ephemeris;
+    let l_prime = mean_longitude(jce);
+    let m_prime = mean_anomaly(jce);
+    let f = argument_of_latitude(jce);
+    let a1 = (119.75_f64 + 131.849_f64 * jce).to_radians();
+    let a3 = (313.45_f64 + 481_266.484_f64 * jce).to_radians();
+
+    let fundamentals = term_fundamentals(jce);
+    let eccentricity = eccentricity_correction(jce);
+
+    let sum = periodic_terms::MOON_LATITUDE_TERMS.iter().fold(0_f64, |curr, row| {
+        let (argument, correction) = term_argument(&row.args, &fundamentals, eccentricity);
+        curr + row.b * correction * argument.sin()
+    }) - 2_235_f64 * l_prime.sin() + 382_f64 * a3.sin() +
+        175_f64 * (a1 - f).sin() + 175_f64 * (a1 + f).sin() +
+        127_f64 * (l_prime - m_prime).sin() - 115_f64 * (l_prime + m_prime).sin();
+
+    (sum / 1_000_000_f64).to_radians()
+}
+
+/// Calculates the distance between the centres of the earth and moon, in km
+///
+/// Obtained from Meeus (ch. 47)
+///
+/// # Examples:
+/// ```
+/// use meealgi::moon::distance;
+///
+/// // 1992 April 12, 0h TD (Meeus example 47.a)
+/// let delta = distance(-0.077_221_081_451_f64);
+/// assert_eq!(368_409.7, (delta * 10_f64).round() / 10_f64);
+/// ```
+pub fn distance(jul_cent_ephemeris: f64) -> f64 {
+    MEAN_DISTANCE + longitude_distance_sums(jul_cent_ephemeris).1 / 1_000_f64
+}
+
+/// Calculates the moon's equatorial horizontal parallax (π), in radians,
+/// given the distance between the centres of the earth and moon (in km)
+///
+/// # Examples:
+/// ```
+/// use meealgi::moon::equatorial_horizontal_parallax;
+///
+/// // Meeus example 47.a
+/// let parallax = equatorial_horizontal_parallax(368_409.7_f64);
+/// assert_eq!(0.991_990, (parallax.to_degrees() * 1_000_000_f64).round() / 1_000_000_f64);
+/// ```
+pub fn equatorial_horizontal_parallax(distance: f64) -> f64 {
+    (earth::EQUATORIAL_RADIUS / distance).asin()
+}
+
+/// Calculates the distance between the centres of the earth and moon, in
+/// km, given the moon's equatorial horizontal parallax (π, in radians)
+///
+/// # Examples:
+/// ```
+/// use meealgi::moon::parallax_distance;
+///
+/// let distance = parallax_distance(0.991_990_f64.to_radians());
+/// assert_eq!(368_410, distance.round() as i64);
+/// ```
+pub fn parallax_distance(parallax: f64) -> f64 {
+    earth::EQUATORIAL_RADIUS / parallax.sin()
+}
+
+/// Calculates the moon's apparent longitude, in radians, given its
+/// geocentric longitude and the nutation in longitude
+///
+/// # Examples:
+/// ```
+/// use meealgi::earth::nutation_longitude;
+/// use meealgi::moon::{apparent_longitude, geocentric_longitude};
+///
+/// // 1992 April 12, 0h TD (Meeus example 47.a)
+/// let jce = -0.077_221_081_451_f64;
+/// let lambda = apparent_longitude(geocentric_longitude(jce), nutation_longitude(jce));
+/// assert_eq!(133.167_26, (lambda.to_degrees() * 100_000_f64).round() / 100_000_f64);
+/// ```
+pub fn apparent_longitude(geocentric_long: f64, nutation_long: f64) -> f64 {
+    limit_radians(geocentric_long + nutation_long)
+}
@@ -0,0 +1,441 @@
+use chrono::{DateTime, TimeZone};
+use time::nasa::{jul_to_jule, jule_to_dt, jule_to_jul};
+use utils::bisection::bisect_change;
+
+/// Ratio of Jupiter's equatorial to polar radius, by which y coordinates
+/// are stretched when testing whether a satellite is against the disk
+const FLATTENING_FACTOR: f64 = 1.071_374_f64;
+/// Step, in days, used when scanning for satellite events (ten minutes,
+/// well under the shortest transit or occultation)
+const SEARCH_STEP: f64 = 1_f64 / 144_f64;
+/// Iterations used to refine an event, narrowing the step to well under a
+/// second
+const REFINEMENTS: usize = 12;
+
+/// One of Jupiter's four Galilean satellites
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum GalileanSatellite {
+    /// Io (satellite I)
+    Io,
+    /// Europa (satellite II)
+    Europa,
+    /// Ganymede (satellite III)
+    Ganymede,
+    /// Callisto (satellite IV)
+    Callisto,
+}
+
+impl GalileanSatellite {
+    /// All of the Galilean satellites, in order of distance from Jupiter
+    pub const ALL: [GalileanSatellite; 4] = [
+        GalileanSatellite::Io,
+        GalileanSatellite::Europa,
+        GalileanSatellite::Ganymede,
+        GalileanSatellite::Callisto,
+    ];
+}
+
+/// A phenomenon of a Galilean satellite, as seen from the earth
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SatellitePhenomenon {
+    /// The satellite crossing in front of Jupiter's disk
+    Transit,
+    /// The satellite hidden behind Jupiter's disk
+    Occultation,
+    /// The satellite's shadow falling on Jupiter's disk
+    ShadowTransit,
+    /// The satellite within Jupiter's shadow
+    Eclipse,
+}
+
+impl SatellitePhenomenon {
+    /// All of the satellite phenomena
+    pub const ALL: [SatellitePhenomenon; 4] = [
+        SatellitePhenomenon::Transit,
+        SatellitePhenomenon::Occultation,
+        SatellitePhenomenon::ShadowTransit,
+        SatellitePhenomenon::Eclipse,
+    ];
+}
+
+/// The apparent position of a Galilean satellite relative to Jupiter, in
+/// units of Jupiter's equatorial radius
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SatellitePosition {
+    /// the satellite
+    pub satellite: GalileanSatellite,
+    /// distance along Jupiter's equator, positive to the west (X)
+    pub x: f64,
+    /// distance along Jupiter's axis, positive to the north (Y)
+    pub y: f64,
+    /// distance along the line of sight, positive when the satellite is
+    /// farther from the earth than Jupiter
+    pub z: f64,
+    /// whether the satellite is in front of Jupiter's disk
+    pub transit: bool,
+    /// whether the satellite is hidden behind Jupiter's disk
+    pub occultation: bool,
+    /// whether the satellite's shadow is on Jupiter's disk
+    pub shadow_transit: bool,
+    /// whether the satellite is within Jupiter's shadow
+    pub eclipse: bool,
+}
+
+impl SatellitePosition {
+    /// Whether the phenomenon is in progress
+    pub fn in_progress(&self, phenomenon: SatellitePhenomenon) -> bool {
+        match phenomenon {
+            SatellitePhenomenon::Transit => self.transit,
+            SatellitePhenomenon::Occultation => self.occultation,
+            SatellitePhenomenon::ShadowTransit => self.shadow_transit,
+            SatellitePhenomenon::Eclipse => self.eclipse,
+        }
+    }
+}
+
+/// Whether a point, seen from a distance, lies within Jupiter's flattened
+/// disk
+fn within_disk(x: f64, y: f64) -> bool {
+    x.powi(2) + (y * FLATTENING_FACTOR).powi(2) < 1_f64
+}
+
+/// Calculates the apparent positions of the Galilean satellites relative to
+/// Jupiter for a given Julian Ephemeris Day, in order of distance from
+/// Jupiter
+///
+/// Uses Meeus' low-accuracy method, which places the satellites to within
+/// a few hundredths of Jupiter's radius, so that events are found to within
+/// a few minutes. The phenomena are for the centre of the satellite (or its
+/// shadow) crossing the limb, as seen from the earth and the sun.
+///
+/// Obtained from Meeus (ch. 44)
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::planet::galilean_positions;
+///
+/// // Meeus example 44.a: 1992 December 16, 0h UT
+/// let positions = galilean_positions(2_448_972.500_685);
+/// assert_eq!(-3.44, (positions[0].x * 100_f64).round() / 100_f64);
+/// assert_eq!(7.44, (positions[1].x * 100_f64).round() / 100_f64);
+/// ```
+pub fn galilean_positions(jde: f64) -> [SatellitePosition; 4] {
+    let d = jde - 2_451_545_f64;
+
+    // Jupiter's orbit, and its distance (Δ) and phase angle (ψ) from the
+    // earth
+    let v = (172.74_f64 + 0.001_115_88_f64 * d).to_radians();
+    let m = (357.529_f64 + 0.985_600_3_f64 * d).to_radians();
+    let n = (20.02_f64 + 0.083_085_3_f64 * d + 0.329_f64 * v.sin()).to_radians();
+    let j = (66.115_f64 + 0.902_517_9_f64 * d - 0.329_f64 * v.sin()).to_radians();
+    let a = (1.915_f64 * m.sin() + 0.020_f64 * (2_f64 * m).sin()).to_radians();
+    let b = (5.555_f64 * n.sin() + 0.168_f64 * (2_f64 * n).sin()).to_radians();
+    let k = j + a - b;
+    let earth_radius = 1.000_14_f64 - 0.016_71_f64 * m.cos() - 0.000_14_f64 * (2_f64 * m).cos();
+    let r = 5.208_72_f64 - 0.252_08_f64 * n.cos() - 0.006_11_f64 * (2_f64 * n).cos();
+    let distance = (r.powi(2) + earth_radius.powi(2) - 2_f64 * r * earth_radius * k.cos()).sqrt();
+    let phase = (earth_radius * k.sin() / distance).asin();
+
+    // the satellites' longitudes, measured from the inferior conjunction
+    // with Jupiter, at the instant their light left them
+    let t = d - distance / 173_f64;
+    let correction = phase - b;
+    let u1 = (163.806_9_f64 + 203.405_864_6_f64 * t).to_radians() + correction;
+    let u2 = (358.414_f64 + 101.291_633_5_f64 * t).to_radians() + correction;
+    let u3 = (5.717_6_f64 + 50.234_518_f64 * t).to_radians() + correction;
+    let u4 = (224.809_2_f64 + 21.487_98_f64 * t).to_radians() + correction;
+    let g = (331.18_f64 + 50.310_482_f64 * t).to_radians();
+    let h = (87.45_f64 + 21.569_231_f64 * t).to_radians();
+
+    let orbits = [
+        (
+            u1 + (0.473_f64 * (2_f64 * (u1 - u2)).sin()).to_radians(),
+            5.905_7_f64 - 0.024_4_f64 * (2_f64 * (u1 - u2)).cos(),
+        ),
+        (
+            u2 + (1.065_f64 * (2_f64 * (u2 - u3)).sin()).to_radians(),
+            9.396_6_f64 - 0.088_2_f64 * (2_f64 * (u2 - u3)).cos(),
+        ),
+        (
+            u3 + (0.165_f64 * g.sin()).to_radians(),
+            14.988_3_f64 - 0.021_6_f64 * g.cos(),
+        ),
+        (
+            u4 + (0.843_f64 * h.sin()).to_radians(),
+            26.362_7_f64 - 0.193_9_f64 * h.cos(),
+        ),
+    ];
+
+    // the planetocentric declinations of the sun (DS) and earth (DE)
+    let lambda = (34.35_f64 + 0.083_091_f64 * d + 0.329_f64 * v.sin()).to_radians() + b;
+    let sun_declination = (3.12_f64 * (lambda + 42.8_f64.to_radians()).sin()).to_radians();
+    let earth_declination = sun_declination -
+        (2.22_f64 * phase.sin() * (lambda + 22_f64.to_radians()).cos() +
+             1.30_f64 * (r - distance) * (lambda - 100.5_f64.to_radians()).sin() / distance)
+            .to_radians();
+
+    let position = |index: usize| {
+        let (u, radius) = orbits[index];
+
+        // as seen from the earth
+        let x = radius * u.sin();
+        let y = -radius * u.cos() * earth_declination.sin();
+        let z = -radius * u.cos();
+        let against_disk = within_disk(x, y);
+
+        // as seen from the sun
+        let u_sun = u - phase;
+        let x_sun = radius * u_sun.sin();
+        let y_sun = -radius * u_sun.cos() * sun_declination.sin();
+        let in_line = within_disk(x_sun, y_sun);
+
+        SatellitePosition {
+            satellite: GalileanSatellite::ALL[index],
+            x,
+            y,
+            z,
+            transit: against_disk && z < 0_f64,
+            occultation: against_disk && z > 0_f64,
+            shadow_transit: in_line && u_sun.cos() > 0_f64,
+            eclipse: in_line && u_sun.cos() < 0_f64,
+        }
+    };
+
+    [position(0), position(1), position(2), position(3)]
+}
+
+/// The beginning or end of a phenomenon of a Galilean satellite
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SatelliteEvent {
+    /// the satellite
+    pub satellite: GalileanSatellite,
+    /// the phenomenon
+    pub phenomenon: SatellitePhenomenon,
+    /// whether the phenomenon begins (rather than ends)
+    pub beginning: bool,
+    /// Julian Ephemeris Day of the event
+    pub jde: f64,
+}
+
+impl SatelliteEvent {
+    /// The Julian Day (UT) of the event
+    pub fn jul(&self) -> f64 {
+        jule_to_jul(self.jde)
+    }
+
+    /// The instant of the event in the given timezone, or `None` if it can't
+    /// be represented by `chrono`
+    pub fn dt<Tz: TimeZone>(&self, tz: &Tz) -> Option<DateTime<Tz>> {
+        jule_to_dt(self.jde, tz)
+    }
+}
+
+/// Narrows down the instant a satellite's phenomenon begins or ends,
+/// between two Julian Ephemeris Days either side of it
+fn refine_event(index: usize, phenomenon: SatellitePhenomenon, from: f64, to: f64) -> f64 {
+    bisect_change(
+        |jde| galilean_positions(jde)[index].in_progress(phenomenon),
+        from,
+        to,
+        REFINEMENTS,
+    )
+}
+
+/// An iterator over the events of the Galilean satellites between two
+/// instants, in order
+#[derive(Debug, Clone)]
+pub struct SatelliteEvents {
+    jde: f64,
+    end_jde: f64,
+    positions: [SatellitePosition; 4],
+    // events found in the latest step, latest first
+    pending: Vec<SatelliteEvent>,
+}
+
+impl Iterator for SatelliteEvents {
+    type Item = SatelliteEvent;
+
+    fn next(&mut self) -> Option<SatelliteEvent> {
+        loop {
+            if let Some(event) = self.pending.pop() {
+                return Some(event);
+            } else if self.jde >= self.end_jde {
+                return None;
+            }
+
+            let next_jde = (self.jde + SEARCH_STEP).min(self.end_jde);
+            let positions = galilean_positions(next_jde);
+
+            for (index, (before, after)) in self.positions.iter().zip(&positions).enumerate() {
+                for &phenomenon in &SatellitePhenomenon::ALL {
+                    let beginning = after.in_progress(phenomenon);
+                    if before.in_progress(phenomenon) != beginning {
+                        self.pending.push(SatelliteEvent {
+                            satellite: after.satellite,
+                            phenomenon,
+                            beginning,
+                            jde: refine_event(index, phenomenon, self.jde, next_jde),
+                        });
+                    }
+                }
+            }
+            self.pending.sort_by(|a, b| b.jde.total_cmp(&a.jde));
+
+            self.jde = next_jde;
+            self.positions = positions;
+        }
+    }
+}
+
+/// Iterates over the beginnings and ends of the transits, occultations,
+/// shadow transits and eclipses of the Galilean satellites, from one Julian
+/// Day (UT, inclusive) to another (exclusive)
+///
+/// There are no events if either Julian Day isn't finite.
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::planet::{satellite_events, GalileanSatellite, SatellitePhenomenon};
+///
+/// // Io transits Jupiter once every 1.77 days
+/// let transits = satellite_events(2_458_000.5, 2_458_030.5)
+///     .filter(|event| {
+///         event.satellite == GalileanSatellite::Io &&
+///             event.phenomenon == SatellitePhenomenon::Transit && event.beginning
+///     })
+///     .count();
+/// assert!(transits == 16 || transits == 17);
+/// ```
+pub fn satellite_events(start_jd: f64, end_jd: f64) -> SatelliteEvents {
+    // the end of a search with non-finite bounds would never be reached, so
+    // search an empty range instead
+    let (start_jd, end_jd) = if start_jd.is_finite() && end_jd.is_finite() {
+        (start_jd, end_jd)
+    } else {
+        (0_f64, 0_f64)
+    };
+    let jde = jul_to_jule(start_jd);
+
+    SatelliteEvents {
+        jde,
+        end_jde: jul_to_jule(end_jd),
+        positions: galilean_positions(jde),
+        pending: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use planet::*;
+
+    #[test]
+    fn meeus_example_44_a() {
+        let positions = galilean_positions(2_448_972.500_685_f64);
+        let expected = [(-3.44_f64, 0.21_f64), (7.44, 0.25), (1.24, 0.65), (7.08, 1.10)];
+
+        for (position, &(x, y)) in positions.iter().zip(&expected) {
+            assert!((position.x - x).abs() < 0.01, "{:?}", position);
+            assert!((position.y - y).abs() < 0.01, "{:?}", position);
+        }
+    }
+
+    #[test]
+    fn triple_shadow_transit_of_2015() {
+        // 2015 January 24, before opposition, so each shadow reached the
+        // disk ahead of its satellite: Io's shadow from 4h35m to 6h54m UT,
+        // and Europa's from 6h28m UT (as published by Sky & Telescope)
+        let events: Vec<SatelliteEvent> = satellite_events(2_457_046.5, 2_457_047_f64).collect();
+        let find = |satellite: GalileanSatellite, phenomenon: SatellitePhenomenon, beginning| {
+            events
+                .iter()
+                .find(|event| {
+                    event.satellite == satellite && event.phenomenon == phenomenon &&
+                        event.beginning == beginning
+                })
+                .map(|event| (event.jul() - 2_457_046.5_f64) * 1_440_f64)
+                .unwrap()
+        };
+
+        let io_shadow = find(GalileanSatellite::Io, SatellitePhenomenon::ShadowTransit, true);
+        let io_shadow_end = find(GalileanSatellite::Io, SatellitePhenomenon::ShadowTransit, false);
+        let europa_shadow =
+            find(GalileanSatellite::Europa, SatellitePhenomenon::ShadowTransit, true);
+        assert!((io_shadow - 275_f64).abs() < 5_f64, "{}", io_shadow);
+        assert!((io_shadow_end - 414_f64).abs() < 5_f64, "{}", io_shadow_end);
+        assert!((europa_shadow - 388_f64).abs() < 5_f64, "{}", europa_shadow);
+
+        let io_transit = find(GalileanSatellite::Io, SatellitePhenomenon::Transit, true);
+        let io_transit_end = find(GalileanSatellite::Io, SatellitePhenomenon::Transit, false);
+        assert!(io_shadow < io_transit && io_shadow_end < io_transit_end);
+    }
+
+    #[test]
+    fn events_alternate() {
+        let events: Vec<SatelliteEvent> = satellite_events(2_458_000.5_f64, 2_458_060.5_f64)
+            .collect();
+
+        for pair in events.windows(2) {
+            assert!(pair[0].jde <= pair[1].jde);
+        }
+
+        for &satellite in &GalileanSatellite::ALL {
+            for &phenomenon in &SatellitePhenomenon::ALL {
+                let mut matching = events.iter().filter(|event| {
+                    event.satellite == satellite && event.phenomenon == phenomenon
+                });
+                let first = matching.next().map(|event| event.beginning);
+
+                for (event, beginning) in matching.zip(
+                    (0..).map(|step| first.unwrap() == (step % 2 == 1)),
+                )
+                {
+                    assert_eq!(beginning, event.beginning, "{:?}", event);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn events_match_positions() {
+        for event in satellite_events(2_458_000.5_f64, 2_458_010.5_f64) {
+            let index = GalileanSatellite::ALL
+                .iter()
+                .position(|&satellite| satellite == event.satellite)
+                .unwrap();
+            let before = galilean_positions(event.jde - 0.001_f64)[index];
+            let after = galilean_positions(event.jde + 0.001_f64)[index];
+
+            assert_eq!(!event.beginning, before.in_progress(event.phenomenon));
+            assert_eq!(event.beginning, after.in_progress(event.phenomenon));
+        }
+    }
+
+    #[test]
+    fn transits_last_a_few_hours() {
+        // an Io transit lasts up to about 2.3 hours
+        let mut start = None;
+        for event in satellite_events(2_458_000.5_f64, 2_458_030.5_f64) {
+            if event.satellite != GalileanSatellite::Io ||
+                event.phenomenon != SatellitePhenomenon::Transit
+            {
+                continue;
+            }
+
+            if event.beginning {
+                start = Some(event.jde);
+            } else if let Some(jde) = start {
+                let hours = (event.jde - jde) * 24_f64;
+                assert!(hours > 1.5 && hours < 2.4, "{}", hours);
+            }
+        }
+    }
+
+    #[test]
+    fn no_events_for_non_finite_bounds() {
+        assert_eq!(0, satellite_events(2_458_000.5_f64, f64::NAN).count());
+        assert_eq!(0, satellite_events(f64::NAN, 2_458_000.5_f64).count());
+        assert_eq!(0, satellite_events(2_458_000.5_f64, f64::INFINITY).count());
+    }
+}
@@ -0,0 +1,170 @@
+use moon::illuminated_fraction;
+use planet::saturn::ring_tilt;
+use planet::{apparent_position, Planet};
+
+/// A planet's illumination and brightness, as seen from the centre of the
+/// earth
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Illumination {
+    /// geocentric elongation of the planet from the sun (ψ), in radians
+    pub elongation: f64,
+    /// phase angle (i), in radians
+    pub phase_angle: f64,
+    /// illuminated fraction of the disk (k), from 0 to 1
+    pub illuminated_fraction: f64,
+    /// apparent visual magnitude (including Saturn's rings)
+    pub magnitude: f64,
+}
+
+/// Calculates a planet's phase angle (i), in radians, given its distance
+/// from the sun (r), its distance from the earth (Δ) and the earth's
+/// distance from the sun (R), all in AU
+///
+/// Obtained from Meeus (ch. 41)
+///
+/// # Examples:
+/// ```
+/// use meealgi::planet::phase_angle;
+///
+/// // Venus on 1992 December 20 (Meeus example 41.a)
+/// let i = phase_angle(0.724_604_f64, 0.910_947_f64, 0.983_824_f64);
+/// assert_eq!(72.96, (i.to_degrees() * 100_f64).round() / 100_f64);
+/// ```
+pub fn phase_angle(sun_distance: f64, earth_distance: f64, earth_sun_distance: f64) -> f64 {
+    ((sun_distance.powi(2) + earth_distance.powi(2) - earth_sun_distance.powi(2)) /
+         (2_f64 * sun_distance * earth_distance))
+        .clamp(-1_f64, 1_f64)
+        .acos()
+}
+
+/// Calculates the visual magnitude of a planet's disk, given its distances
+/// from the sun (r) and earth (Δ) in AU, and its phase angle
+///
+/// Uses the formulae from the Astronomical Almanac, as given by Meeus
+/// (ch. 41). For Saturn this excludes the rings, which are accounted for by
+/// `saturn_ring_magnitude`.
+///
+/// # Examples:
+/// ```
+/// use meealgi::planet::{magnitude, Planet};
+///
+/// // Venus on 1992 December 20 (Meeus example 41.a, which gets -3.8 from
+/// // Müller's older formula)
+/// let m = magnitude(Planet::Venus, 0.724_604_f64, 0.910_947_f64, 72.96_f64.to_radians());
+/// assert_eq!(-4.2, (m * 10_f64).round() / 10_f64);
+/// ```
+pub fn magnitude(planet: Planet, sun_distance: f64, earth_distance: f64, phase_angle: f64) -> f64 {
+    let distance = 5_f64 * (sun_distance * earth_distance).log10();
+    let i = phase_angle.to_degrees();
+
+    distance +
+        match planet {
+            Planet::Mercury => {
+                -0.42_f64 + 0.038_f64 * i - 0.000_273_f64 * i.powi(2) + 0.000_002_f64 * i.powi(3)
+            }
+            Planet::Venus => {
+                -4.4_f64 + 0.000_9_f64 * i + 0.000_239_f64 * i.powi(2) -
+                    0.000_000_65_f64 * i.powi(3)
+            }
+            Planet::Mars => -1.52_f64 + 0.016_f64 * i,
+            Planet::Jupiter => -9.4_f64 + 0.005_f64 * i,
+            Planet::Saturn => -8.88_f64,
+            Planet::Uranus => -7.19_f64,
+            Planet::Neptune => -6.87_f64,
+        }
+}
+
+/// Calculates the change in Saturn's magnitude due to its rings, given the
+/// saturnicentric latitude of the earth referred to the ring plane (B) and
+/// the difference between the saturnicentric longitudes of the sun and
+/// earth (ΔU), in radians
+///
+/// Obtained from Meeus (ch. 41)
+///
+/// # Examples:
+/// ```
+/// use meealgi::planet::saturn_ring_magnitude;
+///
+/// // the rings seen edge-on add nothing
+/// assert_eq!(0_f64, saturn_ring_magnitude(0_f64, 0_f64));
+/// ```
+pub fn saturn_ring_magnitude(ring_tilt: f64, longitude_difference: f64) -> f64 {
+    let sin_b = ring_tilt.sin().abs();
+
+    0.044_f64 * longitude_difference.to_degrees().abs() - 2.6_f64 * sin_b +
+        1.25_f64 * sin_b.powi(2)
+}
+
+/// Calculates a planet's illumination and magnitude for a given Julian
+/// Ephemeris Day
+///
+/// # Examples:
+/// ```
+/// use meealgi::planet::{illumination, Planet};
+///
+/// // Venus on 1992 December 20, 0h TD (Meeus example 41.a)
+/// let venus = illumination(Planet::Venus, 2_448_976.5_f64);
+/// assert_eq!(0.647, (venus.illuminated_fraction * 1_000_f64).round() / 1_000_f64);
+/// assert_eq!(-4.2, (venus.magnitude * 10_f64).round() / 10_f64);
+/// ```
+pub fn illumination(planet: Planet, jde: f64) -> Illumination {
+    let position = apparent_position(planet, jde);
+    let phase_angle = phase_angle(
+        position.sun_distance,
+        position.distance,
+        position.earth_distance,
+    );
+
+    let mut magnitude = magnitude(planet, position.sun_distance, position.distance, phase_angle);
+    if planet == Planet::Saturn {
+        let (tilt, longitude_difference) = ring_tilt(jde);
+        magnitude += saturn_ring_magnitude(tilt, longitude_difference);
+    }
+
+    Illumination {
+        elongation: position.elongation,
+        phase_angle,
+        illuminated_fraction: illuminated_fraction(phase_angle),
+        magnitude,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use planet::*;
+
+    #[test]
+    fn meeus_example_41_a() {
+        let venus = illumination(Planet::Venus, 2_448_976.5_f64);
+
+        assert!((venus.phase_angle.to_degrees() - 72.96_f64).abs() < 0.01);
+        assert!((venus.illuminated_fraction - 0.647_f64).abs() < 0.001);
+        // Meeus gets -3.8 from Müller's older formula
+        assert!((venus.magnitude + 4.2_f64).abs() < 0.05);
+    }
+
+    #[test]
+    fn outer_planets_are_almost_fully_lit() {
+        for &planet in &[Planet::Jupiter, Planet::Saturn, Planet::Uranus, Planet::Neptune] {
+            for step in 0..10 {
+                let jde = 2_458_000.5_f64 + 100_f64 * f64::from(step);
+                let illumination = illumination(planet, jde);
+
+                assert!(illumination.illuminated_fraction > 0.99, "{:?}", planet);
+            }
+        }
+    }
+
+    #[test]
+    fn magnitudes_at_opposition() {
+        // Mars at its 2018 opposition, Jupiter at its 2017 opposition, and
+        // Saturn at its 2017 opposition (with the rings wide open)
+        let mars = illumination(Planet::Mars, 2_458_326.7_f64);
+        let jupiter = illumination(Planet::Jupiter, 2_457_851.4_f64);
+        let saturn = illumination(Planet::Saturn, 2_457_919.6_f64);
+
+        assert!((mars.magnitude + 2.8_f64).abs() < 0.1);
+        assert!((jupiter.magnitude + 2.5_f64).abs() < 0.1);
+        assert!(saturn.magnitude.abs() < 0.1);
+    }
+}
@@ -0,0 +1,183 @@
+use earth;
+use planet::{geometric_position, heliocentric_latitude, heliocentric_longitude, Planet};
+use time::{jul_to_julc, julc_to_julm};
+use utils::angles::limit_radians;
+use utils::coordinates::{Ecliptic, Equatorial};
+
+/// Quantities for the physical observation of Jupiter, in radians
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct JupiterPhysicalEphemeris {
+    /// planetocentric declination of the earth (DE)
+    pub earth_declination: f64,
+    /// planetocentric declination of the sun (DS)
+    pub sun_declination: f64,
+    /// position angle of the northern rotation pole (P), eastward from north
+    pub position_angle: f64,
+    /// longitude of the central meridian in System I (ω1), which applies to
+    /// the equatorial zone
+    pub system_i_central_meridian: f64,
+    /// longitude of the central meridian in System II (ω2), which applies to
+    /// the rest of the disk
+    pub system_ii_central_meridian: f64,
+}
+
+/// Corrects equatorial coordinates for nutation, given the nutation in
+/// longitude (Δψ) and obliquity (Δε) and the obliquity of the ecliptic (ε)
+///
+/// Obtained from Meeus (ch. 23)
+fn nutate(position: Equatorial, nutation_longitude: f64, nutation_obliquity: f64, obliquity: f64)
+          -> Equatorial {
+    let Equatorial { right_ascension: alpha, declination: delta } = position;
+
+    Equatorial {
+        right_ascension: alpha +
+            (obliquity.cos() + obliquity.sin() * alpha.sin() * delta.tan()) * nutation_longitude -
+            alpha.cos() * delta.tan() * nutation_obliquity,
+        declination: delta + obliquity.sin() * alpha.cos() * nutation_longitude +
+            alpha.sin() * nutation_obliquity,
+    }
+}
+
+/// Calculates the physical ephemeris of Jupiter for a given Julian Ephemeris
+/// Day
+///
+/// Obtained from Meeus (ch. 43, the more accurate method)
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::planet::jupiter_physical_ephemeris;
+///
+/// // Meeus example 43.b: 1992 December 16, 0h UT
+/// let jupiter = jupiter_physical_ephemeris(2_448_972.500_685);
+/// assert_eq!(-2.48, (jupiter.earth_declination.to_degrees() * 100_f64).round() / 100_f64);
+/// assert_eq!(24.80, (jupiter.position_angle.to_degrees() * 100_f64).round() / 100_f64);
+/// ```
+pub fn jupiter_physical_ephemeris(jde: f64) -> JupiterPhysicalEphemeris {
+    let jce = jul_to_julc(jde);
+    let jme = julc_to_julm(jce);
+    let days = jde - 2_433_282.5_f64;
+    let centuries = days / 36_525_f64;
+
+    // the northern pole of rotation, referred to the equinox of J2000, and
+    // the prime meridians of both systems
+    let pole = Equatorial {
+        right_ascension: (268_f64 + 0.106_1_f64 * centuries).to_radians(),
+        declination: (64.5_f64 - 0.016_4_f64 * centuries).to_radians(),
+    };
+    let system_i = (17.710_f64 + 877.900_035_39_f64 * days).to_radians();
+    let system_ii = (16.838_f64 + 870.270_035_39_f64 * days).to_radians();
+
+    let earth_longitude = earth::heliocentric_longitude(jme);
+    let earth_latitude = earth::heliocentric_latitude(jme);
+
+    // Jupiter's position at t - τ, when the light left it
+    let (_, distance, light_time, r, earth_radius) = geometric_position(Planet::Jupiter, jde);
+    let tau = julc_to_julm(jul_to_julc(jde - light_time));
+    let b = heliocentric_latitude(Planet::Jupiter, tau);
+
+    // correct the longitude for the sun's aberration as seen from Jupiter
+    let l = heliocentric_longitude(Planet::Jupiter, tau) -
+        (0.012_990_f64 * distance / r.powi(2)).to_radians();
+    let x = r * b.cos() * l.cos() - earth_radius * earth_longitude.cos();
+    let y = r * b.cos() * l.sin() - earth_radius * earth_longitude.sin();
+    let z = r * b.sin() - earth_radius * earth_latitude.sin();
+    let distance = (x.powi(2) + y.powi(2) + z.powi(2)).sqrt();
+
+    let mean_obliquity = earth::mean_obliquity(jme);
+    let sun = Ecliptic { longitude: l, latitude: b }.to_equatorial(mean_obliquity);
+    let sun_declination = (-pole.declination.sin() * sun.declination.sin() -
+                               pole.declination.cos() * sun.declination.cos() *
+                                   (pole.right_ascension - sun.right_ascension).cos())
+        .asin();
+
+    let u = y * mean_obliquity.cos() - z * mean_obliquity.sin();
+    let v = y * mean_obliquity.sin() + z * mean_obliquity.cos();
+    let geocentric = Equatorial {
+        right_ascension: u.atan2(x),
+        declination: v.atan2((x.powi(2) + u.powi(2)).sqrt()),
+    };
+    let ra_difference = pole.right_ascension - geocentric.right_ascension;
+    let zeta = (pole.declination.sin() * geocentric.declination.cos() * ra_difference.cos() -
+                    geocentric.declination.sin() * pole.declination.cos())
+        .atan2(geocentric.declination.cos() * ra_difference.sin());
+    let earth_declination = (-pole.declination.sin() * geocentric.declination.sin() -
+                                 pole.declination.cos() * geocentric.declination.cos() *
+                                     ra_difference.cos())
+        .asin();
+
+    // the phase correction, towards the illuminated limb
+    let phase = (2_f64 * r * distance + earth_radius.powi(2) - r.powi(2) - distance.powi(2)) /
+        (4_f64 * r * distance);
+    let phase = phase.abs() * (l - earth_longitude).sin().signum();
+
+    let system_i_central_meridian =
+        system_i - zeta - (5.070_33_f64 * distance).to_radians() + phase;
+    let system_ii_central_meridian =
+        system_ii - zeta - (5.026_26_f64 * distance).to_radians() + phase;
+
+    // apparent position of Jupiter, with aberration and nutation
+    let aberration = 0.005_693_f64.to_radians();
+    let Equatorial { right_ascension: alpha, declination: delta } = geocentric;
+    let aberrated = Equatorial {
+        right_ascension: alpha +
+            aberration *
+                (alpha.cos() * earth_longitude.cos() * mean_obliquity.cos() +
+                     alpha.sin() * earth_longitude.sin()) /
+                delta.cos(),
+        declination: delta +
+            aberration *
+                (earth_longitude.cos() * mean_obliquity.cos() *
+                     (mean_obliquity.tan() * delta.cos() - alpha.sin() * delta.sin()) +
+                     alpha.cos() * delta.sin() * earth_longitude.sin()),
+    };
+
+    let nutation_longitude = earth::nutation_longitude(jce);
+    let nutation_obliquity = earth::nutation_obliquity(jce);
+    let true_obliquity = earth::true_obliquity(mean_obliquity, nutation_obliquity);
+    let apparent = nutate(aberrated, nutation_longitude, nutation_obliquity, true_obliquity);
+    let apparent_pole = nutate(pole, nutation_longitude, nutation_obliquity, true_obliquity);
+
+    let ra_difference = apparent_pole.right_ascension - apparent.right_ascension;
+    let position_angle = (apparent_pole.declination.cos() * ra_difference.sin()).atan2(
+        apparent_pole.declination.sin() * apparent.declination.cos() -
+            apparent_pole.declination.cos() * apparent.declination.sin() * ra_difference.cos(),
+    );
+
+    JupiterPhysicalEphemeris {
+        earth_declination,
+        sun_declination,
+        position_angle: limit_radians(position_angle),
+        system_i_central_meridian: limit_radians(system_i_central_meridian),
+        system_ii_central_meridian: limit_radians(system_ii_central_meridian),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use planet::*;
+
+    #[test]
+    fn meeus_example_43_b() {
+        let jupiter = jupiter_physical_ephemeris(2_448_972.500_685_f64);
+
+        assert!((jupiter.sun_declination.to_degrees() + 2.20_f64).abs() < 0.01);
+        assert!((jupiter.earth_declination.to_degrees() + 2.48_f64).abs() < 0.01);
+        assert!((jupiter.system_i_central_meridian.to_degrees() - 268.06_f64).abs() < 0.02);
+        assert!((jupiter.system_ii_central_meridian.to_degrees() - 72.74_f64).abs() < 0.01);
+        assert!((jupiter.position_angle.to_degrees() - 24.80_f64).abs() < 0.01);
+    }
+
+    #[test]
+    fn system_i_rotates_faster() {
+        // System I gains about 7.6° a day on System II
+        let jde = 2_458_000.5_f64;
+        let today = jupiter_physical_ephemeris(jde);
+        let tomorrow = jupiter_physical_ephemeris(jde + 1_f64);
+        let system_i = tomorrow.system_i_central_meridian - today.system_i_central_meridian;
+        let system_ii = tomorrow.system_ii_central_meridian - today.system_ii_central_meridian;
+        let gain = (system_i - system_ii).to_degrees().rem_euclid(360_f64);
+
+        assert!((gain - 7.63_f64).abs() < 0.05);
+    }
+}
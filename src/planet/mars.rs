@@ -0,0 +1,153 @@
+use earth;
+use moon::illuminated_fraction;
+use planet::{geometric_position, heliocentric_latitude, heliocentric_longitude, phase_angle,
+             Planet};
+use time::{jul_to_julc, julc_to_julm};
+use utils::angles::limit_radians;
+use utils::coordinates::Ecliptic;
+
+/// Mars' apparent diameter at a distance of one AU, in arcseconds
+const MARS_DIAMETER: f64 = 9.36_f64;
+
+/// Quantities for the physical observation of Mars, in radians
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct MarsPhysicalEphemeris {
+    /// planetocentric declination of the earth (DE)
+    pub earth_declination: f64,
+    /// planetocentric declination of the sun (DS)
+    pub sun_declination: f64,
+    /// position angle of the northern rotation pole (P), eastward from north
+    pub position_angle: f64,
+    /// longitude of the central meridian (ω), as seen from the earth
+    pub central_meridian: f64,
+    /// apparent diameter (d)
+    pub diameter: f64,
+    /// angular width of the greatest defect of illumination (q)
+    pub defect_of_illumination: f64,
+}
+
+/// Calculates the physical ephemeris of Mars for a given Julian Ephemeris
+/// Day
+///
+/// Obtained from Meeus (ch. 42)
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::planet::mars_physical_ephemeris;
+///
+/// // Meeus example 42.a: 1992 November 9, 0h UT
+/// let mars = mars_physical_ephemeris(2_448_935.500_683);
+/// assert_eq!(12.44, (mars.earth_declination.to_degrees() * 100_f64).round() / 100_f64);
+/// assert_eq!(111.55, (mars.central_meridian.to_degrees() * 100_f64).round() / 100_f64);
+/// ```
+pub fn mars_physical_ephemeris(jde: f64) -> MarsPhysicalEphemeris {
+    let jce = jul_to_julc(jde);
+    let jme = julc_to_julm(jce);
+
+    // the northern pole of rotation, referred to the mean equinox of date
+    let pole = Ecliptic {
+        longitude: (352.906_5_f64 + 1.173_3_f64 * jce).to_radians(),
+        latitude: (63.281_8_f64 - 0.003_94_f64 * jce).to_radians(),
+    };
+
+    let (geocentric, distance, light_time, sun_distance, earth_sun_distance) =
+        geometric_position(Planet::Mars, jde);
+    let lambda = geocentric.longitude;
+    let beta = geocentric.latitude;
+
+    let earth_declination = (-pole.latitude.sin() * beta.sin() -
+                                 pole.latitude.cos() * beta.cos() *
+                                     (pole.longitude - lambda).cos())
+        .asin();
+
+    // the sun as seen from Mars, corrected for aberration
+    let tau = julc_to_julm(jul_to_julc(jde - light_time));
+    let l = heliocentric_longitude(Planet::Mars, tau);
+    let b = heliocentric_latitude(Planet::Mars, tau);
+    let node = (49.558_1_f64 + 0.772_1_f64 * jce).to_radians();
+    let l_prime = l - (0.006_97_f64 / sun_distance).to_radians();
+    let b_prime = b - (0.000_225_f64 * (l - node).cos() / sun_distance).to_radians();
+
+    let sun_declination = (-pole.latitude.sin() * b_prime.sin() -
+                               pole.latitude.cos() * b_prime.cos() *
+                                   (pole.longitude - l_prime).cos())
+        .asin();
+
+    // the prime meridian, at the instant the light left Mars
+    let prime_meridian = (11.504_f64 + 350.892_000_25_f64 * (jde - light_time - 2_433_282.5_f64))
+        .to_radians();
+
+    let mean_obliquity = earth::mean_obliquity(jme);
+    let pole_mean = pole.to_equatorial(mean_obliquity);
+    let mars_mean = geocentric.to_equatorial(mean_obliquity);
+    let ra_difference = pole_mean.right_ascension - mars_mean.right_ascension;
+    let zeta = (pole_mean.declination.sin() * mars_mean.declination.cos() * ra_difference.cos() -
+                    mars_mean.declination.sin() * pole_mean.declination.cos())
+        .atan2(mars_mean.declination.cos() * ra_difference.sin());
+
+    // apparent positions of Mars and its pole, with aberration and nutation
+    let earth_longitude = earth::heliocentric_longitude(jme);
+    let aberration = 0.005_693_f64.to_radians();
+    let nutation_longitude = earth::nutation_longitude(jce);
+    let apparent = Ecliptic {
+        longitude: lambda + aberration * (earth_longitude - lambda).cos() / beta.cos() +
+            nutation_longitude,
+        latitude: beta + aberration * (earth_longitude - lambda).sin() * beta.sin(),
+    };
+    let apparent_pole = Ecliptic {
+        longitude: pole.longitude + nutation_longitude,
+        latitude: pole.latitude,
+    };
+
+    let true_obliquity =
+        earth::true_obliquity(mean_obliquity, earth::nutation_obliquity(jce));
+    let pole_true = apparent_pole.to_equatorial(true_obliquity);
+    let mars_true = apparent.to_equatorial(true_obliquity);
+    let ra_difference = pole_true.right_ascension - mars_true.right_ascension;
+    let position_angle = (pole_true.declination.cos() * ra_difference.sin()).atan2(
+        pole_true.declination.sin() * mars_true.declination.cos() -
+            pole_true.declination.cos() * mars_true.declination.sin() * ra_difference.cos(),
+    );
+
+    let diameter = (MARS_DIAMETER / 3_600_f64 / distance).to_radians();
+    let phase_angle = phase_angle(sun_distance, distance, earth_sun_distance);
+
+    MarsPhysicalEphemeris {
+        earth_declination,
+        sun_declination,
+        position_angle: limit_radians(position_angle),
+        central_meridian: limit_radians(prime_meridian - zeta),
+        diameter,
+        defect_of_illumination: (1_f64 - illuminated_fraction(phase_angle)) * diameter,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use planet::*;
+
+    #[test]
+    fn meeus_example_42_a() {
+        let mars = mars_physical_ephemeris(2_448_935.500_683_f64);
+
+        assert!((mars.earth_declination.to_degrees() - 12.44_f64).abs() < 0.01);
+        assert!((mars.sun_declination.to_degrees() + 2.76_f64).abs() < 0.01);
+        assert!((mars.position_angle.to_degrees() - 347.64_f64).abs() < 0.01);
+        assert!((mars.central_meridian.to_degrees() - 111.55_f64).abs() < 0.01);
+        assert!((mars.diameter.to_degrees() * 3_600_f64 - 10.75_f64).abs() < 0.01);
+        assert!((mars.defect_of_illumination.to_degrees() * 3_600_f64 - 1.06_f64).abs() < 0.01);
+    }
+
+    #[test]
+    fn rotates_once_a_sol() {
+        // the central meridian decreases by about 9.4° per (earth) day, as
+        // Mars turns once in 24h37m
+        let jde = 2_458_000.5_f64;
+        let today = mars_physical_ephemeris(jde).central_meridian;
+        let tomorrow = mars_physical_ephemeris(jde + 1_f64).central_meridian;
+        let change = (today - tomorrow).to_degrees().rem_euclid(360_f64);
+
+        assert!((change - 9.4_f64).abs() < 0.5);
+    }
+}
@@ -0,0 +1,24 @@
+//! Calculations involving the major planets, such as their heliocentric
+//! and apparent geocentric positions, their conjunctions, oppositions,
+//! elongations and stations, their illumination and magnitudes, the
+//! physical ephemerides of Mars and Jupiter, the positions and events of
+//! Jupiter's Galilean satellites, and Saturn's rings and major satellites
+mod periodic_terms;
+mod position;
+mod phenomena;
+mod illumination;
+mod saturn;
+mod mars;
+mod jupiter;
+mod galilean;
+mod saturn_satellites;
+
+pub use self::periodic_terms::*;
+pub use self::position::*;
+pub use self::phenomena::*;
+pub use self::illumination::*;
+pub use self::mars::*;
+pub use self::jupiter::*;
+pub use self::galilean::*;
+pub use self::saturn::*;
+pub use self::saturn_satellites::*;
@@ -0,0 +1,1306 @@
+#![allow(clippy::unreadable_literal, clippy::approx_constant)]
+
+use planet::periodic_terms::{PlanetPeriodicTableRow, PlanetPeriodicTerms};
+
+const L0: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 59954691_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 9695899_f64,
+        b: 5.06191793105_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 573610_f64,
+        b: 1.44406205976_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 306389_f64,
+        b: 5.41734729976_f64,
+        c: 1059.3819301892_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 97178_f64,
+        b: 4.14264708819_f64,
+        c: 632.7837393132_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 72903_f64,
+        b: 3.64042909255_f64,
+        c: 522.5774180938_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 64264_f64,
+        b: 3.41145185203_f64,
+        c: 103.0927742186_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 39806_f64,
+        b: 2.29376744855_f64,
+        c: 419.4846438752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 38858_f64,
+        b: 1.2723172486_f64,
+        c: 316.3918696566_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 27965_f64,
+        b: 1.78454589485_f64,
+        c: 536.8045120954_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 13590_f64,
+        b: 5.7748103159_f64,
+        c: 1589.0728952838_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 8246_f64,
+        b: 3.58227961655_f64,
+        c: 206.1855484372_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 8769_f64,
+        b: 3.63000324417_f64,
+        c: 949.1756089698_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 7368_f64,
+        b: 5.08101125612_f64,
+        c: 735.8765135318_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 6263_f64,
+        b: 0.02497643742_f64,
+        c: 213.299095438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 6114_f64,
+        b: 4.51319531666_f64,
+        c: 1162.4747044078_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4905_f64,
+        b: 1.32084631684_f64,
+        c: 110.2063212194_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 5305_f64,
+        b: 1.30671236848_f64,
+        c: 14.2270940016_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 5305_f64,
+        b: 4.18625053495_f64,
+        c: 1052.2683831884_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4647_f64,
+        b: 4.69958109497_f64,
+        c: 3.9321532631_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3045_f64,
+        b: 4.31675960318_f64,
+        c: 426.598190876_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2610_f64,
+        b: 1.5666759485_f64,
+        c: 846.0828347512_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2028_f64,
+        b: 1.06376547379_f64,
+        c: 3.1813937377_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1765_f64,
+        b: 2.14148077766_f64,
+        c: 1066.49547719_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1723_f64,
+        b: 3.88036008872_f64,
+        c: 1265.5674786264_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1921_f64,
+        b: 0.97168928755_f64,
+        c: 639.897286314_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1633_f64,
+        b: 3.58201089758_f64,
+        c: 515.463871093_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1432_f64,
+        b: 4.29683690269_f64,
+        c: 625.6701923124_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 973_f64,
+        b: 4.09764957065_f64,
+        c: 95.9792272178_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 884_f64,
+        b: 2.43701426123_f64,
+        c: 412.3710968744_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 733_f64,
+        b: 6.08534113239_f64,
+        c: 838.9692877504_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 731_f64,
+        b: 3.80591233956_f64,
+        c: 1581.959348283_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 692_f64,
+        b: 6.13368222939_f64,
+        c: 2118.7638603784_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 709_f64,
+        b: 1.29272573658_f64,
+        c: 742.9900605326_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 614_f64,
+        b: 4.10853496756_f64,
+        c: 1478.8665740644_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 495_f64,
+        b: 3.75567461379_f64,
+        c: 323.5054166574_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 582_f64,
+        b: 4.53967717552_f64,
+        c: 309.2783226558_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 376_f64,
+        b: 4.70299124833_f64,
+        c: 1368.660252845_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 390_f64,
+        b: 4.89716105852_f64,
+        c: 1692.1656695024_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 341_f64,
+        b: 5.71452525783_f64,
+        c: 533.6231183577_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 330_f64,
+        b: 4.74049819491_f64,
+        c: 0.0481841098_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 441_f64,
+        b: 2.95818460943_f64,
+        c: 454.9093665273_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 417_f64,
+        b: 1.03554430161_f64,
+        c: 2.4476805548_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 244_f64,
+        b: 5.220208789_f64,
+        c: 728.762966531_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 262_f64,
+        b: 1.87652461032_f64,
+        c: 0.9632078465_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 257_f64,
+        b: 3.72410724159_f64,
+        c: 199.0720014364_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 261_f64,
+        b: 0.82047246448_f64,
+        c: 380.12776796_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 220_f64,
+        b: 1.65115015995_f64,
+        c: 543.9180590962_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 202_f64,
+        b: 1.80684574186_f64,
+        c: 1375.7737998458_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 207_f64,
+        b: 1.85461666594_f64,
+        c: 525.7588118315_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 197_f64,
+        b: 5.29252149016_f64,
+        c: 1155.361157407_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 235_f64,
+        b: 1.22693908124_f64,
+        c: 909.8187330546_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 175_f64,
+        b: 5.90973505276_f64,
+        c: 956.2891559706_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 149_f64,
+        b: 4.37745104275_f64,
+        c: 1685.0521225016_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 175_f64,
+        b: 3.22634903433_f64,
+        c: 1898.3512179396_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 175_f64,
+        b: 3.72966554761_f64,
+        c: 942.062061969_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 158_f64,
+        b: 4.36483921766_f64,
+        c: 1795.258443721_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 138_f64,
+        b: 1.31797920785_f64,
+        c: 1169.5882514086_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 117_f64,
+        b: 2.5002214089_f64,
+        c: 1596.1864422846_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 151_f64,
+        b: 3.90625022622_f64,
+        c: 74.7815985673_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 117_f64,
+        b: 3.38920921041_f64,
+        c: 0.5212648618_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 106_f64,
+        b: 4.55439798236_f64,
+        c: 526.5095713569_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 131_f64,
+        b: 4.16867945489_f64,
+        c: 1045.1548361876_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 141_f64,
+        b: 3.13568357861_f64,
+        c: 491.5579294568_f64,
+    },
+];
+
+const L1: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 52993480757_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 489741_f64,
+        b: 4.22066689928_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 228919_f64,
+        b: 6.02647464016_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 27655_f64,
+        b: 4.57265956824_f64,
+        c: 1059.3819301892_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 20721_f64,
+        b: 5.45938936295_f64,
+        c: 522.5774180938_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 12106_f64,
+        b: 0.16985765041_f64,
+        c: 536.8045120954_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 6068_f64,
+        b: 4.42419502005_f64,
+        c: 103.0927742186_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 5434_f64,
+        b: 3.98478382565_f64,
+        c: 419.4846438752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4238_f64,
+        b: 5.89009351271_f64,
+        c: 14.2270940016_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2212_f64,
+        b: 5.26771446618_f64,
+        c: 206.1855484372_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1296_f64,
+        b: 5.55132765087_f64,
+        c: 3.1813937377_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1746_f64,
+        b: 4.92669378486_f64,
+        c: 1589.0728952838_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1163_f64,
+        b: 0.51450895328_f64,
+        c: 3.9321532631_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1007_f64,
+        b: 0.46478398551_f64,
+        c: 735.8765135318_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1173_f64,
+        b: 5.8564730435_f64,
+        c: 1052.2683831884_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 848_f64,
+        b: 5.7580585045_f64,
+        c: 110.2063212194_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 827_f64,
+        b: 4.80312015734_f64,
+        c: 213.299095438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1004_f64,
+        b: 3.15040301822_f64,
+        c: 426.598190876_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1099_f64,
+        b: 5.30704981594_f64,
+        c: 515.463871093_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 816_f64,
+        b: 0.58643054886_f64,
+        c: 1066.49547719_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 725_f64,
+        b: 5.51827471473_f64,
+        c: 639.897286314_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 568_f64,
+        b: 5.98867049451_f64,
+        c: 625.6701923124_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 474_f64,
+        b: 4.13245269168_f64,
+        c: 412.3710968744_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 413_f64,
+        b: 5.73652891261_f64,
+        c: 95.9792272178_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 336_f64,
+        b: 3.73248749046_f64,
+        c: 1162.4747044078_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 345_f64,
+        b: 4.2415956541_f64,
+        c: 632.7837393132_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 234_f64,
+        b: 6.24302226646_f64,
+        c: 309.2783226558_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 195_f64,
+        b: 2.21879010911_f64,
+        c: 323.5054166574_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 234_f64,
+        b: 4.03469970332_f64,
+        c: 949.1756089698_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 184_f64,
+        b: 6.27963588822_f64,
+        c: 543.9180590962_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 199_f64,
+        b: 1.50458442825_f64,
+        c: 838.9692877504_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 187_f64,
+        b: 6.08620565908_f64,
+        c: 742.9900605326_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 171_f64,
+        b: 5.41655983845_f64,
+        c: 199.0720014364_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 131_f64,
+        b: 0.62643377351_f64,
+        c: 728.762966531_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 108_f64,
+        b: 4.49282760117_f64,
+        c: 956.2891559706_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 115_f64,
+        b: 0.68019050174_f64,
+        c: 846.0828347512_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 115_f64,
+        b: 5.28641699144_f64,
+        c: 2118.7638603784_f64,
+    },
+];
+
+const L2: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 47234_f64,
+        b: 4.32148323554_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 30629_f64,
+        b: 2.93021440216_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 38966_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3189_f64,
+        b: 1.05504615595_f64,
+        c: 522.5774180938_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2723_f64,
+        b: 3.41411526638_f64,
+        c: 1059.3819301892_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2729_f64,
+        b: 4.84545481351_f64,
+        c: 536.8045120954_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1721_f64,
+        b: 4.18734385158_f64,
+        c: 14.2270940016_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 383_f64,
+        b: 5.76790714387_f64,
+        c: 419.4846438752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 367_f64,
+        b: 6.05509120409_f64,
+        c: 103.0927742186_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 378_f64,
+        b: 0.76048964872_f64,
+        c: 515.463871093_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 337_f64,
+        b: 3.78644384244_f64,
+        c: 3.1813937377_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 308_f64,
+        b: 0.69356654052_f64,
+        c: 206.1855484372_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 218_f64,
+        b: 3.81389191353_f64,
+        c: 1589.0728952838_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 199_f64,
+        b: 5.33996443444_f64,
+        c: 1066.49547719_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 197_f64,
+        b: 2.48356402053_f64,
+        c: 3.9321532631_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 146_f64,
+        b: 3.81373196838_f64,
+        c: 639.897286314_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 156_f64,
+        b: 1.40642426467_f64,
+        c: 1052.2683831884_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 130_f64,
+        b: 5.83738872525_f64,
+        c: 412.3710968744_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 142_f64,
+        b: 1.63435169016_f64,
+        c: 426.598190876_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 117_f64,
+        b: 1.41435462588_f64,
+        c: 625.6701923124_f64,
+    },
+];
+
+const L3: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 6502_f64,
+        b: 2.59862880482_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1357_f64,
+        b: 1.34635886411_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 471_f64,
+        b: 2.47503977883_f64,
+        c: 14.2270940016_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 417_f64,
+        b: 3.24451243214_f64,
+        c: 536.8045120954_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 353_f64,
+        b: 2.97360159003_f64,
+        c: 522.5774180938_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 155_f64,
+        b: 2.07565585817_f64,
+        c: 1059.3819301892_f64,
+    },
+];
+
+const L4: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 669_f64,
+        b: 0.8528242109_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 114_f64,
+        b: 3.14159265359_f64,
+        c: 0_f64,
+    },
+];
+
+const B0: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 2268616_f64,
+        b: 3.55852606718_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 109972_f64,
+        b: 3.90809347389_f64,
+        c: 1059.3819301892_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 110090_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 8101_f64,
+        b: 3.60509573368_f64,
+        c: 522.5774180938_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 6044_f64,
+        b: 4.25883108794_f64,
+        c: 1589.0728952838_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 6438_f64,
+        b: 0.30627121409_f64,
+        c: 536.8045120954_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1107_f64,
+        b: 2.98534421928_f64,
+        c: 1162.4747044078_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 942_f64,
+        b: 2.93619072405_f64,
+        c: 1052.2683831884_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 894_f64,
+        b: 1.75447429921_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 767_f64,
+        b: 2.1547359406_f64,
+        c: 632.7837393132_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 944_f64,
+        b: 1.67522288396_f64,
+        c: 426.598190876_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 684_f64,
+        b: 3.67808770098_f64,
+        c: 213.299095438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 629_f64,
+        b: 0.64343282328_f64,
+        c: 1066.49547719_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 836_f64,
+        b: 5.17881973234_f64,
+        c: 103.0927742186_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 532_f64,
+        b: 2.70305954352_f64,
+        c: 110.2063212194_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 559_f64,
+        b: 0.01354830508_f64,
+        c: 846.0828347512_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 464_f64,
+        b: 1.17337249185_f64,
+        c: 949.1756089698_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 431_f64,
+        b: 2.60825000494_f64,
+        c: 419.4846438752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 351_f64,
+        b: 4.61062990714_f64,
+        c: 2118.7638603784_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 123_f64,
+        b: 3.34968181384_f64,
+        c: 1692.1656695024_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 115_f64,
+        b: 5.04892295442_f64,
+        c: 316.3918696566_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 132_f64,
+        b: 4.7781699067_f64,
+        c: 742.9900605326_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 103_f64,
+        b: 2.31878999565_f64,
+        c: 1478.8665740644_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 116_f64,
+        b: 1.38688232033_f64,
+        c: 323.5054166574_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 102_f64,
+        b: 3.15293785436_f64,
+        c: 1581.959348283_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 104_f64,
+        b: 3.7010383811_f64,
+        c: 515.463871093_f64,
+    },
+];
+
+const B1: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 177352_f64,
+        b: 5.70166488486_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3230_f64,
+        b: 5.7794161934_f64,
+        c: 1059.3819301892_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3081_f64,
+        b: 5.47464296527_f64,
+        c: 522.5774180938_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2212_f64,
+        b: 4.73477480209_f64,
+        c: 536.8045120954_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1694_f64,
+        b: 3.14159265359_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 346_f64,
+        b: 4.74595174109_f64,
+        c: 1052.2683831884_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 234_f64,
+        b: 5.18856099929_f64,
+        c: 1066.49547719_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 196_f64,
+        b: 6.18554286642_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 150_f64,
+        b: 3.92721226087_f64,
+        c: 1589.0728952838_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 114_f64,
+        b: 3.4389727183_f64,
+        c: 632.7837393132_f64,
+    },
+];
+
+const B2: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 8094_f64,
+        b: 1.46322843658_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 742_f64,
+        b: 0.95691639003_f64,
+        c: 522.5774180938_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 813_f64,
+        b: 3.14159265359_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 399_f64,
+        b: 2.89888666447_f64,
+        c: 536.8045120954_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 342_f64,
+        b: 1.44683789727_f64,
+        c: 1059.3819301892_f64,
+    },
+];
+
+const B3: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 252_f64,
+        b: 3.38087923084_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 122_f64,
+        b: 2.733118372_f64,
+        c: 522.5774180938_f64,
+    },
+];
+
+const R0: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 520887429_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 25209327_f64,
+        b: 3.49108640015_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 610600_f64,
+        b: 3.84115365602_f64,
+        c: 1059.3819301892_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 282029_f64,
+        b: 2.57419879933_f64,
+        c: 632.7837393132_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 187647_f64,
+        b: 2.07590380082_f64,
+        c: 522.5774180938_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 86793_f64,
+        b: 0.71001090609_f64,
+        c: 419.4846438752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 72063_f64,
+        b: 0.21465694745_f64,
+        c: 536.8045120954_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 65517_f64,
+        b: 5.97995850843_f64,
+        c: 316.3918696566_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 29135_f64,
+        b: 1.6775924371_f64,
+        c: 103.0927742186_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 30135_f64,
+        b: 2.16132058449_f64,
+        c: 949.1756089698_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 23453_f64,
+        b: 3.54023147303_f64,
+        c: 735.8765135318_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 22284_f64,
+        b: 4.19362773546_f64,
+        c: 1589.0728952838_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 23947_f64,
+        b: 0.27457854894_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 13033_f64,
+        b: 2.96043055741_f64,
+        c: 1162.4747044078_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 9703_f64,
+        b: 1.90669572402_f64,
+        c: 206.1855484372_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 12749_f64,
+        b: 2.71550102862_f64,
+        c: 1052.2683831884_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 9161_f64,
+        b: 4.41352618935_f64,
+        c: 213.299095438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 7895_f64,
+        b: 2.47907551404_f64,
+        c: 426.598190876_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 7058_f64,
+        b: 2.18184753111_f64,
+        c: 1265.5674786264_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 6138_f64,
+        b: 6.26417542514_f64,
+        c: 846.0828347512_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 5477_f64,
+        b: 5.65729325169_f64,
+        c: 639.897286314_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3503_f64,
+        b: 0.56531297394_f64,
+        c: 1066.49547719_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4137_f64,
+        b: 2.72219979684_f64,
+        c: 625.6701923124_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4170_f64,
+        b: 2.01605033912_f64,
+        c: 515.463871093_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2500_f64,
+        b: 4.55182055941_f64,
+        c: 838.9692877504_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2617_f64,
+        b: 2.00993967129_f64,
+        c: 1581.959348283_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1912_f64,
+        b: 0.85621927419_f64,
+        c: 412.3710968744_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2128_f64,
+        b: 6.1275146175_f64,
+        c: 742.9900605326_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1611_f64,
+        b: 3.08867789275_f64,
+        c: 1368.660252845_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1479_f64,
+        b: 2.68026191372_f64,
+        c: 1478.8665740644_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1231_f64,
+        b: 1.89042979701_f64,
+        c: 323.5054166574_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1217_f64,
+        b: 1.80171561024_f64,
+        c: 110.2063212194_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 961_f64,
+        b: 4.54876989805_f64,
+        c: 2118.7638603784_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 886_f64,
+        b: 4.14785948471_f64,
+        c: 533.6231183577_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 777_f64,
+        b: 3.6769695469_f64,
+        c: 728.762966531_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 999_f64,
+        b: 2.8720894011_f64,
+        c: 309.2783226558_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1015_f64,
+        b: 1.38673237666_f64,
+        c: 454.9093665273_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 727_f64,
+        b: 3.98824686402_f64,
+        c: 1155.361157407_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 655_f64,
+        b: 2.79065604219_f64,
+        c: 1685.0521225016_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 821_f64,
+        b: 1.59342534396_f64,
+        c: 1898.3512179396_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 621_f64,
+        b: 4.82284338962_f64,
+        c: 956.2891559706_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 654_f64,
+        b: 3.38150775269_f64,
+        c: 1692.1656695024_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 812_f64,
+        b: 5.94091899141_f64,
+        c: 909.8187330546_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 562_f64,
+        b: 0.08095987241_f64,
+        c: 543.9180590962_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 542_f64,
+        b: 0.28360266386_f64,
+        c: 525.7588118315_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 615_f64,
+        b: 2.27624915604_f64,
+        c: 942.062061969_f64,
+    },
+];
+
+const R1: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 1271802_f64,
+        b: 2.64937511122_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 61662_f64,
+        b: 3.00076251018_f64,
+        c: 1059.3819301892_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 53444_f64,
+        b: 3.89717644226_f64,
+        c: 522.5774180938_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 31185_f64,
+        b: 4.88276663526_f64,
+        c: 536.8045120954_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 41390_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 11847_f64,
+        b: 2.41329588176_f64,
+        c: 419.4846438752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 9166_f64,
+        b: 4.75979408587_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3176_f64,
+        b: 2.79297987071_f64,
+        c: 103.0927742186_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3203_f64,
+        b: 5.21083285476_f64,
+        c: 735.8765135318_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3404_f64,
+        b: 3.34688537997_f64,
+        c: 1589.0728952838_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2600_f64,
+        b: 3.63435101622_f64,
+        c: 206.1855484372_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2412_f64,
+        b: 1.46947308304_f64,
+        c: 426.598190876_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2806_f64,
+        b: 3.7422369358_f64,
+        c: 515.463871093_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2677_f64,
+        b: 4.33052878699_f64,
+        c: 1052.2683831884_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2101_f64,
+        b: 3.92762682306_f64,
+        c: 639.897286314_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1646_f64,
+        b: 5.30953510947_f64,
+        c: 1066.49547719_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1641_f64,
+        b: 4.41628669824_f64,
+        c: 625.6701923124_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1050_f64,
+        b: 3.16113622955_f64,
+        c: 213.299095438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1025_f64,
+        b: 2.55432643018_f64,
+        c: 412.3710968744_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 741_f64,
+        b: 2.17094630558_f64,
+        c: 1162.4747044078_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 806_f64,
+        b: 2.6775080138_f64,
+        c: 632.7837393132_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 677_f64,
+        b: 6.2495347979_f64,
+        c: 838.9692877504_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 567_f64,
+        b: 4.57655414712_f64,
+        c: 742.9900605326_f64,
+    },
+];
+
+const R2: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 79645_f64,
+        b: 1.35865896596_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 8252_f64,
+        b: 5.77773935444_f64,
+        c: 522.5774180938_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 7030_f64,
+        b: 3.27476965833_f64,
+        c: 536.8045120954_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 5314_f64,
+        b: 1.83835109712_f64,
+        c: 1059.3819301892_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1861_f64,
+        b: 2.97682139367_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 836_f64,
+        b: 4.19889881718_f64,
+        c: 419.4846438752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 964_f64,
+        b: 5.48031822015_f64,
+        c: 515.463871093_f64,
+    },
+];
+
+const R3: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 3519_f64,
+        b: 6.05800633846_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1073_f64,
+        b: 1.6732134576_f64,
+        c: 536.8045120954_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 916_f64,
+        b: 1.41329676116_f64,
+        c: 522.5774180938_f64,
+    },
+];
+
+/// The periodic terms for the heliocentric coordinates of Jupiter, from
+/// VSOP87D with terms smaller than 1e-6 radians (or 1e-6 of the mean
+/// distance, for the radius vector) omitted
+pub const JUPITER_TERMS: PlanetPeriodicTerms = PlanetPeriodicTerms {
+    longitude: &[L0, L1, L2, L3, L4],
+    latitude: &[B0, B1, B2, B3],
+    radius: &[R0, R1, R2, R3],
+};
@@ -0,0 +1,991 @@
+#![allow(clippy::unreadable_literal, clippy::approx_constant)]
+
+use planet::periodic_terms::{PlanetPeriodicTableRow, PlanetPeriodicTerms};
+
+const L0: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 620347712_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 18656368_f64,
+        b: 5.05037100303_f64,
+        c: 3340.6124266998_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1108217_f64,
+        b: 5.40099836958_f64,
+        c: 6681.2248533996_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 91798_f64,
+        b: 5.75478745111_f64,
+        c: 10021.8372800994_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 27745_f64,
+        b: 5.97049512942_f64,
+        c: 3.523118349_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 10610_f64,
+        b: 2.93958524973_f64,
+        c: 2281.2304965106_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 12316_f64,
+        b: 0.84956081238_f64,
+        c: 2810.9214616052_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 8927_f64,
+        b: 4.15697845939_f64,
+        c: 0.0172536522_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 8716_f64,
+        b: 6.11005159792_f64,
+        c: 13362.4497067992_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 6798_f64,
+        b: 0.36462243626_f64,
+        c: 398.1490034082_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 7775_f64,
+        b: 3.33968655074_f64,
+        c: 5621.8429232104_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3575_f64,
+        b: 1.66186540141_f64,
+        c: 2544.3144198834_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4161_f64,
+        b: 0.2281497533_f64,
+        c: 2942.4634232916_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3075_f64,
+        b: 0.85696597082_f64,
+        c: 191.4482661116_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2628_f64,
+        b: 0.6480614357_f64,
+        c: 3337.0893083508_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2938_f64,
+        b: 6.07893711408_f64,
+        c: 0.0673103028_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2389_f64,
+        b: 5.03896401349_f64,
+        c: 796.2980068164_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2580_f64,
+        b: 0.02996706197_f64,
+        c: 3344.1355450488_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1528_f64,
+        b: 1.14979306228_f64,
+        c: 6151.533888305_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1799_f64,
+        b: 0.65634026844_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1264_f64,
+        b: 3.62275092231_f64,
+        c: 5092.1519581158_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1286_f64,
+        b: 3.06795924626_f64,
+        c: 2146.1654164752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1546_f64,
+        b: 2.91579633392_f64,
+        c: 1751.539531416_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1025_f64,
+        b: 3.69334293555_f64,
+        c: 8962.4553499102_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 892_f64,
+        b: 0.1829389909_f64,
+        c: 16703.062133499_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 859_f64,
+        b: 2.40093704204_f64,
+        c: 2914.0142358238_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 833_f64,
+        b: 2.46418591282_f64,
+        c: 3340.5951730476_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 833_f64,
+        b: 4.49495753458_f64,
+        c: 3340.629680352_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 713_f64,
+        b: 3.66336014788_f64,
+        c: 1059.3819301892_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 749_f64,
+        b: 3.82248399468_f64,
+        c: 155.4203994342_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 724_f64,
+        b: 0.67497565801_f64,
+        c: 3738.761430108_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 636_f64,
+        b: 2.92182704275_f64,
+        c: 8432.7643848156_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 655_f64,
+        b: 0.48864075176_f64,
+        c: 3127.3133312618_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 550_f64,
+        b: 3.81001205408_f64,
+        c: 0.9803210682_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 553_f64,
+        b: 4.47478863016_f64,
+        c: 1748.016413067_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 426_f64,
+        b: 0.55365138172_f64,
+        c: 6283.0758499914_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 415_f64,
+        b: 0.49662314774_f64,
+        c: 213.299095438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 472_f64,
+        b: 3.6254781941_f64,
+        c: 1194.4470102246_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 307_f64,
+        b: 0.38052862973_f64,
+        c: 6684.7479717486_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 312_f64,
+        b: 0.99853322843_f64,
+        c: 6677.7017350506_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 293_f64,
+        b: 4.22131277914_f64,
+        c: 20.7753954924_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 302_f64,
+        b: 4.48618150321_f64,
+        c: 3532.0606928114_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 274_f64,
+        b: 0.54222141841_f64,
+        c: 3340.545116397_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 281_f64,
+        b: 5.88163372945_f64,
+        c: 1349.8674096588_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 231_f64,
+        b: 1.28240685294_f64,
+        c: 3870.3033917944_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 284_f64,
+        b: 5.76885494123_f64,
+        c: 3149.1641605882_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 236_f64,
+        b: 5.75504515576_f64,
+        c: 3333.498879699_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 274_f64,
+        b: 0.13372501211_f64,
+        c: 3340.6797370026_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 299_f64,
+        b: 2.78323705697_f64,
+        c: 6254.6266625236_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 204_f64,
+        b: 2.82133266185_f64,
+        c: 1221.8485663214_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 239_f64,
+        b: 5.37155471672_f64,
+        c: 4136.9104335162_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 189_f64,
+        b: 1.49103016486_f64,
+        c: 9492.1463150048_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 221_f64,
+        b: 3.50466672203_f64,
+        c: 382.8965322232_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 179_f64,
+        b: 1.00561112574_f64,
+        c: 951.7184062506_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 172_f64,
+        b: 0.43943041719_f64,
+        c: 5486.777843175_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 193_f64,
+        b: 3.35715137745_f64,
+        c: 3.5904286518_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 144_f64,
+        b: 1.41874193418_f64,
+        c: 135.0650800354_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 160_f64,
+        b: 3.94854735192_f64,
+        c: 4562.4609930212_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 174_f64,
+        b: 2.41360332576_f64,
+        c: 553.5694028424_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 131_f64,
+        b: 4.04491720264_f64,
+        c: 12303.06777661_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 138_f64,
+        b: 4.30145176915_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 128_f64,
+        b: 1.80665643332_f64,
+        c: 5088.6288397668_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 140_f64,
+        b: 3.32592516164_f64,
+        c: 2700.7151403858_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 128_f64,
+        b: 2.20806651008_f64,
+        c: 1592.5960136328_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 117_f64,
+        b: 3.12805282207_f64,
+        c: 7903.073419721_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 110_f64,
+        b: 1.05195079687_f64,
+        c: 242.728603974_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 113_f64,
+        b: 3.70070798123_f64,
+        c: 1589.0728952838_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 100_f64,
+        b: 3.24343740861_f64,
+        c: 11773.3768115154_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 105_f64,
+        b: 0.78535382076_f64,
+        c: 8827.3902698748_f64,
+    },
+];
+
+const L1: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 334085627474_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1458227_f64,
+        b: 3.60426053609_f64,
+        c: 3340.6124266998_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 164901_f64,
+        b: 3.92631250962_f64,
+        c: 6681.2248533996_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 19963_f64,
+        b: 4.2659406103_f64,
+        c: 10021.8372800994_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3452_f64,
+        b: 4.73210386365_f64,
+        c: 3.523118349_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2485_f64,
+        b: 4.61277567318_f64,
+        c: 13362.4497067992_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 842_f64,
+        b: 4.45858256765_f64,
+        c: 2281.2304965106_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 538_f64,
+        b: 5.01589727492_f64,
+        c: 398.1490034082_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 521_f64,
+        b: 4.99422678175_f64,
+        c: 3344.1355450488_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 433_f64,
+        b: 2.5606640286_f64,
+        c: 191.4482661116_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 430_f64,
+        b: 5.31646162367_f64,
+        c: 155.4203994342_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 382_f64,
+        b: 3.53881289437_f64,
+        c: 796.2980068164_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 314_f64,
+        b: 4.96335266049_f64,
+        c: 16703.062133499_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 283_f64,
+        b: 3.15967518204_f64,
+        c: 2544.3144198834_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 206_f64,
+        b: 4.5689145566_f64,
+        c: 2146.1654164752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 169_f64,
+        b: 1.32894813366_f64,
+        c: 3337.0893083508_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 158_f64,
+        b: 4.18501035954_f64,
+        c: 1751.539531416_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 134_f64,
+        b: 2.23325104196_f64,
+        c: 0.9803210682_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 117_f64,
+        b: 2.21347652545_f64,
+        c: 1059.3819301892_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 118_f64,
+        b: 6.02407213861_f64,
+        c: 6151.533888305_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 114_f64,
+        b: 5.42803224317_f64,
+        c: 3738.761430108_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 134_f64,
+        b: 5.97421903927_f64,
+        c: 1748.016413067_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 114_f64,
+        b: 2.12869455089_f64,
+        c: 1194.4470102246_f64,
+    },
+];
+
+const L2: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 58016_f64,
+        b: 2.04979463279_f64,
+        c: 3340.6124266998_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 54188_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 13908_f64,
+        b: 2.45742359888_f64,
+        c: 6681.2248533996_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2465_f64,
+        b: 2.80000020929_f64,
+        c: 10021.8372800994_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 398_f64,
+        b: 3.14118428289_f64,
+        c: 13362.4497067992_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 222_f64,
+        b: 3.19436080019_f64,
+        c: 3.523118349_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 121_f64,
+        b: 0.54325292454_f64,
+        c: 155.4203994342_f64,
+    },
+];
+
+const L3: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 1482_f64,
+        b: 0.44434694876_f64,
+        c: 3340.6124266998_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 662_f64,
+        b: 0.88469178686_f64,
+        c: 6681.2248533996_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 188_f64,
+        b: 1.28799982497_f64,
+        c: 10021.8372800994_f64,
+    },
+];
+
+const L4: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 114_f64,
+        b: 3.14159265359_f64,
+        c: 0_f64,
+    },
+];
+
+const B0: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 3197135_f64,
+        b: 3.76832042432_f64,
+        c: 3340.6124266998_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 298033_f64,
+        b: 4.10616996243_f64,
+        c: 6681.2248533996_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 289105_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 31366_f64,
+        b: 4.44651052853_f64,
+        c: 10021.8372800994_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3484_f64,
+        b: 4.78812547889_f64,
+        c: 13362.4497067992_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 443_f64,
+        b: 5.65233015876_f64,
+        c: 3337.0893083508_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 443_f64,
+        b: 5.02642620491_f64,
+        c: 3344.1355450488_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 399_f64,
+        b: 5.130568147_f64,
+        c: 16703.062133499_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 293_f64,
+        b: 3.79290644595_f64,
+        c: 2281.2304965106_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 182_f64,
+        b: 6.13648011704_f64,
+        c: 6151.533888305_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 163_f64,
+        b: 4.26399626634_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 160_f64,
+        b: 2.23194610246_f64,
+        c: 1059.3819301892_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 139_f64,
+        b: 2.41796344238_f64,
+        c: 8962.4553499102_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 149_f64,
+        b: 2.16501209917_f64,
+        c: 5621.8429232104_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 143_f64,
+        b: 1.1821501611_f64,
+        c: 3340.5951730476_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 143_f64,
+        b: 3.2129218082_f64,
+        c: 3340.629680352_f64,
+    },
+];
+
+const B1: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 350069_f64,
+        b: 5.36847836211_f64,
+        c: 3340.6124266998_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 14116_f64,
+        b: 3.14159265359_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 9671_f64,
+        b: 5.47877786506_f64,
+        c: 6681.2248533996_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1472_f64,
+        b: 3.20205766795_f64,
+        c: 10021.8372800994_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 426_f64,
+        b: 3.40843812875_f64,
+        c: 13362.4497067992_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 102_f64,
+        b: 0.77617286189_f64,
+        c: 3337.0893083508_f64,
+    },
+];
+
+const B2: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 16727_f64,
+        b: 0.60221392419_f64,
+        c: 3340.6124266998_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4987_f64,
+        b: 3.14159265359_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 302_f64,
+        b: 5.55871276021_f64,
+        c: 6681.2248533996_f64,
+    },
+];
+
+const B3: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 607_f64,
+        b: 1.98050633529_f64,
+        c: 3340.6124266998_f64,
+    },
+];
+
+const R0: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 153033488_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 14184953_f64,
+        b: 3.47971283519_f64,
+        c: 3340.6124266998_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 660776_f64,
+        b: 3.81783442097_f64,
+        c: 6681.2248533996_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 46179_f64,
+        b: 4.15595316284_f64,
+        c: 10021.8372800994_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 8110_f64,
+        b: 5.55958460165_f64,
+        c: 2810.9214616052_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 7485_f64,
+        b: 1.77238998069_f64,
+        c: 5621.8429232104_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 5523_f64,
+        b: 1.3643631888_f64,
+        c: 2281.2304965106_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3825_f64,
+        b: 4.49407182408_f64,
+        c: 13362.4497067992_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2307_f64,
+        b: 0.09081742493_f64,
+        c: 2544.3144198834_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1999_f64,
+        b: 5.36059605227_f64,
+        c: 3337.0893083508_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2484_f64,
+        b: 4.92545577893_f64,
+        c: 2942.4634232916_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1960_f64,
+        b: 4.74249386323_f64,
+        c: 3344.1355450488_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1167_f64,
+        b: 2.11261501155_f64,
+        c: 5092.1519581158_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1103_f64,
+        b: 5.0090826416_f64,
+        c: 398.1490034082_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 899_f64,
+        b: 4.40790433994_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 992_f64,
+        b: 5.83862401067_f64,
+        c: 6151.533888305_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 807_f64,
+        b: 2.10216647104_f64,
+        c: 1059.3819301892_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 798_f64,
+        b: 3.44839026172_f64,
+        c: 796.2980068164_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 741_f64,
+        b: 1.49906336892_f64,
+        c: 2146.1654164752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 692_f64,
+        b: 2.13378814785_f64,
+        c: 8962.4553499102_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 633_f64,
+        b: 0.89353285018_f64,
+        c: 3340.5951730476_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 726_f64,
+        b: 1.24516913473_f64,
+        c: 8432.7643848156_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 633_f64,
+        b: 2.92430448169_f64,
+        c: 3340.629680352_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 574_f64,
+        b: 0.82896196337_f64,
+        c: 2914.0142358238_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 526_f64,
+        b: 5.38292276228_f64,
+        c: 3738.761430108_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 630_f64,
+        b: 1.28738135858_f64,
+        c: 1751.539531416_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 473_f64,
+        b: 5.19850457873_f64,
+        c: 3127.3133312618_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 348_f64,
+        b: 4.83219198908_f64,
+        c: 16703.062133499_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 284_f64,
+        b: 2.90692294913_f64,
+        c: 3532.0606928114_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 280_f64,
+        b: 5.25749247548_f64,
+        c: 6283.0758499914_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 234_f64,
+        b: 5.10546492529_f64,
+        c: 5486.777843175_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 219_f64,
+        b: 5.58340248784_f64,
+        c: 191.4482661116_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 270_f64,
+        b: 3.76394728622_f64,
+        c: 5884.9268465832_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 208_f64,
+        b: 5.25476080773_f64,
+        c: 3340.545116397_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 275_f64,
+        b: 2.90818883832_f64,
+        c: 1748.016413067_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 276_f64,
+        b: 1.21767967781_f64,
+        c: 6254.6266625236_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 239_f64,
+        b: 2.03669896238_f64,
+        c: 1194.4470102246_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 223_f64,
+        b: 4.19861593779_f64,
+        c: 3149.1641605882_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 183_f64,
+        b: 5.08062683355_f64,
+        c: 6684.7479717486_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 186_f64,
+        b: 5.69871555748_f64,
+        c: 6677.7017350506_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 176_f64,
+        b: 5.95341786369_f64,
+        c: 3870.3033917944_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 179_f64,
+        b: 4.18423025538_f64,
+        c: 3333.498879699_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 208_f64,
+        b: 4.84626442122_f64,
+        c: 3340.6797370026_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 228_f64,
+        b: 3.2552902062_f64,
+        c: 6872.6731195112_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 164_f64,
+        b: 3.79889068111_f64,
+        c: 4136.9104335162_f64,
+    },
+];
+
+const R1: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 1107433_f64,
+        b: 2.0325052495_f64,
+        c: 3340.6124266998_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 103176_f64,
+        b: 2.37071845682_f64,
+        c: 6681.2248533996_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 12877_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 10816_f64,
+        b: 2.70888093803_f64,
+        c: 10021.8372800994_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1195_f64,
+        b: 3.04702182503_f64,
+        c: 13362.4497067992_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 439_f64,
+        b: 2.88835072628_f64,
+        c: 2281.2304965106_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 396_f64,
+        b: 3.42324611291_f64,
+        c: 3344.1355450488_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 183_f64,
+        b: 1.58428644001_f64,
+        c: 2544.3144198834_f64,
+    },
+];
+
+const R2: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 44242_f64,
+        b: 0.47930603943_f64,
+        c: 3340.6124266998_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 8138_f64,
+        b: 0.86998398093_f64,
+        c: 6681.2248533996_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1275_f64,
+        b: 1.22594050809_f64,
+        c: 10021.8372800994_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 187_f64,
+        b: 1.57298991982_f64,
+        c: 13362.4497067992_f64,
+    },
+];
+
+const R3: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 1113_f64,
+        b: 5.14987350142_f64,
+        c: 3340.6124266998_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 424_f64,
+        b: 5.61343766478_f64,
+        c: 6681.2248533996_f64,
+    },
+];
+
+/// The periodic terms for the heliocentric coordinates of Mars, from
+/// VSOP87D with terms smaller than 1e-6 radians (or 1e-6 of the mean
+/// distance, for the radius vector) omitted
+pub const MARS_TERMS: PlanetPeriodicTerms = PlanetPeriodicTerms {
+    longitude: &[L0, L1, L2, L3, L4],
+    latitude: &[B0, B1, B2, B3],
+    radius: &[R0, R1, R2, R3],
+};
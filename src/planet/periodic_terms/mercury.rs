@@ -0,0 +1,658 @@
+#![allow(clippy::unreadable_literal, clippy::approx_constant)]
+
+use planet::periodic_terms::{PlanetPeriodicTableRow, PlanetPeriodicTerms};
+
+const L0: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 440250710_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 40989415_f64,
+        b: 1.48302034194_f64,
+        c: 26087.9031415742_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 5046294_f64,
+        b: 4.4778548954_f64,
+        c: 52175.8062831484_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 855347_f64,
+        b: 1.16520322351_f64,
+        c: 78263.70942472259_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 165590_f64,
+        b: 4.11969163181_f64,
+        c: 104351.61256629678_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 34562_f64,
+        b: 0.77930765817_f64,
+        c: 130439.51570787099_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 7583_f64,
+        b: 3.7134840051_f64,
+        c: 156527.41884944518_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3560_f64,
+        b: 1.51202669419_f64,
+        c: 1109.3785520934_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1726_f64,
+        b: 0.35832239908_f64,
+        c: 182615.3219910194_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1803_f64,
+        b: 4.1033317841_f64,
+        c: 5661.3320491522_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1365_f64,
+        b: 4.59918318745_f64,
+        c: 27197.2816936676_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1590_f64,
+        b: 2.99510417815_f64,
+        c: 25028.521211385_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1017_f64,
+        b: 0.8803143904_f64,
+        c: 31749.2351907264_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 714_f64,
+        b: 1.54144865265_f64,
+        c: 24978.5245894808_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 644_f64,
+        b: 5.30266110787_f64,
+        c: 21535.9496445154_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 404_f64,
+        b: 3.28228847025_f64,
+        c: 208703.2251325936_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 352_f64,
+        b: 5.24156297101_f64,
+        c: 20426.571092422_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 343_f64,
+        b: 5.76531885335_f64,
+        c: 955.5997416086_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 339_f64,
+        b: 5.86327765_f64,
+        c: 25558.2121764796_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 451_f64,
+        b: 6.04989275289_f64,
+        c: 51116.4243529592_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 325_f64,
+        b: 1.3367433478_f64,
+        c: 53285.1848352418_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 260_f64,
+        b: 0.98732428184_f64,
+        c: 4551.9534970588_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 345_f64,
+        b: 2.79211901539_f64,
+        c: 15874.6175953632_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 273_f64,
+        b: 2.49451163975_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 235_f64,
+        b: 0.266721189_f64,
+        c: 11322.6640983044_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 239_f64,
+        b: 0.11343953378_f64,
+        c: 1059.3819301892_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 264_f64,
+        b: 3.91705094013_f64,
+        c: 57837.1383323006_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 217_f64,
+        b: 0.65987207348_f64,
+        c: 13521.7514415914_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 183_f64,
+        b: 2.62878670784_f64,
+        c: 27043.5028831828_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 176_f64,
+        b: 4.53636829858_f64,
+        c: 51066.427731055_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 182_f64,
+        b: 2.43413502466_f64,
+        c: 25661.3049506982_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 209_f64,
+        b: 2.09178234008_f64,
+        c: 47623.8527860896_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 173_f64,
+        b: 2.45200164173_f64,
+        c: 24498.8302462904_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 142_f64,
+        b: 3.36003948842_f64,
+        c: 37410.5672398786_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 138_f64,
+        b: 0.29098447849_f64,
+        c: 10213.285546211_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 118_f64,
+        b: 2.78149786369_f64,
+        c: 77204.32749453338_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 125_f64,
+        b: 3.72079804425_f64,
+        c: 39609.6545831656_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 106_f64,
+        b: 4.20572116254_f64,
+        c: 19804.8272915828_f64,
+    },
+];
+
+const L1: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 2608814706223_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1126008_f64,
+        b: 6.21703970996_f64,
+        c: 26087.9031415742_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 303471_f64,
+        b: 3.05565472363_f64,
+        c: 52175.8062831484_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 80538_f64,
+        b: 6.10454743366_f64,
+        c: 78263.70942472259_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 21245_f64,
+        b: 2.83531934452_f64,
+        c: 104351.61256629678_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 5592_f64,
+        b: 5.82675673328_f64,
+        c: 130439.51570787099_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1472_f64,
+        b: 2.51845458395_f64,
+        c: 156527.41884944518_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 352_f64,
+        b: 3.05238094403_f64,
+        c: 1109.3785520934_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 388_f64,
+        b: 5.48039225891_f64,
+        c: 182615.3219910194_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 103_f64,
+        b: 2.14879173777_f64,
+        c: 208703.2251325936_f64,
+    },
+];
+
+const L2: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 53050_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 16904_f64,
+        b: 4.69072300649_f64,
+        c: 26087.9031415742_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 7397_f64,
+        b: 1.34735624669_f64,
+        c: 52175.8062831484_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3018_f64,
+        b: 4.45643539705_f64,
+        c: 78263.70942472259_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1107_f64,
+        b: 1.26226537554_f64,
+        c: 104351.61256629678_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 378_f64,
+        b: 4.319980559_f64,
+        c: 130439.51570787099_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 123_f64,
+        b: 1.06868541052_f64,
+        c: 156527.41884944518_f64,
+    },
+];
+
+const L3: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 188_f64,
+        b: 0.03466830117_f64,
+        c: 52175.8062831484_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 142_f64,
+        b: 3.125054526_f64,
+        c: 26087.9031415742_f64,
+    },
+];
+
+const L4: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 114_f64,
+        b: 3.14159265359_f64,
+        c: 0_f64,
+    },
+];
+
+const B0: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 11737529_f64,
+        b: 1.98357498767_f64,
+        c: 26087.9031415742_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2388077_f64,
+        b: 5.03738959685_f64,
+        c: 52175.8062831484_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1222840_f64,
+        b: 3.14159265359_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 543252_f64,
+        b: 1.79644363963_f64,
+        c: 78263.70942472259_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 129779_f64,
+        b: 4.83232503961_f64,
+        c: 104351.61256629678_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 31867_f64,
+        b: 1.58088495667_f64,
+        c: 130439.51570787099_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 7963_f64,
+        b: 4.60972126348_f64,
+        c: 156527.41884944518_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2014_f64,
+        b: 1.35324164694_f64,
+        c: 182615.3219910194_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 514_f64,
+        b: 4.37835409309_f64,
+        c: 208703.2251325936_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 208_f64,
+        b: 4.91772564073_f64,
+        c: 27197.2816936676_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 209_f64,
+        b: 2.02020294153_f64,
+        c: 24978.5245894808_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 132_f64,
+        b: 1.11908492283_f64,
+        c: 234791.12827416777_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 100_f64,
+        b: 5.65684734206_f64,
+        c: 20426.571092422_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 121_f64,
+        b: 1.81271752059_f64,
+        c: 53285.1848352418_f64,
+    },
+];
+
+const B1: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 429151_f64,
+        b: 3.50169780393_f64,
+        c: 26087.9031415742_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 146234_f64,
+        b: 3.14159265359_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 22675_f64,
+        b: 0.0151536688_f64,
+        c: 52175.8062831484_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 10895_f64,
+        b: 0.48540174006_f64,
+        c: 78263.70942472259_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 6353_f64,
+        b: 3.42943919982_f64,
+        c: 104351.61256629678_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2496_f64,
+        b: 0.16051210665_f64,
+        c: 130439.51570787099_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 860_f64,
+        b: 3.18452433647_f64,
+        c: 156527.41884944518_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 278_f64,
+        b: 6.21020774184_f64,
+        c: 182615.3219910194_f64,
+    },
+];
+
+const B2: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 11831_f64,
+        b: 4.79065585784_f64,
+        c: 26087.9031415742_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1914_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1045_f64,
+        b: 1.21216540536_f64,
+        c: 52175.8062831484_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 266_f64,
+        b: 4.43418336532_f64,
+        c: 78263.70942472259_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 170_f64,
+        b: 1.62255638714_f64,
+        c: 104351.61256629678_f64,
+    },
+];
+
+const B3: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 235_f64,
+        b: 0.35387524604_f64,
+        c: 26087.9031415742_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 161_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+];
+
+const R0: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 39528272_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 7834132_f64,
+        b: 6.19233722599_f64,
+        c: 26087.9031415742_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 795526_f64,
+        b: 2.95989690096_f64,
+        c: 52175.8062831484_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 121282_f64,
+        b: 6.01064153805_f64,
+        c: 78263.70942472259_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 21922_f64,
+        b: 2.77820093975_f64,
+        c: 104351.61256629678_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4354_f64,
+        b: 5.82894543257_f64,
+        c: 130439.51570787099_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 918_f64,
+        b: 2.59650562598_f64,
+        c: 156527.41884944518_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 260_f64,
+        b: 3.02817753482_f64,
+        c: 27197.2816936676_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 290_f64,
+        b: 1.42441936951_f64,
+        c: 25028.521211385_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 202_f64,
+        b: 5.6472504035_f64,
+        c: 182615.3219910194_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 201_f64,
+        b: 5.59227724202_f64,
+        c: 31749.2351907264_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 142_f64,
+        b: 6.25264202645_f64,
+        c: 24978.5245894808_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 100_f64,
+        b: 3.73435608689_f64,
+        c: 21535.9496445154_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 78_f64,
+        b: 3.66972526976_f64,
+        c: 20426.571092422_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 63_f64,
+        b: 4.29905918105_f64,
+        c: 25558.2121764796_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 63_f64,
+        b: 4.76588899933_f64,
+        c: 1059.3819301892_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 67_f64,
+        b: 2.52520309182_f64,
+        c: 5661.3320491522_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 76_f64,
+        b: 4.47428642962_f64,
+        c: 51116.4243529592_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 48_f64,
+        b: 6.06824478778_f64,
+        c: 53285.1848352418_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 46_f64,
+        b: 2.41480951648_f64,
+        c: 208703.2251325936_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 41_f64,
+        b: 2.35882016415_f64,
+        c: 57837.1383323006_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 44_f64,
+        b: 1.21957314874_f64,
+        c: 15874.6175953632_f64,
+    },
+];
+
+const R1: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 217348_f64,
+        b: 4.65617158663_f64,
+        c: 26087.9031415742_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 44142_f64,
+        b: 1.42385543975_f64,
+        c: 52175.8062831484_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 10094_f64,
+        b: 4.47466326316_f64,
+        c: 78263.70942472259_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2433_f64,
+        b: 1.24226083435_f64,
+        c: 104351.61256629678_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1624_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 604_f64,
+        b: 4.29303116561_f64,
+        c: 130439.51570787099_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 153_f64,
+        b: 1.0606077981_f64,
+        c: 156527.41884944518_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 39_f64,
+        b: 4.11136751416_f64,
+        c: 182615.3219910194_f64,
+    },
+];
+
+const R2: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 3118_f64,
+        b: 3.08231840296_f64,
+        c: 26087.9031415742_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1245_f64,
+        b: 6.15183317423_f64,
+        c: 52175.8062831484_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 425_f64,
+        b: 2.9258335296_f64,
+        c: 78263.70942472259_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 136_f64,
+        b: 5.97983925842_f64,
+        c: 104351.61256629678_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 42_f64,
+        b: 2.74936980629_f64,
+        c: 130439.51570787099_f64,
+    },
+];
+
+/// The periodic terms for the heliocentric coordinates of Mercury, from
+/// VSOP87D with terms smaller than 1e-6 radians (or 1e-6 of the mean
+/// distance, for the radius vector) omitted
+pub const MERCURY_TERMS: PlanetPeriodicTerms = PlanetPeriodicTerms {
+    longitude: &[L0, L1, L2, L3, L4],
+    latitude: &[B0, B1, B2, B3],
+    radius: &[R0, R1, R2],
+};
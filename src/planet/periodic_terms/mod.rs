@@ -0,0 +1,42 @@
+mod mercury;
+mod venus;
+mod mars;
+mod jupiter;
+mod saturn;
+mod uranus;
+mod neptune;
+mod phenomena;
+
+pub use self::mercury::*;
+pub use self::venus::*;
+pub use self::mars::*;
+pub use self::jupiter::*;
+pub use self::saturn::*;
+pub use self::uranus::*;
+pub use self::neptune::*;
+pub use self::phenomena::*;
+
+/// A row from a planet's periodic terms table, contributing
+/// `a * cos(b + c * τ)` (in units of 1e-8) for τ in Julian millennia
+#[derive(Debug, Copy, Clone)]
+pub struct PlanetPeriodicTableRow {
+    /// amplitude, in units of 1e-8 radians or AU
+    pub a: f64,
+    /// phase, in radians
+    pub b: f64,
+    /// frequency, in radians per Julian millennium
+    pub c: f64,
+}
+
+/// The periodic terms for a planet's heliocentric longitude, latitude and
+/// radius vector, each split into the series multiplied by successive
+/// powers of τ
+#[derive(Debug, Copy, Clone)]
+pub struct PlanetPeriodicTerms {
+    /// the L0, L1, ... series
+    pub longitude: &'static [&'static [PlanetPeriodicTableRow]],
+    /// the B0, B1, ... series
+    pub latitude: &'static [&'static [PlanetPeriodicTableRow]],
+    /// the R0, R1, ... series
+    pub radius: &'static [&'static [PlanetPeriodicTableRow]],
+}
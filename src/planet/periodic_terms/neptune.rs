@@ -0,0 +1,572 @@
+#![allow(clippy::unreadable_literal, clippy::approx_constant)]
+
+use planet::periodic_terms::{PlanetPeriodicTableRow, PlanetPeriodicTerms};
+
+const L0: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 531188633_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1798476_f64,
+        b: 2.9010127305_f64,
+        c: 38.1330356378_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1019728_f64,
+        b: 0.4858092366_f64,
+        c: 1.4844727083_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 124532_f64,
+        b: 4.83008090682_f64,
+        c: 36.6485629295_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 42064_f64,
+        b: 5.41054991607_f64,
+        c: 2.9689454166_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 37715_f64,
+        b: 6.09221834946_f64,
+        c: 35.1640902212_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 33785_f64,
+        b: 1.24488865578_f64,
+        c: 76.2660712756_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 16483_f64,
+        b: 0.00007729261_f64,
+        c: 491.5579294568_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 9199_f64,
+        b: 4.93747059924_f64,
+        c: 39.6175083461_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 8994_f64,
+        b: 0.27462142569_f64,
+        c: 175.1660598002_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4216_f64,
+        b: 1.98711914364_f64,
+        c: 73.297125859_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3365_f64,
+        b: 1.03590121818_f64,
+        c: 33.6796175129_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2285_f64,
+        b: 4.20606932559_f64,
+        c: 4.4534181249_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1434_f64,
+        b: 2.78340432711_f64,
+        c: 74.7815985673_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 900_f64,
+        b: 2.07606702418_f64,
+        c: 109.9456887885_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 745_f64,
+        b: 3.19032530145_f64,
+        c: 71.8126531507_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 506_f64,
+        b: 5.74785370252_f64,
+        c: 114.3991069134_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 400_f64,
+        b: 0.34972342569_f64,
+        c: 1021.2488945514_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 345_f64,
+        b: 3.46186210169_f64,
+        c: 41.1019810544_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 306_f64,
+        b: 0.49684039897_f64,
+        c: 0.5212648618_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 287_f64,
+        b: 4.50523446022_f64,
+        c: 0.0481841098_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 323_f64,
+        b: 2.24815188609_f64,
+        c: 32.1951448046_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 340_f64,
+        b: 3.30369900416_f64,
+        c: 77.7505439839_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 267_f64,
+        b: 4.88932609483_f64,
+        c: 0.9632078465_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 227_f64,
+        b: 1.79713054538_f64,
+        c: 453.424893819_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 245_f64,
+        b: 1.24693337933_f64,
+        c: 9.5612275556_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 233_f64,
+        b: 2.50459795017_f64,
+        c: 137.0330241624_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 282_f64,
+        b: 2.24565579693_f64,
+        c: 146.594251718_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 252_f64,
+        b: 5.78166597292_f64,
+        c: 388.4651552382_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 150_f64,
+        b: 2.99706110414_f64,
+        c: 5.9378908332_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 170_f64,
+        b: 3.3239063065_f64,
+        c: 108.4612160802_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 151_f64,
+        b: 2.1915309428_f64,
+        c: 33.9402499438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 148_f64,
+        b: 0.85948986145_f64,
+        c: 111.4301614968_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 119_f64,
+        b: 3.67706204305_f64,
+        c: 2.4476805548_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 102_f64,
+        b: 5.70539236951_f64,
+        c: 0.1118745846_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 103_f64,
+        b: 4.40441222_f64,
+        c: 70.3281804424_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 103_f64,
+        b: 0.04078966679_f64,
+        c: 0.2606324309_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 109_f64,
+        b: 2.41599378049_f64,
+        c: 183.2428146475_f64,
+    },
+];
+
+const L1: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 3837687717_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 16604_f64,
+        b: 4.86319129565_f64,
+        c: 1.4844727083_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 15807_f64,
+        b: 2.27923488532_f64,
+        c: 38.1330356378_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3335_f64,
+        b: 3.6819967602_f64,
+        c: 76.2660712756_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1306_f64,
+        b: 3.67320813491_f64,
+        c: 2.9689454166_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 605_f64,
+        b: 1.50477747549_f64,
+        c: 35.1640902212_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 179_f64,
+        b: 3.45318524147_f64,
+        c: 39.6175083461_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 107_f64,
+        b: 2.45126138334_f64,
+        c: 4.4534181249_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 106_f64,
+        b: 2.7547932655_f64,
+        c: 33.6796175129_f64,
+    },
+];
+
+const L2: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 53893_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 281_f64,
+        b: 1.19084538887_f64,
+        c: 38.1330356378_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 296_f64,
+        b: 1.85520292248_f64,
+        c: 1.4844727083_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 270_f64,
+        b: 5.72143228148_f64,
+        c: 76.2660712756_f64,
+    },
+];
+
+const B0: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 3088623_f64,
+        b: 1.44104372626_f64,
+        c: 38.1330356378_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 27780_f64,
+        b: 5.91271882843_f64,
+        c: 76.2660712756_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 27624_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 15355_f64,
+        b: 2.52123799481_f64,
+        c: 36.6485629295_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 15448_f64,
+        b: 3.50877080888_f64,
+        c: 39.6175083461_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2000_f64,
+        b: 1.50998669505_f64,
+        c: 74.7815985673_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1968_f64,
+        b: 4.37778195768_f64,
+        c: 1.4844727083_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1015_f64,
+        b: 3.21561035875_f64,
+        c: 35.1640902212_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 606_f64,
+        b: 2.80246601405_f64,
+        c: 73.297125859_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 595_f64,
+        b: 2.12892708114_f64,
+        c: 41.1019810544_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 589_f64,
+        b: 3.18655882497_f64,
+        c: 2.9689454166_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 402_f64,
+        b: 4.16883287237_f64,
+        c: 114.3991069134_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 254_f64,
+        b: 3.27120499438_f64,
+        c: 453.424893819_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 262_f64,
+        b: 3.76722704749_f64,
+        c: 213.299095438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 280_f64,
+        b: 1.68165309699_f64,
+        c: 77.7505439839_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 206_f64,
+        b: 4.25652348864_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 140_f64,
+        b: 3.52969556376_f64,
+        c: 137.0330241624_f64,
+    },
+];
+
+const B1: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 227279_f64,
+        b: 3.8079308987_f64,
+        c: 38.1330356378_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1803_f64,
+        b: 1.97576485377_f64,
+        c: 76.2660712756_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1386_f64,
+        b: 4.82555548018_f64,
+        c: 36.6485629295_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1433_f64,
+        b: 3.14159265359_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1073_f64,
+        b: 6.08054240712_f64,
+        c: 39.6175083461_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 148_f64,
+        b: 3.85766231348_f64,
+        c: 74.7815985673_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 136_f64,
+        b: 0.47764957338_f64,
+        c: 1.4844727083_f64,
+    },
+];
+
+const B2: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 9691_f64,
+        b: 5.57123750291_f64,
+        c: 38.1330356378_f64,
+    },
+];
+
+const B3: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 273_f64,
+        b: 1.01688979072_f64,
+        c: 38.1330356378_f64,
+    },
+];
+
+const R0: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 3007013206_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 27062259_f64,
+        b: 1.3299945893_f64,
+        c: 38.1330356378_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1691764_f64,
+        b: 3.25186138896_f64,
+        c: 36.6485629295_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 807831_f64,
+        b: 5.18592836167_f64,
+        c: 1.4844727083_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 537761_f64,
+        b: 4.52113902845_f64,
+        c: 35.1640902212_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 495726_f64,
+        b: 1.57105654815_f64,
+        c: 491.5579294568_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 274572_f64,
+        b: 1.84552256801_f64,
+        c: 175.1660598002_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 135134_f64,
+        b: 3.37220607384_f64,
+        c: 39.6175083461_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 121802_f64,
+        b: 5.79754444303_f64,
+        c: 76.2660712756_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 100895_f64,
+        b: 0.37702748681_f64,
+        c: 73.297125859_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 69792_f64,
+        b: 3.79617226928_f64,
+        c: 2.9689454166_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 46688_f64,
+        b: 5.74937810094_f64,
+        c: 33.6796175129_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 24594_f64,
+        b: 0.50801728204_f64,
+        c: 109.9456887885_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 16939_f64,
+        b: 1.59422166991_f64,
+        c: 71.8126531507_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 14230_f64,
+        b: 1.07786112902_f64,
+        c: 74.7815985673_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 12012_f64,
+        b: 1.92062131635_f64,
+        c: 1021.2488945514_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 8395_f64,
+        b: 0.67816895547_f64,
+        c: 146.594251718_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 7572_f64,
+        b: 1.07149263431_f64,
+        c: 388.4651552382_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 5721_f64,
+        b: 2.59059512267_f64,
+        c: 4.4534181249_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4840_f64,
+        b: 1.9068599107_f64,
+        c: 41.1019810544_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4483_f64,
+        b: 2.90573457534_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4270_f64,
+        b: 3.41343865825_f64,
+        c: 453.424893819_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4354_f64,
+        b: 0.6798566237_f64,
+        c: 32.1951448046_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4421_f64,
+        b: 1.74993796503_f64,
+        c: 108.4612160802_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3381_f64,
+        b: 0.84810683275_f64,
+        c: 183.2428146475_f64,
+    },
+];
+
+const R1: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 236339_f64,
+        b: 0.70498011235_f64,
+        c: 38.1330356378_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 13220_f64,
+        b: 3.32015499895_f64,
+        c: 1.4844727083_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 8622_f64,
+        b: 6.2162895163_f64,
+        c: 35.1640902212_f64,
+    },
+];
+
+const R2: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 4247_f64,
+        b: 5.89910679117_f64,
+        c: 38.1330356378_f64,
+    },
+];
+
+/// The periodic terms for the heliocentric coordinates of Neptune, from
+/// VSOP87D with terms smaller than 1e-6 radians (or 1e-6 of the mean
+/// distance, for the radius vector) omitted
+pub const NEPTUNE_TERMS: PlanetPeriodicTerms = PlanetPeriodicTerms {
+    longitude: &[L0, L1, L2],
+    latitude: &[B0, B1, B2, B3],
+    radius: &[R0, R1, R2],
+};
@@ -0,0 +1,1493 @@
+/// A row from one of Meeus' tables of periodic terms for the planetary
+/// phenomena, contributing `(c0 + c1 T + c2 T²)` days multiplied by the sine
+/// or cosine of a multiple of one of the arguments
+#[derive(Debug, Copy, Clone)]
+pub struct PlanetPhenomenonTableRow {
+    /// the argument: 0 for the planet's mean anomaly (M), or 1 to 7 for
+    /// Meeus' a to g
+    pub argument: usize,
+    /// multiple of the argument (0 for the constant term)
+    pub multiple: i8,
+    /// whether the term uses the cosine, rather than the sine, of the
+    /// argument
+    pub cosine: bool,
+    /// coefficients of 1, T and T², in days
+    pub coefficients: [f64; 3],
+}
+
+/// Periodic terms for the inferior conjunction of Mercury, in days, from Meeus (ch. 36)
+pub const MERCURY_INFERIOR_CONJUNCTION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [0.054_5_f64, 0.000_2_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [-6.200_8_f64, 0.007_4_f64, 0.000_03_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [-3.275_f64, -0.019_7_f64, 0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [0.473_7_f64, -0.005_2_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [0.811_1_f64, 0.003_3_f64, -0.000_02_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: false,
+        coefficients: [0.003_7_f64, 0.001_8_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: true,
+        coefficients: [-0.176_8_f64, 0_f64, 0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 4,
+        cosine: false,
+        coefficients: [-0.021_1_f64, -0.000_4_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 4,
+        cosine: true,
+        coefficients: [0.032_6_f64, -0.000_3_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 5,
+        cosine: false,
+        coefficients: [0.008_3_f64, 0.000_1_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 5,
+        cosine: true,
+        coefficients: [-0.004_f64, 0.000_1_f64, 0_f64],
+    },
+];
+
+/// Periodic terms for the superior conjunction of Mercury, in days, from Meeus (ch. 36)
+pub const MERCURY_SUPERIOR_CONJUNCTION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [-0.054_8_f64, -0.000_2_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [7.389_4_f64, -0.01_f64, -0.000_03_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [3.22_f64, 0.019_7_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [0.838_3_f64, -0.006_4_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [0.966_6_f64, 0.003_9_f64, -0.000_03_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: false,
+        coefficients: [0.077_f64, -0.002_6_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: true,
+        coefficients: [0.275_8_f64, 0.000_2_f64, -0.000_02_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 4,
+        cosine: false,
+        coefficients: [-0.012_8_f64, -0.000_8_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 4,
+        cosine: true,
+        coefficients: [0.073_4_f64, -0.000_4_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 5,
+        cosine: false,
+        coefficients: [-0.012_2_f64, -0.000_2_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 5,
+        cosine: true,
+        coefficients: [0.017_3_f64, -0.000_2_f64, 0_f64],
+    },
+];
+
+/// Periodic terms for the greatest eastern elongation of Mercury, in days, from Meeus (ch. 36)
+pub const MERCURY_GREATEST_EASTERN_ELONGATION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [-21.610_1_f64, 0.000_2_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [-1.980_3_f64, -0.006_f64, 0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [1.415_1_f64, -0.007_2_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [0.552_8_f64, -0.000_5_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [0.290_5_f64, 0.003_4_f64, 0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: false,
+        coefficients: [-0.112_1_f64, -0.000_1_f64, 0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: true,
+        coefficients: [-0.009_8_f64, -0.001_5_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 4,
+        cosine: false,
+        coefficients: [0.019_2_f64, 0_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 4,
+        cosine: true,
+        coefficients: [0.011_1_f64, 0.000_4_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 5,
+        cosine: false,
+        coefficients: [-0.006_1_f64, 0_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 5,
+        cosine: true,
+        coefficients: [-0.003_2_f64, -0.000_1_f64, 0_f64],
+    },
+];
+
+/// Periodic terms for the greatest western elongation of Mercury, in days, from Meeus (ch. 36)
+pub const MERCURY_GREATEST_WESTERN_ELONGATION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [21.624_9_f64, -0.000_2_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [0.130_6_f64, 0.006_5_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [-2.766_1_f64, -0.001_1_f64, 0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [0.243_8_f64, -0.002_4_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [0.576_7_f64, 0.002_3_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: false,
+        coefficients: [0.104_1_f64, 0_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: true,
+        coefficients: [-0.018_4_f64, 0.000_7_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 4,
+        cosine: false,
+        coefficients: [-0.005_1_f64, -0.000_1_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 4,
+        cosine: true,
+        coefficients: [0.004_8_f64, 0.000_1_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 5,
+        cosine: false,
+        coefficients: [0.002_6_f64, 0_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 5,
+        cosine: true,
+        coefficients: [0.003_7_f64, 0_f64, 0_f64],
+    },
+];
+
+/// Periodic terms for the retrograde station of Mercury, in days, from Meeus (ch. 36)
+pub const MERCURY_RETROGRADE_STATION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [-11.076_1_f64, 0.000_3_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [-4.732_1_f64, 0.002_3_f64, 0.000_02_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [-1.323_f64, -0.015_6_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [0.227_f64, -0.004_6_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [0.718_4_f64, 0.001_3_f64, -0.000_02_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: false,
+        coefficients: [0.063_8_f64, 0.001_6_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: true,
+        coefficients: [-0.165_5_f64, 0.000_7_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 4,
+        cosine: false,
+        coefficients: [-0.039_5_f64, -0.000_3_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 4,
+        cosine: true,
+        coefficients: [0.024_7_f64, -0.000_6_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 5,
+        cosine: false,
+        coefficients: [0.013_1_f64, 0_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 5,
+        cosine: true,
+        coefficients: [0.000_8_f64, 0.000_2_f64, 0_f64],
+    },
+];
+
+/// Periodic terms for the direct station of Mercury, in days, from Meeus (ch. 36)
+/// with the cos 3M and cos 4M coefficients refitted against VSOP87
+pub const MERCURY_DIRECT_STATION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [11.134_3_f64, -0.000_1_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [-3.913_7_f64, 0.007_3_f64, 0.000_02_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [-3.386_1_f64, -0.012_8_f64, 0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [0.522_2_f64, -0.004_f64, -0.000_02_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [0.592_9_f64, 0.003_9_f64, -0.000_02_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: false,
+        coefficients: [-0.059_3_f64, 0.001_8_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: true,
+        coefficients: [-0.174_5_f64, 0.000_4_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 4,
+        cosine: false,
+        coefficients: [-0.006_6_f64, 0.000_1_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 4,
+        cosine: true,
+        coefficients: [0.048_3_f64, -0.000_6_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 5,
+        cosine: false,
+        coefficients: [0.008_7_f64, 0_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 5,
+        cosine: true,
+        coefficients: [-0.013_2_f64, 0_f64, 0_f64],
+    },
+];
+
+/// Periodic terms for the inferior conjunction of Venus, in days, from Meeus (ch. 36)
+pub const VENUS_INFERIOR_CONJUNCTION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [-0.009_6_f64, 0.000_2_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [2.000_9_f64, -0.003_3_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [0.598_f64, -0.010_4_f64, 0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [0.096_7_f64, -0.001_8_f64, -0.000_03_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [0.091_3_f64, 0.000_9_f64, -0.000_02_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: false,
+        coefficients: [0.004_6_f64, -0.000_2_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: true,
+        coefficients: [0.007_9_f64, 0.000_1_f64, 0_f64],
+    },
+];
+
+/// Periodic terms for the superior conjunction of Venus, in days, from Meeus (ch. 36)
+pub const VENUS_SUPERIOR_CONJUNCTION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [0.009_9_f64, -0.000_2_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [4.199_1_f64, -0.012_1_f64, -0.000_03_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [-0.609_5_f64, 0.010_2_f64, -0.000_02_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [0.25_f64, -0.002_8_f64, -0.000_03_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [0.006_3_f64, 0.002_5_f64, -0.000_02_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: false,
+        coefficients: [0.023_2_f64, -0.000_5_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: true,
+        coefficients: [0.003_1_f64, 0.000_4_f64, 0_f64],
+    },
+];
+
+/// Periodic terms for the greatest eastern elongation of Venus, in days, from Meeus (ch. 36)
+pub const VENUS_GREATEST_EASTERN_ELONGATION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [-70.76_f64, 0.000_2_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [1.028_2_f64, -0.001_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [0.276_1_f64, -0.006_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [-0.043_8_f64, -0.002_3_f64, 0.000_02_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [0.166_f64, -0.003_7_f64, -0.000_04_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: false,
+        coefficients: [0.003_6_f64, 0.000_1_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: true,
+        coefficients: [-0.001_1_f64, 0_f64, 0.000_01_f64],
+    },
+];
+
+/// Periodic terms for the greatest western elongation of Venus, in days, from Meeus (ch. 36)
+pub const VENUS_GREATEST_WESTERN_ELONGATION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [70.746_2_f64, 0_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [1.121_8_f64, -0.002_5_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [0.453_8_f64, -0.006_6_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [0.132_f64, 0.002_f64, -0.000_03_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [-0.070_2_f64, 0.002_2_f64, 0.000_04_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: false,
+        coefficients: [0.006_2_f64, -0.000_1_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: true,
+        coefficients: [0.001_5_f64, 0_f64, -0.000_01_f64],
+    },
+];
+
+/// Periodic terms for the retrograde station of Venus, in days, from Meeus (ch. 36)
+pub const VENUS_RETROGRADE_STATION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [-21.067_2_f64, 0.000_2_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [1.939_6_f64, -0.002_9_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [1.072_7_f64, -0.010_2_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [0.040_4_f64, -0.002_3_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [0.130_5_f64, -0.000_4_f64, -0.000_03_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: false,
+        coefficients: [-0.000_7_f64, -0.000_2_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: true,
+        coefficients: [0.009_8_f64, 0_f64, 0_f64],
+    },
+];
+
+/// Periodic terms for the direct station of Venus, in days, from Meeus (ch. 36)
+pub const VENUS_DIRECT_STATION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [21.062_3_f64, 0_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [1.991_3_f64, -0.004_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [-0.040_7_f64, 0.007_7_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [0.135_1_f64, -0.000_9_f64, -0.000_04_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [0.030_3_f64, 0.001_9_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: false,
+        coefficients: [0.000_2_f64, -0.000_2_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: true,
+        coefficients: [0.010_7_f64, 0_f64, 0_f64],
+    },
+];
+
+/// Periodic terms for the opposition of Mars, in days, from Meeus (ch. 36)
+pub const MARS_OPPOSITION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [-0.308_8_f64, 0_f64, 0.000_02_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [-17.646_5_f64, 0.036_3_f64, 0.000_05_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [18.313_1_f64, 0.046_7_f64, -0.000_06_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [-0.216_2_f64, -0.019_8_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [-4.502_8_f64, -0.001_9_f64, 0.000_07_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: false,
+        coefficients: [0.898_7_f64, 0.005_8_f64, -0.000_02_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: true,
+        coefficients: [0.766_6_f64, -0.005_f64, -0.000_03_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 4,
+        cosine: false,
+        coefficients: [-0.363_6_f64, -0.000_1_f64, 0.000_02_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 4,
+        cosine: true,
+        coefficients: [0.040_2_f64, 0.003_2_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 5,
+        cosine: false,
+        coefficients: [0.073_7_f64, -0.000_8_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 5,
+        cosine: true,
+        coefficients: [-0.098_f64, -0.001_1_f64, 0_f64],
+    },
+];
+
+/// Periodic terms for the conjunction of Mars, in days, from Meeus (ch. 36)
+pub const MARS_CONJUNCTION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [0.310_2_f64, -0.000_1_f64, 0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [9.727_3_f64, -0.015_6_f64, 0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [-18.319_5_f64, -0.046_7_f64, 0.000_09_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [-1.648_8_f64, -0.013_3_f64, 0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [-2.611_7_f64, -0.002_f64, 0.000_04_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: false,
+        coefficients: [-0.682_7_f64, -0.002_6_f64, 0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: true,
+        coefficients: [0.028_1_f64, 0.003_5_f64, 0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 4,
+        cosine: false,
+        coefficients: [-0.082_3_f64, 0.000_6_f64, 0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 4,
+        cosine: true,
+        coefficients: [0.158_4_f64, 0.001_3_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 5,
+        cosine: false,
+        coefficients: [0.027_f64, 0.000_5_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 5,
+        cosine: true,
+        coefficients: [0.043_3_f64, 0_f64, 0_f64],
+    },
+];
+
+/// Periodic terms for the retrograde station of Mars, in days, from Meeus (ch. 36)
+pub const MARS_RETROGRADE_STATION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [-37.079_f64, -0.000_9_f64, 0.000_02_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [-20.065_1_f64, 0.022_8_f64, 0.000_04_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [14.520_5_f64, 0.050_4_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [1.173_7_f64, -0.016_9_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [-4.255_f64, -0.007_5_f64, 0.000_08_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: false,
+        coefficients: [0.489_7_f64, 0.007_4_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: true,
+        coefficients: [1.115_1_f64, -0.002_1_f64, -0.000_05_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 4,
+        cosine: false,
+        coefficients: [-0.363_6_f64, -0.002_f64, 0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 4,
+        cosine: true,
+        coefficients: [-0.176_9_f64, 0.002_8_f64, 0.000_02_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 5,
+        cosine: false,
+        coefficients: [0.143_7_f64, -0.000_4_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 5,
+        cosine: true,
+        coefficients: [-0.038_3_f64, -0.001_6_f64, 0_f64],
+    },
+];
+
+/// Periodic terms for the direct station of Mars, in days, from Meeus (ch. 36)
+pub const MARS_DIRECT_STATION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [36.719_1_f64, 0.001_6_f64, 0.000_03_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [-12.616_3_f64, 0.041_7_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [20.121_8_f64, 0.037_9_f64, -0.000_06_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [-1.636_f64, -0.019_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [-3.965_7_f64, 0.004_5_f64, 0.000_07_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: false,
+        coefficients: [1.154_6_f64, 0.002_9_f64, -0.000_03_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: true,
+        coefficients: [0.288_8_f64, -0.007_3_f64, -0.000_02_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 4,
+        cosine: false,
+        coefficients: [-0.312_8_f64, 0.001_7_f64, 0.000_02_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 4,
+        cosine: true,
+        coefficients: [0.251_3_f64, 0.002_6_f64, -0.000_02_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 5,
+        cosine: false,
+        coefficients: [-0.002_1_f64, -0.001_6_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 5,
+        cosine: true,
+        coefficients: [-0.149_7_f64, -0.000_6_f64, 0_f64],
+    },
+];
+
+/// Periodic terms for the opposition of Jupiter, in days, from Meeus (ch. 36)
+pub const JUPITER_OPPOSITION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [-0.102_9_f64, 0_f64, -0.000_09_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [-1.965_8_f64, -0.005_6_f64, 0.000_07_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [6.153_7_f64, 0.021_f64, -0.000_06_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [-0.208_1_f64, -0.001_3_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [-0.111_6_f64, -0.001_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: false,
+        coefficients: [0.007_4_f64, 0.000_1_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: true,
+        coefficients: [-0.009_7_f64, -0.000_1_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 1,
+        multiple: 1,
+        cosine: false,
+        coefficients: [0.014_4_f64, -0.000_8_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 1,
+        multiple: 1,
+        cosine: true,
+        coefficients: [0.364_2_f64, -0.001_9_f64, -0.000_29_f64],
+    },
+];
+
+/// Periodic terms for the conjunction of Jupiter, in days, from Meeus (ch. 36)
+pub const JUPITER_CONJUNCTION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [0.102_7_f64, 0.000_2_f64, -0.000_09_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [-2.263_7_f64, 0.016_3_f64, -0.000_03_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [-6.154_f64, -0.021_f64, 0.000_08_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [-0.202_1_f64, -0.001_7_f64, 0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [0.131_f64, -0.000_8_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: false,
+        coefficients: [0.008_6_f64, 0_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: true,
+        coefficients: [0.008_7_f64, 0.000_2_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 1,
+        multiple: 1,
+        cosine: false,
+        coefficients: [0_f64, 0.014_4_f64, -0.000_08_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 1,
+        multiple: 1,
+        cosine: true,
+        coefficients: [0.364_2_f64, -0.001_9_f64, -0.000_29_f64],
+    },
+];
+
+/// Periodic terms for the opposition of Saturn, in days, from Meeus (ch. 36)
+pub const SATURN_OPPOSITION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [-0.020_9_f64, 0.000_6_f64, 0.000_23_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [4.579_5_f64, -0.031_2_f64, -0.000_17_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [1.146_2_f64, -0.035_1_f64, 0.000_11_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [0.098_5_f64, -0.001_5_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [0.073_3_f64, -0.003_1_f64, 0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: false,
+        coefficients: [0.002_5_f64, -0.000_1_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: true,
+        coefficients: [0.005_f64, -0.000_2_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 1,
+        multiple: 1,
+        cosine: false,
+        coefficients: [0_f64, -0.033_7_f64, 0.000_18_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 1,
+        multiple: 1,
+        cosine: true,
+        coefficients: [-0.851_f64, 0.004_4_f64, 0.000_68_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 2,
+        multiple: 1,
+        cosine: false,
+        coefficients: [-0.006_4_f64, 0.003_4_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 2,
+        multiple: 1,
+        cosine: true,
+        coefficients: [0.239_7_f64, -0.001_2_f64, -0.000_08_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 3,
+        multiple: 1,
+        cosine: false,
+        coefficients: [0_f64, -0.001_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 3,
+        multiple: 1,
+        cosine: true,
+        coefficients: [0.124_5_f64, 0.000_6_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 4,
+        multiple: 1,
+        cosine: false,
+        coefficients: [0.010_4_f64, -0.000_1_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 4,
+        multiple: 1,
+        cosine: true,
+        coefficients: [-0.005_6_f64, 0.000_1_f64, 0_f64],
+    },
+];
+
+/// Periodic terms for the conjunction of Saturn, in days, from Meeus (ch. 36)
+pub const SATURN_CONJUNCTION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [0.017_2_f64, -0.000_6_f64, 0.000_23_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [-8.588_5_f64, 0.041_1_f64, 0.000_2_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [-1.147_f64, 0.035_2_f64, -0.000_11_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [0.333_1_f64, -0.003_4_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [0.114_5_f64, -0.004_5_f64, 0.000_02_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: false,
+        coefficients: [-0.016_9_f64, 0.000_2_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: true,
+        coefficients: [-0.010_9_f64, 0.000_4_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 1,
+        multiple: 1,
+        cosine: false,
+        coefficients: [0_f64, -0.033_7_f64, 0.000_18_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 1,
+        multiple: 1,
+        cosine: true,
+        coefficients: [-0.851_f64, 0.004_4_f64, 0.000_68_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 2,
+        multiple: 1,
+        cosine: false,
+        coefficients: [-0.006_4_f64, 0.003_4_f64, -0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 2,
+        multiple: 1,
+        cosine: true,
+        coefficients: [0.239_7_f64, -0.001_2_f64, -0.000_08_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 3,
+        multiple: 1,
+        cosine: false,
+        coefficients: [0_f64, -0.001_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 3,
+        multiple: 1,
+        cosine: true,
+        coefficients: [0.124_5_f64, 0.000_6_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 4,
+        multiple: 1,
+        cosine: false,
+        coefficients: [0.010_4_f64, -0.000_1_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 4,
+        multiple: 1,
+        cosine: true,
+        coefficients: [-0.005_6_f64, 0.000_1_f64, 0_f64],
+    },
+];
+
+/// Periodic terms for the opposition of Uranus, in days, from Meeus (ch. 36)
+pub const URANUS_OPPOSITION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [0.084_4_f64, -0.000_6_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [-0.104_8_f64, 0.024_6_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [-5.122_1_f64, 0.010_4_f64, 0.000_03_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [-0.142_8_f64, 0.000_5_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [-0.014_8_f64, -0.001_3_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: true,
+        coefficients: [0.005_5_f64, 0_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 5,
+        multiple: 1,
+        cosine: true,
+        coefficients: [0.885_f64, 0_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 6,
+        multiple: 1,
+        cosine: true,
+        coefficients: [0.215_3_f64, 0_f64, 0_f64],
+    },
+];
+
+/// Periodic terms for the conjunction of Uranus, in days, from Meeus (ch. 36)
+pub const URANUS_CONJUNCTION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [-0.085_9_f64, 0.000_3_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [-3.817_9_f64, -0.014_8_f64, 0.000_03_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [5.122_8_f64, -0.010_5_f64, -0.000_02_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [-0.080_3_f64, 0.001_1_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [-0.190_5_f64, -0.000_6_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 3,
+        cosine: false,
+        coefficients: [0.008_8_f64, 0.000_1_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 5,
+        multiple: 1,
+        cosine: true,
+        coefficients: [0.885_f64, 0_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 6,
+        multiple: 1,
+        cosine: true,
+        coefficients: [0.215_3_f64, 0_f64, 0_f64],
+    },
+];
+
+/// Periodic terms for the opposition of Neptune, in days, from Meeus (ch. 36)
+pub const NEPTUNE_OPPOSITION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [-0.014_f64, 0_f64, 0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [-1.348_6_f64, 0.001_f64, 0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [0.859_7_f64, 0.003_7_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [-0.008_2_f64, -0.000_2_f64, 0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [0.003_7_f64, -0.000_3_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 5,
+        multiple: 1,
+        cosine: true,
+        coefficients: [-0.596_4_f64, 0_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 7,
+        multiple: 1,
+        cosine: true,
+        coefficients: [0.072_8_f64, 0_f64, 0_f64],
+    },
+];
+
+/// Periodic terms for the conjunction of Neptune, in days, from Meeus (ch. 36)
+pub const NEPTUNE_CONJUNCTION_TERMS: &[PlanetPhenomenonTableRow] = &[
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 0,
+        cosine: true,
+        coefficients: [0.016_8_f64, 0_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: false,
+        coefficients: [-2.560_6_f64, 0.008_8_f64, 0.000_02_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 1,
+        cosine: true,
+        coefficients: [-0.861_1_f64, -0.003_7_f64, 0.000_02_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: false,
+        coefficients: [0.011_8_f64, -0.000_4_f64, 0.000_01_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 0,
+        multiple: 2,
+        cosine: true,
+        coefficients: [0.030_7_f64, -0.000_3_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 5,
+        multiple: 1,
+        cosine: true,
+        coefficients: [-0.596_4_f64, 0_f64, 0_f64],
+    },
+    PlanetPhenomenonTableRow {
+        argument: 7,
+        multiple: 1,
+        cosine: true,
+        coefficients: [0.072_8_f64, 0_f64, 0_f64],
+    },
+];
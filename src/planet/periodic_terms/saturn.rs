@@ -0,0 +1,1827 @@
+#![allow(clippy::unreadable_literal, clippy::approx_constant)]
+
+use planet::periodic_terms::{PlanetPeriodicTableRow, PlanetPeriodicTerms};
+
+const L0: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 87401354_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 11107660_f64,
+        b: 3.96205090194_f64,
+        c: 213.299095438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1414151_f64,
+        b: 4.58581515873_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 398379_f64,
+        b: 0.52112025957_f64,
+        c: 206.1855484372_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 350769_f64,
+        b: 3.30329903015_f64,
+        c: 426.598190876_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 206816_f64,
+        b: 0.24658366938_f64,
+        c: 103.0927742186_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 79271_f64,
+        b: 3.8400707853_f64,
+        c: 220.4126424388_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 23990_f64,
+        b: 4.6697693486_f64,
+        c: 110.2063212194_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 16574_f64,
+        b: 0.43719123541_f64,
+        c: 419.4846438752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 14907_f64,
+        b: 5.76903283845_f64,
+        c: 316.3918696566_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 15820_f64,
+        b: 0.9380895376_f64,
+        c: 632.7837393132_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 14610_f64,
+        b: 1.56518573691_f64,
+        c: 3.9321532631_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 13160_f64,
+        b: 4.44891180176_f64,
+        c: 14.2270940016_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 15054_f64,
+        b: 2.71670027883_f64,
+        c: 639.897286314_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 13005_f64,
+        b: 5.98119067061_f64,
+        c: 11.0457002639_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 10725_f64,
+        b: 3.12939596466_f64,
+        c: 202.2533951741_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 5863_f64,
+        b: 0.23657028777_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 5228_f64,
+        b: 4.2078316238_f64,
+        c: 3.1813937377_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 6126_f64,
+        b: 1.76328499656_f64,
+        c: 277.0349937414_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 5020_f64,
+        b: 3.17787919533_f64,
+        c: 433.7117378768_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4593_f64,
+        b: 0.61976424374_f64,
+        c: 199.0720014364_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4006_f64,
+        b: 2.24479893937_f64,
+        c: 63.7358983034_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2954_f64,
+        b: 0.98280385206_f64,
+        c: 95.9792272178_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3874_f64,
+        b: 3.22282692566_f64,
+        c: 138.5174968707_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2461_f64,
+        b: 2.03163631205_f64,
+        c: 735.8765135318_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3269_f64,
+        b: 0.77491895787_f64,
+        c: 949.1756089698_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1758_f64,
+        b: 3.26580514774_f64,
+        c: 522.5774180938_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1640_f64,
+        b: 5.50504966218_f64,
+        c: 846.0828347512_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1391_f64,
+        b: 4.02331978116_f64,
+        c: 323.5054166574_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1581_f64,
+        b: 4.3726631412_f64,
+        c: 309.2783226558_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1124_f64,
+        b: 2.83726793572_f64,
+        c: 415.5524906121_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1017_f64,
+        b: 3.71698151814_f64,
+        c: 227.5261894396_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 849_f64,
+        b: 3.19149825839_f64,
+        c: 209.3669421749_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1087_f64,
+        b: 4.18343232481_f64,
+        c: 2.4476805548_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 957_f64,
+        b: 0.50740889886_f64,
+        c: 1265.5674786264_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 789_f64,
+        b: 5.00745123149_f64,
+        c: 0.9632078465_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 687_f64,
+        b: 1.74714407827_f64,
+        c: 1052.2683831884_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 654_f64,
+        b: 1.59889331515_f64,
+        c: 0.0481841098_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 749_f64,
+        b: 2.14398149298_f64,
+        c: 853.196381752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 634_f64,
+        b: 2.29889903023_f64,
+        c: 412.3710968744_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 744_f64,
+        b: 5.25276954625_f64,
+        c: 224.3447957019_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 853_f64,
+        b: 3.42141350697_f64,
+        c: 175.1660598002_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 580_f64,
+        b: 3.09259007048_f64,
+        c: 74.7815985673_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 625_f64,
+        b: 0.97046831256_f64,
+        c: 210.1177017003_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 530_f64,
+        b: 4.44938897119_f64,
+        c: 117.3198682202_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 543_f64,
+        b: 1.51824320514_f64,
+        c: 9.5612275556_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 474_f64,
+        b: 5.47527185987_f64,
+        c: 742.9900605326_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 449_f64,
+        b: 1.28990416161_f64,
+        c: 127.4717966068_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 546_f64,
+        b: 2.12678554211_f64,
+        c: 350.3321196004_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 478_f64,
+        b: 2.96488054338_f64,
+        c: 137.0330241624_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 355_f64,
+        b: 3.0128648303_f64,
+        c: 838.9692877504_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 452_f64,
+        b: 1.04436664241_f64,
+        c: 490.3340891794_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 347_f64,
+        b: 1.53928227764_f64,
+        c: 340.7708920448_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 343_f64,
+        b: 0.24604039134_f64,
+        c: 0.5212648618_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 309_f64,
+        b: 3.49486734909_f64,
+        c: 216.4804891757_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 322_f64,
+        b: 0.96137456104_f64,
+        c: 203.7378678824_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 372_f64,
+        b: 2.27819108625_f64,
+        c: 217.2312487011_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 322_f64,
+        b: 2.57182354537_f64,
+        c: 647.0108333148_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 330_f64,
+        b: 0.24715617844_f64,
+        c: 1581.959348283_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 249_f64,
+        b: 1.47010534421_f64,
+        c: 1368.660252845_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 287_f64,
+        b: 2.37043745859_f64,
+        c: 351.8165923087_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 220_f64,
+        b: 4.20422424873_f64,
+        c: 200.7689224658_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 278_f64,
+        b: 0.40020408926_f64,
+        c: 211.8146227297_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 204_f64,
+        b: 6.010822066_f64,
+        c: 265.9892934775_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 208_f64,
+        b: 0.48349820488_f64,
+        c: 1162.4747044078_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 209_f64,
+        b: 1.34516255304_f64,
+        c: 625.6701923124_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 182_f64,
+        b: 5.49122292426_f64,
+        c: 2.9207613068_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 227_f64,
+        b: 4.91003163138_f64,
+        c: 12.5301729722_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 208_f64,
+        b: 1.283022189_f64,
+        c: 39.3568759152_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 174_f64,
+        b: 1.86305806814_f64,
+        c: 0.7507595254_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 185_f64,
+        b: 3.50344404958_f64,
+        c: 149.5631971346_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 184_f64,
+        b: 0.97254952728_f64,
+        c: 4.192785694_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 146_f64,
+        b: 6.23102544071_f64,
+        c: 195.1398481733_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 165_f64,
+        b: 0.4400551752_f64,
+        c: 5.4166259714_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 148_f64,
+        b: 1.53529320509_f64,
+        c: 5.6290742925_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 140_f64,
+        b: 4.29450260069_f64,
+        c: 21.3406410024_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 131_f64,
+        b: 4.06828961903_f64,
+        c: 10.2949407385_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 117_f64,
+        b: 2.67920400584_f64,
+        c: 1155.361157407_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 149_f64,
+        b: 5.73594349789_f64,
+        c: 52.6901980395_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 122_f64,
+        b: 1.97588777199_f64,
+        c: 4.665866446_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 114_f64,
+        b: 5.59427544714_f64,
+        c: 1059.3819301892_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 103_f64,
+        b: 1.19748124058_f64,
+        c: 1685.0521225016_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 118_f64,
+        b: 5.340729339_f64,
+        c: 554.0699874828_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 109_f64,
+        b: 3.43812715686_f64,
+        c: 536.8045120954_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 110_f64,
+        b: 0.1660402409_f64,
+        c: 1.4844727083_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 125_f64,
+        b: 6.27737805832_f64,
+        c: 1898.3512179396_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 104_f64,
+        b: 2.19210363069_f64,
+        c: 88.865680217_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 112_f64,
+        b: 1.10502663534_f64,
+        c: 191.2076949102_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 107_f64,
+        b: 4.01156608514_f64,
+        c: 956.2891559706_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 101_f64,
+        b: 4.96513666539_f64,
+        c: 269.9214467406_f64,
+    },
+];
+
+const L1: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 21354295596_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1296855_f64,
+        b: 1.82820544701_f64,
+        c: 213.299095438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 564348_f64,
+        b: 2.88500136429_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 98323_f64,
+        b: 1.08070061328_f64,
+        c: 426.598190876_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 107679_f64,
+        b: 2.27769911872_f64,
+        c: 206.1855484372_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 40255_f64,
+        b: 2.0412825709_f64,
+        c: 220.4126424388_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 19942_f64,
+        b: 1.27954662736_f64,
+        c: 103.0927742186_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 10512_f64,
+        b: 2.748803928_f64,
+        c: 14.2270940016_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 6939_f64,
+        b: 0.40493079985_f64,
+        c: 639.897286314_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4803_f64,
+        b: 2.44194097666_f64,
+        c: 419.4846438752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4056_f64,
+        b: 2.92166618776_f64,
+        c: 110.2063212194_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3769_f64,
+        b: 3.6496563146_f64,
+        c: 3.9321532631_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3385_f64,
+        b: 2.41694251653_f64,
+        c: 3.1813937377_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3302_f64,
+        b: 1.26256486715_f64,
+        c: 433.7117378768_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3071_f64,
+        b: 2.3273931775_f64,
+        c: 199.0720014364_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1953_f64,
+        b: 3.563946833_f64,
+        c: 11.0457002639_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1249_f64,
+        b: 2.62803737519_f64,
+        c: 95.9792272178_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 922_f64,
+        b: 1.9608983425_f64,
+        c: 227.5261894396_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 706_f64,
+        b: 4.4168924933_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 650_f64,
+        b: 6.17418093659_f64,
+        c: 202.2533951741_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 628_f64,
+        b: 6.11088227167_f64,
+        c: 309.2783226558_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 487_f64,
+        b: 6.03998200305_f64,
+        c: 853.196381752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 468_f64,
+        b: 4.61707843907_f64,
+        c: 63.7358983034_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 479_f64,
+        b: 4.98776987984_f64,
+        c: 522.5774180938_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 417_f64,
+        b: 2.11708169277_f64,
+        c: 323.5054166574_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 408_f64,
+        b: 1.29949556676_f64,
+        c: 209.3669421749_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 344_f64,
+        b: 3.95854178574_f64,
+        c: 412.3710968744_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 340_f64,
+        b: 3.63396398752_f64,
+        c: 316.3918696566_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 336_f64,
+        b: 3.77173072712_f64,
+        c: 735.8765135318_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 332_f64,
+        b: 2.86077699882_f64,
+        c: 210.1177017003_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 352_f64,
+        b: 2.31707079463_f64,
+        c: 632.7837393132_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 289_f64,
+        b: 2.73263080235_f64,
+        c: 117.3198682202_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 266_f64,
+        b: 0.54344631312_f64,
+        c: 647.0108333148_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 230_f64,
+        b: 1.64428879621_f64,
+        c: 216.4804891757_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 281_f64,
+        b: 5.74398845416_f64,
+        c: 2.4476805548_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 192_f64,
+        b: 2.96512946582_f64,
+        c: 224.3447957019_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 173_f64,
+        b: 4.07695221044_f64,
+        c: 846.0828347512_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 167_f64,
+        b: 2.59745202658_f64,
+        c: 21.3406410024_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 136_f64,
+        b: 2.28580246629_f64,
+        c: 10.2949407385_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 131_f64,
+        b: 3.44108355646_f64,
+        c: 742.9900605326_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 128_f64,
+        b: 4.09533471247_f64,
+        c: 217.2312487011_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 109_f64,
+        b: 6.16141072262_f64,
+        c: 415.5524906121_f64,
+    },
+];
+
+const L2: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 116441_f64,
+        b: 1.17987850633_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 91921_f64,
+        b: 0.07425261094_f64,
+        c: 213.299095438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 90592_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 15277_f64,
+        b: 4.06492007503_f64,
+        c: 206.1855484372_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 10631_f64,
+        b: 0.25778277414_f64,
+        c: 220.4126424388_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 10605_f64,
+        b: 5.40963595885_f64,
+        c: 426.598190876_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4265_f64,
+        b: 1.0459555663_f64,
+        c: 14.2270940016_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1216_f64,
+        b: 2.91860042123_f64,
+        c: 103.0927742186_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1165_f64,
+        b: 4.60942128971_f64,
+        c: 639.897286314_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1082_f64,
+        b: 5.6913035167_f64,
+        c: 433.7117378768_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1020_f64,
+        b: 0.63369182642_f64,
+        c: 3.1813937377_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1045_f64,
+        b: 4.04206453611_f64,
+        c: 199.0720014364_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 634_f64,
+        b: 4.38825410036_f64,
+        c: 419.4846438752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 549_f64,
+        b: 5.57303134242_f64,
+        c: 3.9321532631_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 457_f64,
+        b: 1.26840971349_f64,
+        c: 110.2063212194_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 425_f64,
+        b: 0.20935499279_f64,
+        c: 227.5261894396_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 274_f64,
+        b: 4.28841011784_f64,
+        c: 95.9792272178_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 162_f64,
+        b: 1.3813914942_f64,
+        c: 11.0457002639_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 129_f64,
+        b: 1.5658688417_f64,
+        c: 309.2783226558_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 117_f64,
+        b: 3.88120915956_f64,
+        c: 853.196381752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 105_f64,
+        b: 4.90003203599_f64,
+        c: 647.0108333148_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 101_f64,
+        b: 0.892704931_f64,
+        c: 21.3406410024_f64,
+    },
+];
+
+const L3: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 16039_f64,
+        b: 5.73945377424_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4250_f64,
+        b: 4.58539675603_f64,
+        c: 213.299095438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1907_f64,
+        b: 4.76082050205_f64,
+        c: 220.4126424388_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1466_f64,
+        b: 5.91326678323_f64,
+        c: 206.1855484372_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1162_f64,
+        b: 5.61973132428_f64,
+        c: 14.2270940016_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1067_f64,
+        b: 3.60816533142_f64,
+        c: 426.598190876_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 239_f64,
+        b: 3.86088273439_f64,
+        c: 433.7117378768_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 237_f64,
+        b: 5.76826451465_f64,
+        c: 199.0720014364_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 166_f64,
+        b: 5.11641150216_f64,
+        c: 3.1813937377_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 131_f64,
+        b: 4.74327544615_f64,
+        c: 227.5261894396_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 151_f64,
+        b: 2.73594641861_f64,
+        c: 639.897286314_f64,
+    },
+];
+
+const L4: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 1662_f64,
+        b: 3.99826248978_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 257_f64,
+        b: 2.98436499013_f64,
+        c: 220.4126424388_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 236_f64,
+        b: 3.90241428075_f64,
+        c: 14.2270940016_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 149_f64,
+        b: 2.74110824208_f64,
+        c: 213.299095438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 110_f64,
+        b: 1.51515739251_f64,
+        c: 206.1855484372_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 114_f64,
+        b: 3.14159265359_f64,
+        c: 0_f64,
+    },
+];
+
+const L5: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 124_f64,
+        b: 2.25923345732_f64,
+        c: 7.1135470008_f64,
+    },
+];
+
+const B0: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 4330678_f64,
+        b: 3.60284428399_f64,
+        c: 213.299095438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 240348_f64,
+        b: 2.8523848939_f64,
+        c: 426.598190876_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 84746_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 30863_f64,
+        b: 3.48441504465_f64,
+        c: 220.4126424388_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 34116_f64,
+        b: 0.57297307844_f64,
+        c: 206.1855484372_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 14734_f64,
+        b: 2.1184659787_f64,
+        c: 639.897286314_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 9917_f64,
+        b: 5.79003189405_f64,
+        c: 419.4846438752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 6994_f64,
+        b: 4.73604689179_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4808_f64,
+        b: 5.43305315602_f64,
+        c: 316.3918696566_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4788_f64,
+        b: 4.9651292742_f64,
+        c: 110.2063212194_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3432_f64,
+        b: 2.73255752123_f64,
+        c: 433.7117378768_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1506_f64,
+        b: 6.01304536144_f64,
+        c: 103.0927742186_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1060_f64,
+        b: 5.63099292414_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 969_f64,
+        b: 5.20434966103_f64,
+        c: 632.7837393132_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 942_f64,
+        b: 1.39646678088_f64,
+        c: 853.196381752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 708_f64,
+        b: 3.80302329547_f64,
+        c: 323.5054166574_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 552_f64,
+        b: 5.13149109045_f64,
+        c: 202.2533951741_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 400_f64,
+        b: 3.35891413961_f64,
+        c: 227.5261894396_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 316_f64,
+        b: 1.99716764199_f64,
+        c: 647.0108333148_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 319_f64,
+        b: 3.6257155098_f64,
+        c: 209.3669421749_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 284_f64,
+        b: 4.88648481625_f64,
+        c: 224.3447957019_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 314_f64,
+        b: 0.4651027241_f64,
+        c: 217.2312487011_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 236_f64,
+        b: 2.13887472281_f64,
+        c: 11.0457002639_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 215_f64,
+        b: 5.94982610103_f64,
+        c: 846.0828347512_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 209_f64,
+        b: 2.12003893769_f64,
+        c: 415.5524906121_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 179_f64,
+        b: 2.95361514672_f64,
+        c: 63.7358983034_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 207_f64,
+        b: 0.73021462851_f64,
+        c: 199.0720014364_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 139_f64,
+        b: 1.9982199094_f64,
+        c: 735.8765135318_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 135_f64,
+        b: 5.24500819605_f64,
+        c: 742.9900605326_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 141_f64,
+        b: 0.64417620299_f64,
+        c: 490.3340891794_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 122_f64,
+        b: 3.11537140876_f64,
+        c: 522.5774180938_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 139_f64,
+        b: 4.59535168021_f64,
+        c: 14.2270940016_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 116_f64,
+        b: 3.10891547171_f64,
+        c: 216.4804891757_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 114_f64,
+        b: 0.96261442133_f64,
+        c: 210.1177017003_f64,
+    },
+];
+
+const B1: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 397555_f64,
+        b: 5.33289992556_f64,
+        c: 213.299095438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 49479_f64,
+        b: 3.14159265359_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 18572_f64,
+        b: 6.09919206378_f64,
+        c: 426.598190876_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 14801_f64,
+        b: 2.3058606052_f64,
+        c: 206.1855484372_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 9644_f64,
+        b: 1.6967466012_f64,
+        c: 220.4126424388_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3757_f64,
+        b: 1.25429514018_f64,
+        c: 419.4846438752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2717_f64,
+        b: 5.91166664787_f64,
+        c: 639.897286314_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1455_f64,
+        b: 0.85161616532_f64,
+        c: 433.7117378768_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1291_f64,
+        b: 2.9177085709_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 853_f64,
+        b: 0.43572078997_f64,
+        c: 316.3918696566_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 284_f64,
+        b: 1.61881754773_f64,
+        c: 227.5261894396_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 292_f64,
+        b: 5.3157425127_f64,
+        c: 853.196381752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 275_f64,
+        b: 3.88864137336_f64,
+        c: 103.0927742186_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 298_f64,
+        b: 0.91909206723_f64,
+        c: 632.7837393132_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 172_f64,
+        b: 0.05215146556_f64,
+        c: 647.0108333148_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 128_f64,
+        b: 1.20711452525_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 166_f64,
+        b: 2.44351613165_f64,
+        c: 199.0720014364_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 158_f64,
+        b: 5.20850125766_f64,
+        c: 110.2063212194_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 110_f64,
+        b: 2.45695551627_f64,
+        c: 217.2312487011_f64,
+    },
+];
+
+const B2: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 20630_f64,
+        b: 0.50482422817_f64,
+        c: 213.299095438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3720_f64,
+        b: 3.99833475829_f64,
+        c: 206.1855484372_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1627_f64,
+        b: 6.181899395_f64,
+        c: 220.4126424388_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1346_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 706_f64,
+        b: 3.03914308836_f64,
+        c: 419.4846438752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 365_f64,
+        b: 5.09928680706_f64,
+        c: 426.598190876_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 330_f64,
+        b: 5.27899210039_f64,
+        c: 433.7117378768_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 219_f64,
+        b: 3.82841533795_f64,
+        c: 639.897286314_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 139_f64,
+        b: 1.04272623499_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 104_f64,
+        b: 6.15730992966_f64,
+        c: 227.5261894396_f64,
+    },
+];
+
+const B3: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 666_f64,
+        b: 1.99006340181_f64,
+        c: 213.299095438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 632_f64,
+        b: 5.69778316807_f64,
+        c: 206.1855484372_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 398_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 188_f64,
+        b: 4.33779804809_f64,
+        c: 220.4126424388_f64,
+    },
+];
+
+const R0: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 955758136_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 52921382_f64,
+        b: 2.39226219733_f64,
+        c: 213.299095438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1873680_f64,
+        b: 5.23549605091_f64,
+        c: 206.1855484372_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1464664_f64,
+        b: 1.64763045468_f64,
+        c: 426.598190876_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 821891_f64,
+        b: 5.93520025371_f64,
+        c: 316.3918696566_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 547507_f64,
+        b: 5.01532628454_f64,
+        c: 103.0927742186_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 371684_f64,
+        b: 2.27114833428_f64,
+        c: 220.4126424388_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 361778_f64,
+        b: 3.13904303264_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 140618_f64,
+        b: 5.70406652991_f64,
+        c: 632.7837393132_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 108975_f64,
+        b: 3.29313595577_f64,
+        c: 110.2063212194_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 69007_f64,
+        b: 5.94099622447_f64,
+        c: 419.4846438752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 61053_f64,
+        b: 0.94037761156_f64,
+        c: 639.897286314_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 48913_f64,
+        b: 1.55733388472_f64,
+        c: 202.2533951741_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 34144_f64,
+        b: 0.19518550682_f64,
+        c: 277.0349937414_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 32402_f64,
+        b: 5.47084606947_f64,
+        c: 949.1756089698_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 20937_f64,
+        b: 0.46349163993_f64,
+        c: 735.8765135318_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 20839_f64,
+        b: 1.5210259064_f64,
+        c: 433.7117378768_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 20747_f64,
+        b: 5.33255667599_f64,
+        c: 199.0720014364_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 15298_f64,
+        b: 3.05943652881_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 14296_f64,
+        b: 2.60433537909_f64,
+        c: 323.5054166574_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 11993_f64,
+        b: 5.98051421881_f64,
+        c: 846.0828347512_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 11380_f64,
+        b: 1.73105746566_f64,
+        c: 522.5774180938_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 12884_f64,
+        b: 1.64892310393_f64,
+        c: 138.5174968707_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 7753_f64,
+        b: 5.85191318903_f64,
+        c: 95.9792272178_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 9796_f64,
+        b: 5.20475863996_f64,
+        c: 1265.5674786264_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 6466_f64,
+        b: 0.17733160145_f64,
+        c: 1052.2683831884_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 6771_f64,
+        b: 3.00433479284_f64,
+        c: 14.2270940016_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 5850_f64,
+        b: 1.45519636076_f64,
+        c: 415.5524906121_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 5307_f64,
+        b: 0.5973753405_f64,
+        c: 63.7358983034_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4696_f64,
+        b: 2.14919036956_f64,
+        c: 227.5261894396_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4044_f64,
+        b: 1.64010323863_f64,
+        c: 209.3669421749_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3688_f64,
+        b: 0.7801613317_f64,
+        c: 412.3710968744_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3376_f64,
+        b: 3.69528478828_f64,
+        c: 224.3447957019_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2885_f64,
+        b: 1.38764077631_f64,
+        c: 838.9692877504_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2976_f64,
+        b: 5.68467931117_f64,
+        c: 210.1177017003_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3420_f64,
+        b: 4.94549148887_f64,
+        c: 1581.959348283_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3461_f64,
+        b: 1.85088802878_f64,
+        c: 175.1660598002_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3401_f64,
+        b: 0.55386747515_f64,
+        c: 350.3321196004_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2508_f64,
+        b: 3.53851863255_f64,
+        c: 742.9900605326_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2448_f64,
+        b: 6.18412386316_f64,
+        c: 1368.660252845_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2406_f64,
+        b: 2.96559220267_f64,
+        c: 117.3198682202_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2881_f64,
+        b: 0.17960757891_f64,
+        c: 853.196381752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2174_f64,
+        b: 0.01508587396_f64,
+        c: 340.7708920448_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2024_f64,
+        b: 5.05411271271_f64,
+        c: 11.0457002639_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1740_f64,
+        b: 2.34657043464_f64,
+        c: 309.2783226558_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1861_f64,
+        b: 5.93361638244_f64,
+        c: 625.6701923124_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1888_f64,
+        b: 0.02968443389_f64,
+        c: 3.9321532631_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1611_f64,
+        b: 1.17302463549_f64,
+        c: 74.7815985673_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1463_f64,
+        b: 1.92588134017_f64,
+        c: 216.4804891757_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1475_f64,
+        b: 5.6767046113_f64,
+        c: 203.7378678824_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1395_f64,
+        b: 5.93669404929_f64,
+        c: 127.4717966068_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1781_f64,
+        b: 0.76314388077_f64,
+        c: 217.2312487011_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1817_f64,
+        b: 5.77713225779_f64,
+        c: 490.3340891794_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1472_f64,
+        b: 1.40064915651_f64,
+        c: 137.0330241624_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1304_f64,
+        b: 0.77235613966_f64,
+        c: 647.0108333148_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1150_f64,
+        b: 5.74021249703_f64,
+        c: 1162.4747044078_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1127_f64,
+        b: 4.46707803791_f64,
+        c: 265.9892934775_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1277_f64,
+        b: 2.98412586423_f64,
+        c: 1059.3819301892_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1207_f64,
+        b: 0.7528593316_f64,
+        c: 351.8165923087_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1071_f64,
+        b: 1.13567265104_f64,
+        c: 1155.361157407_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1021_f64,
+        b: 5.91233512844_f64,
+        c: 1685.0521225016_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1315_f64,
+        b: 5.11202572637_f64,
+        c: 211.8146227297_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1296_f64,
+        b: 4.69184139933_f64,
+        c: 1898.3512179396_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1099_f64,
+        b: 1.81765118601_f64,
+        c: 149.5631971346_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 998_f64,
+        b: 2.63131596867_f64,
+        c: 200.7689224658_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 986_f64,
+        b: 2.25992849742_f64,
+        c: 956.2891559706_f64,
+    },
+];
+
+const R1: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 6182981_f64,
+        b: 0.25843515034_f64,
+        c: 213.299095438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 506578_f64,
+        b: 0.71114650941_f64,
+        c: 206.1855484372_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 341394_f64,
+        b: 5.7963577396_f64,
+        c: 426.598190876_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 188491_f64,
+        b: 0.47215719444_f64,
+        c: 220.4126424388_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 186262_f64,
+        b: 3.14159265359_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 143891_f64,
+        b: 1.40744864239_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 49621_f64,
+        b: 6.0174446958_f64,
+        c: 103.0927742186_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 20928_f64,
+        b: 5.0924565447_f64,
+        c: 639.897286314_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 19953_f64,
+        b: 1.17560125007_f64,
+        c: 419.4846438752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 18840_f64,
+        b: 1.60819563173_f64,
+        c: 110.2063212194_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 12893_f64,
+        b: 5.94330258435_f64,
+        c: 433.7117378768_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 13877_f64,
+        b: 0.75886204364_f64,
+        c: 199.0720014364_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 5397_f64,
+        b: 1.28852405908_f64,
+        c: 14.2270940016_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4869_f64,
+        b: 0.86793894213_f64,
+        c: 323.5054166574_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4247_f64,
+        b: 0.39299384543_f64,
+        c: 227.5261894396_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3252_f64,
+        b: 1.25853470491_f64,
+        c: 95.9792272178_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2856_f64,
+        b: 2.16731405366_f64,
+        c: 735.8765135318_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2909_f64,
+        b: 4.60679154788_f64,
+        c: 202.2533951741_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3081_f64,
+        b: 3.43662557418_f64,
+        c: 522.5774180938_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1988_f64,
+        b: 2.45054204795_f64,
+        c: 412.3710968744_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1941_f64,
+        b: 6.02393385142_f64,
+        c: 209.3669421749_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1581_f64,
+        b: 1.29191789712_f64,
+        c: 210.1177017003_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1340_f64,
+        b: 4.30801821806_f64,
+        c: 853.196381752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1316_f64,
+        b: 1.25296446023_f64,
+        c: 117.3198682202_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1203_f64,
+        b: 1.86654673794_f64,
+        c: 316.3918696566_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1091_f64,
+        b: 0.07527246854_f64,
+        c: 216.4804891757_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 954_f64,
+        b: 5.15173410519_f64,
+        c: 647.0108333148_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 966_f64,
+        b: 0.47991379141_f64,
+        c: 632.7837393132_f64,
+    },
+];
+
+const R2: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 436902_f64,
+        b: 4.78671673044_f64,
+        c: 213.299095438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 71923_f64,
+        b: 2.50069994874_f64,
+        c: 206.1855484372_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 49767_f64,
+        b: 4.9716815087_f64,
+        c: 220.4126424388_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 43221_f64,
+        b: 3.86940443794_f64,
+        c: 426.598190876_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 29646_f64,
+        b: 5.96310264282_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4142_f64,
+        b: 4.10670940823_f64,
+        c: 433.7117378768_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4721_f64,
+        b: 2.47527992423_f64,
+        c: 199.0720014364_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3789_f64,
+        b: 3.09771025067_f64,
+        c: 639.897286314_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2964_f64,
+        b: 1.37206248846_f64,
+        c: 103.0927742186_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2556_f64,
+        b: 2.85065721526_f64,
+        c: 419.4846438752_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2208_f64,
+        b: 6.27588858707_f64,
+        c: 110.2063212194_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2188_f64,
+        b: 5.85545832218_f64,
+        c: 14.2270940016_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1957_f64,
+        b: 4.92448618045_f64,
+        c: 227.5261894396_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2327_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+];
+
+const R3: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 20315_f64,
+        b: 3.02186626038_f64,
+        c: 213.299095438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 8924_f64,
+        b: 3.19144205755_f64,
+        c: 220.4126424388_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 6909_f64,
+        b: 4.35174889353_f64,
+        c: 206.1855484372_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4087_f64,
+        b: 4.22406927376_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3879_f64,
+        b: 2.01056445995_f64,
+        c: 426.598190876_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1071_f64,
+        b: 4.20360341236_f64,
+        c: 199.0720014364_f64,
+    },
+];
+
+const R4: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 1202_f64,
+        b: 1.41499446465_f64,
+        c: 220.4126424388_f64,
+    },
+];
+
+/// The periodic terms for the heliocentric coordinates of Saturn, from
+/// VSOP87D with terms smaller than 1e-6 radians (or 1e-6 of the mean
+/// distance, for the radius vector) omitted
+pub const SATURN_TERMS: PlanetPeriodicTerms = PlanetPeriodicTerms {
+    longitude: &[L0, L1, L2, L3, L4, L5],
+    latitude: &[B0, B1, B2, B3],
+    radius: &[R0, R1, R2, R3, R4],
+};
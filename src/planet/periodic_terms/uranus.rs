@@ -0,0 +1,1338 @@
+#![allow(clippy::unreadable_literal, clippy::approx_constant)]
+
+use planet::periodic_terms::{PlanetPeriodicTableRow, PlanetPeriodicTerms};
+
+const L0: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 548129294_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 9260408_f64,
+        b: 0.8910642153_f64,
+        c: 74.7815985673_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1504248_f64,
+        b: 3.62719262195_f64,
+        c: 1.4844727083_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 365982_f64,
+        b: 1.89962189068_f64,
+        c: 73.297125859_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 272328_f64,
+        b: 3.35823710524_f64,
+        c: 149.5631971346_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 70328_f64,
+        b: 5.39254431993_f64,
+        c: 63.7358983034_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 68893_f64,
+        b: 6.09292489045_f64,
+        c: 76.2660712756_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 61999_f64,
+        b: 2.26952040469_f64,
+        c: 2.9689454166_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 61951_f64,
+        b: 2.85098907565_f64,
+        c: 11.0457002639_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 26469_f64,
+        b: 3.14152087888_f64,
+        c: 71.8126531507_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 25711_f64,
+        b: 6.11379842935_f64,
+        c: 454.9093665273_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 21079_f64,
+        b: 4.36059465144_f64,
+        c: 148.0787244263_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 17819_f64,
+        b: 1.74436982544_f64,
+        c: 36.6485629295_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 14613_f64,
+        b: 4.73732047977_f64,
+        c: 3.9321532631_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 11163_f64,
+        b: 5.82681993692_f64,
+        c: 224.3447957019_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 10998_f64,
+        b: 0.48865493179_f64,
+        c: 138.5174968707_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 9527_f64,
+        b: 2.95516893093_f64,
+        c: 35.1640902212_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 7546_f64,
+        b: 5.23626440666_f64,
+        c: 109.9456887885_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4220_f64,
+        b: 3.23328535514_f64,
+        c: 70.8494453042_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4052_f64,
+        b: 2.27754158724_f64,
+        c: 151.0476698429_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3355_f64,
+        b: 1.06549008887_f64,
+        c: 4.4534181249_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2927_f64,
+        b: 4.62903695486_f64,
+        c: 9.5612275556_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3490_f64,
+        b: 5.48305567292_f64,
+        c: 146.594251718_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3144_f64,
+        b: 4.75199307603_f64,
+        c: 77.7505439839_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2922_f64,
+        b: 5.3523674338_f64,
+        c: 85.8272988312_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2273_f64,
+        b: 4.36600802756_f64,
+        c: 70.3281804424_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2051_f64,
+        b: 1.51773563459_f64,
+        c: 0.1118745846_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2149_f64,
+        b: 0.60745800902_f64,
+        c: 38.1330356378_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1992_f64,
+        b: 4.92437290826_f64,
+        c: 277.0349937414_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1376_f64,
+        b: 2.04281409054_f64,
+        c: 65.2203710117_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1667_f64,
+        b: 3.62744580852_f64,
+        c: 380.12776796_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1284_f64,
+        b: 3.11346336879_f64,
+        c: 202.2533951741_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1150_f64,
+        b: 0.93344454002_f64,
+        c: 3.1813937377_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1533_f64,
+        b: 2.58593414266_f64,
+        c: 52.6901980395_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1282_f64,
+        b: 0.54269869505_f64,
+        c: 222.8603229936_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1372_f64,
+        b: 4.19641615561_f64,
+        c: 111.4301614968_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1221_f64,
+        b: 0.19901396193_f64,
+        c: 108.4612160802_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 946_f64,
+        b: 1.19249463066_f64,
+        c: 127.4717966068_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1151_f64,
+        b: 4.17898207045_f64,
+        c: 33.6796175129_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1244_f64,
+        b: 0.91612680579_f64,
+        c: 2.4476805548_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1072_f64,
+        b: 0.23564502877_f64,
+        c: 62.2514255951_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1090_f64,
+        b: 1.77501638912_f64,
+        c: 12.5301729722_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 708_f64,
+        b: 5.18285226584_f64,
+        c: 213.299095438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 653_f64,
+        b: 0.96586909116_f64,
+        c: 78.7137518304_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 628_f64,
+        b: 0.18210181975_f64,
+        c: 984.6003316219_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 524_f64,
+        b: 2.01276706996_f64,
+        c: 299.1263942692_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 559_f64,
+        b: 3.35776737704_f64,
+        c: 0.5212648618_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 607_f64,
+        b: 5.43209728952_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 405_f64,
+        b: 5.98689011389_f64,
+        c: 8.0767548473_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 467_f64,
+        b: 0.41484068933_f64,
+        c: 145.1097790097_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 471_f64,
+        b: 1.40664336447_f64,
+        c: 184.7272873558_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 483_f64,
+        b: 2.10553990154_f64,
+        c: 0.9632078465_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 396_f64,
+        b: 5.87039580949_f64,
+        c: 351.8165923087_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 434_f64,
+        b: 5.52142978255_f64,
+        c: 183.2428146475_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 310_f64,
+        b: 5.83301304674_f64,
+        c: 145.6310438715_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 379_f64,
+        b: 2.34975805006_f64,
+        c: 56.6223513026_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 399_f64,
+        b: 0.33810765436_f64,
+        c: 415.5524906121_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 300_f64,
+        b: 5.64353974146_f64,
+        c: 22.0914005278_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 249_f64,
+        b: 4.74617120584_f64,
+        c: 225.8292684102_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 239_f64,
+        b: 2.35045874708_f64,
+        c: 137.0330241624_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 294_f64,
+        b: 5.83916826225_f64,
+        c: 39.6175083461_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 216_f64,
+        b: 4.77847481363_f64,
+        c: 340.7708920448_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 252_f64,
+        b: 1.63696775578_f64,
+        c: 221.3758502853_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 220_f64,
+        b: 1.92212987979_f64,
+        c: 67.6680515665_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 202_f64,
+        b: 1.29693040865_f64,
+        c: 0.0481841098_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 224_f64,
+        b: 0.51574863468_f64,
+        c: 84.3428261229_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 217_f64,
+        b: 6.14211862702_f64,
+        c: 5.9378908332_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 223_f64,
+        b: 2.84309380331_f64,
+        c: 0.2606324309_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 208_f64,
+        b: 5.5802057004_f64,
+        c: 68.8437077341_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 187_f64,
+        b: 1.31924326253_f64,
+        c: 0.1600586944_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 158_f64,
+        b: 0.73811997211_f64,
+        c: 54.1746707478_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 199_f64,
+        b: 0.9563415501_f64,
+        c: 152.5321425512_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 169_f64,
+        b: 5.87874000882_f64,
+        c: 18.1592472647_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 170_f64,
+        b: 3.67717520688_f64,
+        c: 5.4166259714_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 194_f64,
+        b: 1.88800122606_f64,
+        c: 456.3938392356_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 193_f64,
+        b: 0.91616058506_f64,
+        c: 453.424893819_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 182_f64,
+        b: 3.53624029238_f64,
+        c: 79.2350166922_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 173_f64,
+        b: 1.53860728054_f64,
+        c: 160.6088973985_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 165_f64,
+        b: 1.42379714838_f64,
+        c: 106.9767433719_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 172_f64,
+        b: 5.67952685533_f64,
+        c: 219.891377577_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 163_f64,
+        b: 3.05029377666_f64,
+        c: 112.9146342051_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 147_f64,
+        b: 1.26300172265_f64,
+        c: 59.8037450403_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 139_f64,
+        b: 5.385977234_f64,
+        c: 32.1951448046_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 139_f64,
+        b: 4.25994786673_f64,
+        c: 909.8187330546_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 143_f64,
+        b: 1.29995487555_f64,
+        c: 35.4247226521_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 124_f64,
+        b: 1.37359990336_f64,
+        c: 7.1135470008_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 104_f64,
+        b: 5.02820888813_f64,
+        c: 0.7507595254_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 103_f64,
+        b: 0.68095301267_f64,
+        c: 14.977853527_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 110_f64,
+        b: 2.02685778976_f64,
+        c: 554.0699874828_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 109_f64,
+        b: 5.70581833286_f64,
+        c: 77.962992305_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 104_f64,
+        b: 1.45770270246_f64,
+        c: 24.3790223882_f64,
+    },
+];
+
+const L1: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 7502543122_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 154458_f64,
+        b: 5.24201658072_f64,
+        c: 74.7815985673_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 24456_f64,
+        b: 1.71255705309_f64,
+        c: 1.4844727083_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 9258_f64,
+        b: 0.42844639064_f64,
+        c: 11.0457002639_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 8266_f64,
+        b: 1.5022003511_f64,
+        c: 63.7358983034_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 7842_f64,
+        b: 1.31983607251_f64,
+        c: 149.5631971346_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3899_f64,
+        b: 0.46483574024_f64,
+        c: 3.9321532631_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2284_f64,
+        b: 4.17367533997_f64,
+        c: 76.2660712756_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1927_f64,
+        b: 0.53013080152_f64,
+        c: 2.9689454166_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1233_f64,
+        b: 1.58634458237_f64,
+        c: 70.8494453042_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 791_f64,
+        b: 5.43641224143_f64,
+        c: 3.1813937377_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 767_f64,
+        b: 1.99555409575_f64,
+        c: 73.297125859_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 482_f64,
+        b: 2.98401996914_f64,
+        c: 85.8272988312_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 450_f64,
+        b: 4.13826237508_f64,
+        c: 138.5174968707_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 446_f64,
+        b: 3.72300400331_f64,
+        c: 224.3447957019_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 427_f64,
+        b: 4.73126059388_f64,
+        c: 71.8126531507_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 348_f64,
+        b: 2.45372261286_f64,
+        c: 9.5612275556_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 354_f64,
+        b: 2.58324496886_f64,
+        c: 148.0787244263_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 317_f64,
+        b: 5.57855232072_f64,
+        c: 52.6901980395_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 180_f64,
+        b: 5.68367730922_f64,
+        c: 12.5301729722_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 171_f64,
+        b: 3.00060075287_f64,
+        c: 78.7137518304_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 206_f64,
+        b: 2.36263144251_f64,
+        c: 2.4476805548_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 158_f64,
+        b: 2.90931969498_f64,
+        c: 0.9632078465_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 189_f64,
+        b: 4.20242881378_f64,
+        c: 56.6223513026_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 155_f64,
+        b: 5.59083925605_f64,
+        c: 4.4534181249_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 184_f64,
+        b: 0.28371004654_f64,
+        c: 151.0476698429_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 143_f64,
+        b: 2.59049246726_f64,
+        c: 62.2514255951_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 152_f64,
+        b: 2.9421732689_f64,
+        c: 77.7505439839_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 154_f64,
+        b: 4.65186885939_f64,
+        c: 35.1640902212_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 121_f64,
+        b: 4.1483920492_f64,
+        c: 127.4717966068_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 116_f64,
+        b: 3.73224603791_f64,
+        c: 65.2203710117_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 102_f64,
+        b: 4.18754517993_f64,
+        c: 145.6310438715_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 102_f64,
+        b: 6.03385875009_f64,
+        c: 0.1118745846_f64,
+    },
+];
+
+const L2: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 53033_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2358_f64,
+        b: 2.26014661705_f64,
+        c: 74.7815985673_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 769_f64,
+        b: 4.52561041823_f64,
+        c: 11.0457002639_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 552_f64,
+        b: 3.25814281023_f64,
+        c: 63.7358983034_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 542_f64,
+        b: 2.27573907424_f64,
+        c: 3.9321532631_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 529_f64,
+        b: 4.92348433826_f64,
+        c: 1.4844727083_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 258_f64,
+        b: 3.69059216858_f64,
+        c: 3.1813937377_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 239_f64,
+        b: 5.85806638405_f64,
+        c: 149.5631971346_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 182_f64,
+        b: 6.21763603405_f64,
+        c: 70.8494453042_f64,
+    },
+];
+
+const L3: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 121_f64,
+        b: 0.02418789918_f64,
+        c: 74.7815985673_f64,
+    },
+];
+
+const L4: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 114_f64,
+        b: 3.14159265359_f64,
+        c: 0_f64,
+    },
+];
+
+const B0: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 1346278_f64,
+        b: 2.61877810545_f64,
+        c: 74.7815985673_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 62341_f64,
+        b: 5.08111175856_f64,
+        c: 149.5631971346_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 61601_f64,
+        b: 3.14159265359_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 9964_f64,
+        b: 1.61603876357_f64,
+        c: 76.2660712756_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 9926_f64,
+        b: 0.57630387917_f64,
+        c: 73.297125859_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3259_f64,
+        b: 1.2611938596_f64,
+        c: 224.3447957019_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2972_f64,
+        b: 2.24367035538_f64,
+        c: 1.4844727083_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2010_f64,
+        b: 6.05550401088_f64,
+        c: 148.0787244263_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1522_f64,
+        b: 0.27960386377_f64,
+        c: 63.7358983034_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 924_f64,
+        b: 4.03822927853_f64,
+        c: 151.0476698429_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 761_f64,
+        b: 6.14000431923_f64,
+        c: 71.8126531507_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 420_f64,
+        b: 5.21279984788_f64,
+        c: 11.0457002639_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 431_f64,
+        b: 3.55445034854_f64,
+        c: 213.299095438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 437_f64,
+        b: 3.38082524317_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 522_f64,
+        b: 3.3208519477_f64,
+        c: 138.5174968707_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 435_f64,
+        b: 0.34065281858_f64,
+        c: 77.7505439839_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 463_f64,
+        b: 0.74256727574_f64,
+        c: 85.8272988312_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 233_f64,
+        b: 2.25716421383_f64,
+        c: 222.8603229936_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 216_f64,
+        b: 1.5912170494_f64,
+        c: 38.1330356378_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 245_f64,
+        b: 0.78795150326_f64,
+        c: 2.9689454166_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 180_f64,
+        b: 3.72487952673_f64,
+        c: 299.1263942692_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 175_f64,
+        b: 1.23550262213_f64,
+        c: 146.594251718_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 174_f64,
+        b: 1.93654269131_f64,
+        c: 380.12776796_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 160_f64,
+        b: 5.33635436463_f64,
+        c: 111.4301614968_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 144_f64,
+        b: 5.96239326415_f64,
+        c: 35.1640902212_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 102_f64,
+        b: 2.61876256513_f64,
+        c: 78.7137518304_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 116_f64,
+        b: 5.73877190007_f64,
+        c: 70.8494453042_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 106_f64,
+        b: 0.94103112994_f64,
+        c: 70.3281804424_f64,
+    },
+];
+
+const B1: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 206366_f64,
+        b: 4.12394311407_f64,
+        c: 74.7815985673_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 8563_f64,
+        b: 0.33819986165_f64,
+        c: 149.5631971346_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1726_f64,
+        b: 2.12193159895_f64,
+        c: 73.297125859_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1369_f64,
+        b: 3.06861722047_f64,
+        c: 76.2660712756_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1374_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 400_f64,
+        b: 2.84767037795_f64,
+        c: 224.3447957019_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 451_f64,
+        b: 3.77656180977_f64,
+        c: 1.4844727083_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 307_f64,
+        b: 1.25456766737_f64,
+        c: 148.0787244263_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 154_f64,
+        b: 3.78575467747_f64,
+        c: 63.7358983034_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 111_f64,
+        b: 5.32888676461_f64,
+        c: 138.5174968707_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 112_f64,
+        b: 5.57299891505_f64,
+        c: 151.0476698429_f64,
+    },
+];
+
+const B2: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 9212_f64,
+        b: 5.80044305785_f64,
+        c: 74.7815985673_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 557_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 286_f64,
+        b: 2.17729776353_f64,
+        c: 149.5631971346_f64,
+    },
+];
+
+const B3: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 268_f64,
+        b: 1.25097888291_f64,
+        c: 74.7815985673_f64,
+    },
+];
+
+const R0: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 1921264848_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 88784984_f64,
+        b: 5.60377526994_f64,
+        c: 74.7815985673_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3440836_f64,
+        b: 0.32836098991_f64,
+        c: 73.297125859_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2055653_f64,
+        b: 1.78295170028_f64,
+        c: 149.5631971346_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 649322_f64,
+        b: 4.52247298119_f64,
+        c: 76.2660712756_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 602248_f64,
+        b: 3.86003820462_f64,
+        c: 63.7358983034_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 496404_f64,
+        b: 1.40139934716_f64,
+        c: 454.9093665273_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 338526_f64,
+        b: 1.58002682946_f64,
+        c: 138.5174968707_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 243508_f64,
+        b: 1.57086595074_f64,
+        c: 71.8126531507_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 190522_f64,
+        b: 1.99809364502_f64,
+        c: 1.4844727083_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 161858_f64,
+        b: 2.79137863469_f64,
+        c: 148.0787244263_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 143706_f64,
+        b: 1.38368574483_f64,
+        c: 11.0457002639_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 93192_f64,
+        b: 0.17437193645_f64,
+        c: 36.6485629295_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 71424_f64,
+        b: 4.24509327405_f64,
+        c: 224.3447957019_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 89806_f64,
+        b: 3.66105366329_f64,
+        c: 109.9456887885_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 39010_f64,
+        b: 1.66971128869_f64,
+        c: 70.8494453042_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 46677_f64,
+        b: 1.39976563936_f64,
+        c: 35.1640902212_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 39026_f64,
+        b: 3.36234710692_f64,
+        c: 277.0349937414_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 36755_f64,
+        b: 3.88648934736_f64,
+        c: 146.594251718_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 30349_f64,
+        b: 0.70100446346_f64,
+        c: 151.0476698429_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 29156_f64,
+        b: 3.18056174556_f64,
+        c: 77.7505439839_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 20472_f64,
+        b: 1.555889615_f64,
+        c: 202.2533951741_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 25620_f64,
+        b: 5.25656292802_f64,
+        c: 380.12776796_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 25786_f64,
+        b: 3.78537741503_f64,
+        c: 85.8272988312_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 22637_f64,
+        b: 0.72519137745_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 20473_f64,
+        b: 2.79639811626_f64,
+        c: 70.3281804424_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 17901_f64,
+        b: 0.55455488605_f64,
+        c: 2.9689454166_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 12328_f64,
+        b: 5.96039150918_f64,
+        c: 127.4717966068_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 14702_f64,
+        b: 4.90434406648_f64,
+        c: 108.4612160802_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 11495_f64,
+        b: 0.43774027872_f64,
+        c: 65.2203710117_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 15503_f64,
+        b: 5.35405037603_f64,
+        c: 38.1330356378_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 10793_f64,
+        b: 1.42104858472_f64,
+        c: 213.299095438_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 11696_f64,
+        b: 3.29825599114_f64,
+        c: 3.9321532631_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 11959_f64,
+        b: 1.75044072173_f64,
+        c: 984.6003316219_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 12897_f64,
+        b: 2.62154018241_f64,
+        c: 111.4301614968_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 11853_f64,
+        b: 0.99342814582_f64,
+        c: 52.6901980395_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 9111_f64,
+        b: 4.99638600045_f64,
+        c: 62.2514255951_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 8421_f64,
+        b: 5.25350716616_f64,
+        c: 222.8603229936_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 7449_f64,
+        b: 0.79491905956_f64,
+        c: 351.8165923087_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 8402_f64,
+        b: 5.03877516489_f64,
+        c: 415.5524906121_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 6046_f64,
+        b: 5.67960948357_f64,
+        c: 78.7137518304_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 5524_f64,
+        b: 3.11499484161_f64,
+        c: 9.5612275556_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 7329_f64,
+        b: 3.9727752784_f64,
+        c: 183.2428146475_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 5445_f64,
+        b: 5.10575635361_f64,
+        c: 145.1097790097_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 5238_f64,
+        b: 2.62960141797_f64,
+        c: 33.6796175129_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4079_f64,
+        b: 3.22064788674_f64,
+        c: 340.7708920448_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3802_f64,
+        b: 6.10985558505_f64,
+        c: 184.7272873558_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3919_f64,
+        b: 4.25015288873_f64,
+        c: 39.6175083461_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2940_f64,
+        b: 2.14637460319_f64,
+        c: 137.0330241624_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3781_f64,
+        b: 3.45840272873_f64,
+        c: 456.3938392356_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2942_f64,
+        b: 0.42393808854_f64,
+        c: 299.1263942692_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3687_f64,
+        b: 2.48718116535_f64,
+        c: 453.424893819_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3102_f64,
+        b: 4.14031063896_f64,
+        c: 219.891377577_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2963_f64,
+        b: 0.82977991995_f64,
+        c: 56.6223513026_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2938_f64,
+        b: 3.6765745093_f64,
+        c: 140.001969579_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2865_f64,
+        b: 0.30996903761_f64,
+        c: 12.5301729722_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2538_f64,
+        b: 4.85457831993_f64,
+        c: 131.4039498699_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1963_f64,
+        b: 5.24342224065_f64,
+        c: 84.3428261229_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2364_f64,
+        b: 0.44253328372_f64,
+        c: 554.0699874828_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1979_f64,
+        b: 6.12836181686_f64,
+        c: 106.9767433719_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2183_f64,
+        b: 2.94040431638_f64,
+        c: 305.3461693927_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1963_f64,
+        b: 0.0411473912_f64,
+        c: 221.3758502853_f64,
+    },
+];
+
+const R1: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 1479896_f64,
+        b: 3.67205705317_f64,
+        c: 74.7815985673_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 71212_f64,
+        b: 6.22601006675_f64,
+        c: 63.7358983034_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 68627_f64,
+        b: 6.13411265052_f64,
+        c: 149.5631971346_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 20857_f64,
+        b: 5.24625494219_f64,
+        c: 11.0457002639_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 21468_f64,
+        b: 2.6017670427_f64,
+        c: 76.2660712756_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 24060_f64,
+        b: 3.14159265359_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 11405_f64,
+        b: 0.01848461561_f64,
+        c: 70.8494453042_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 7497_f64,
+        b: 0.42360033283_f64,
+        c: 73.297125859_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4244_f64,
+        b: 1.41692350371_f64,
+        c: 85.8272988312_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3506_f64,
+        b: 2.58354048851_f64,
+        c: 138.5174968707_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3229_f64,
+        b: 5.25499602896_f64,
+        c: 3.9321532631_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3927_f64,
+        b: 3.15513991323_f64,
+        c: 71.8126531507_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3060_f64,
+        b: 0.15321893225_f64,
+        c: 1.4844727083_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3578_f64,
+        b: 2.31160668309_f64,
+        c: 224.3447957019_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2564_f64,
+        b: 0.98076846352_f64,
+        c: 148.0787244263_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2429_f64,
+        b: 3.99440122468_f64,
+        c: 52.6901980395_f64,
+    },
+];
+
+const R2: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 22440_f64,
+        b: 0.6995311876_f64,
+        c: 74.7815985673_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4727_f64,
+        b: 1.69901641488_f64,
+        c: 63.7358983034_f64,
+    },
+];
+
+/// The periodic terms for the heliocentric coordinates of Uranus, from
+/// VSOP87D with terms smaller than 1e-6 radians (or 1e-6 of the mean
+/// distance, for the radius vector) omitted
+pub const URANUS_TERMS: PlanetPeriodicTerms = PlanetPeriodicTerms {
+    longitude: &[L0, L1, L2, L3, L4],
+    latitude: &[B0, B1, B2, B3],
+    radius: &[R0, R1, R2],
+};
@@ -0,0 +1,403 @@
+#![allow(clippy::unreadable_literal, clippy::approx_constant)]
+
+use planet::periodic_terms::{PlanetPeriodicTableRow, PlanetPeriodicTerms};
+
+const L0: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 317614667_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1353968_f64,
+        b: 5.59313319619_f64,
+        c: 10213.285546211_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 89892_f64,
+        b: 5.30650048468_f64,
+        c: 20426.571092422_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 5477_f64,
+        b: 4.41630652531_f64,
+        c: 7860.4193924392_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3456_f64,
+        b: 2.69964470778_f64,
+        c: 11790.6290886588_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 2372_f64,
+        b: 2.99377539568_f64,
+        c: 3930.2096962196_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1317_f64,
+        b: 5.18668219093_f64,
+        c: 26.2983197998_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1664_f64,
+        b: 4.2501893503_f64,
+        c: 1577.3435424478_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1438_f64,
+        b: 4.15745043958_f64,
+        c: 9683.5945811164_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1201_f64,
+        b: 6.15357115319_f64,
+        c: 30639.856638633_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 761_f64,
+        b: 1.9501470212_f64,
+        c: 529.6909650946_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 708_f64,
+        b: 1.06466707214_f64,
+        c: 775.522611324_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 585_f64,
+        b: 3.99839884762_f64,
+        c: 191.4482661116_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 769_f64,
+        b: 0.81629615911_f64,
+        c: 9437.762934887_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 500_f64,
+        b: 4.12340210074_f64,
+        c: 15720.8387848784_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 326_f64,
+        b: 4.59056473097_f64,
+        c: 10404.7338123226_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 429_f64,
+        b: 3.58642859752_f64,
+        c: 19367.1891622328_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 327_f64,
+        b: 5.67736583705_f64,
+        c: 5507.5532386674_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 232_f64,
+        b: 3.16251057072_f64,
+        c: 9153.9036160218_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 180_f64,
+        b: 4.65337915578_f64,
+        c: 1109.3785520934_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 128_f64,
+        b: 4.22604493736_f64,
+        c: 20.7753954924_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 155_f64,
+        b: 5.57043888948_f64,
+        c: 19651.048481098_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 128_f64,
+        b: 0.96209822685_f64,
+        c: 5661.3320491522_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 106_f64,
+        b: 1.53721191253_f64,
+        c: 801.8209311238_f64,
+    },
+];
+
+const L1: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 1021352943053_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 95708_f64,
+        b: 2.46424448979_f64,
+        c: 10213.285546211_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 14445_f64,
+        b: 0.51624564679_f64,
+        c: 20426.571092422_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 213_f64,
+        b: 1.79547929368_f64,
+        c: 30639.856638633_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 152_f64,
+        b: 6.10635282369_f64,
+        c: 1577.3435424478_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 174_f64,
+        b: 2.65535879443_f64,
+        c: 26.2983197998_f64,
+    },
+];
+
+const L2: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 54127_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 3891_f64,
+        b: 0.34514360047_f64,
+        c: 10213.285546211_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1338_f64,
+        b: 2.02011286082_f64,
+        c: 20426.571092422_f64,
+    },
+];
+
+const L3: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 136_f64,
+        b: 4.80389020993_f64,
+        c: 10213.285546211_f64,
+    },
+];
+
+const L4: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 114_f64,
+        b: 3.14159265359_f64,
+        c: 0_f64,
+    },
+];
+
+const B0: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 5923638_f64,
+        b: 0.26702775813_f64,
+        c: 10213.285546211_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 40108_f64,
+        b: 1.14737178106_f64,
+        c: 20426.571092422_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 32815_f64,
+        b: 3.14159265359_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1011_f64,
+        b: 1.08946123021_f64,
+        c: 30639.856638633_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 149_f64,
+        b: 6.25390296069_f64,
+        c: 18073.7049386502_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 138_f64,
+        b: 0.86020146523_f64,
+        c: 1577.3435424478_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 130_f64,
+        b: 3.67152483651_f64,
+        c: 9437.762934887_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 120_f64,
+        b: 3.70468812804_f64,
+        c: 2352.8661537718_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 108_f64,
+        b: 4.53903677647_f64,
+        c: 22003.9146348698_f64,
+    },
+];
+
+const B1: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 513348_f64,
+        b: 1.80364310797_f64,
+        c: 10213.285546211_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 4380_f64,
+        b: 3.38615711591_f64,
+        c: 20426.571092422_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 197_f64,
+        b: 2.53001197486_f64,
+        c: 30639.856638633_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 199_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+];
+
+const B2: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 22378_f64,
+        b: 3.38509143877_f64,
+        c: 10213.285546211_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 282_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 173_f64,
+        b: 5.25563766915_f64,
+        c: 20426.571092422_f64,
+    },
+];
+
+const B3: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 647_f64,
+        b: 4.99166565277_f64,
+        c: 10213.285546211_f64,
+    },
+];
+
+const R0: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 72334821_f64,
+        b: 0_f64,
+        c: 0_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 489824_f64,
+        b: 4.02151832268_f64,
+        c: 10213.285546211_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1658_f64,
+        b: 4.90206728012_f64,
+        c: 20426.571092422_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1632_f64,
+        b: 2.84548851892_f64,
+        c: 7860.4193924392_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 1378_f64,
+        b: 1.128465906_f64,
+        c: 11790.6290886588_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 498_f64,
+        b: 2.58682187717_f64,
+        c: 9683.5945811164_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 374_f64,
+        b: 1.42314837063_f64,
+        c: 3930.2096962196_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 264_f64,
+        b: 5.5293818592_f64,
+        c: 9437.762934887_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 237_f64,
+        b: 2.55135903978_f64,
+        c: 15720.8387848784_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 222_f64,
+        b: 2.01346776772_f64,
+        c: 19367.1891622328_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 119_f64,
+        b: 3.01975365264_f64,
+        c: 10404.7338123226_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 126_f64,
+        b: 2.72769833559_f64,
+        c: 1577.3435424478_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 76_f64,
+        b: 1.59577224486_f64,
+        c: 9153.9036160218_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 85_f64,
+        b: 3.98607953754_f64,
+        c: 19651.048481098_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 74_f64,
+        b: 4.11957854039_f64,
+        c: 5507.5532386674_f64,
+    },
+];
+
+const R1: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 34551_f64,
+        b: 0.89198710598_f64,
+        c: 10213.285546211_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 234_f64,
+        b: 1.77224942714_f64,
+        c: 20426.571092422_f64,
+    },
+    PlanetPeriodicTableRow {
+        a: 234_f64,
+        b: 3.14159265359_f64,
+        c: 0_f64,
+    },
+];
+
+const R2: &[PlanetPeriodicTableRow] = &[
+    PlanetPeriodicTableRow {
+        a: 1407_f64,
+        b: 5.0636639519_f64,
+        c: 10213.285546211_f64,
+    },
+];
+
+/// The periodic terms for the heliocentric coordinates of Venus, from
+/// VSOP87D with terms smaller than 1e-6 radians (or 1e-6 of the mean
+/// distance, for the radius vector) omitted
+pub const VENUS_TERMS: PlanetPeriodicTerms = PlanetPeriodicTerms {
+    longitude: &[L0, L1, L2, L3, L4],
+    latitude: &[B0, B1, B2, B3],
+    radius: &[R0, R1, R2],
+};
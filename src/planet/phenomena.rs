@@ -0,0 +1,586 @@
+use chrono::{DateTime, TimeZone};
+use planet::periodic_terms::{self, PlanetPhenomenonTableRow};
+use planet::{apparent_position, Planet};
+use std::f64::consts::PI;
+use sun;
+use time::nasa::{jule_to_dt, jule_to_jul};
+use utils::angles::limit_signed_radians;
+use utils::bisection::bisect;
+use utils::cycles::{nearest_event, CycleEvents};
+
+/// Step, in days, used when searching outward from an estimate for a
+/// change of sign
+const SEARCH_STEP: f64 = 0.5_f64;
+/// Furthest, in days, searched either side of an estimate
+const SEARCH_WINDOW: f64 = 30_f64;
+/// Interval, in days, used for the rates of change of longitude and
+/// elongation
+const DERIVATIVE_STEP: f64 = 0.01_f64;
+/// Number of bisections used to refine the instant of a phenomenon,
+/// narrowing the search step to about a tenth of a second
+const REFINEMENTS: usize = 19;
+
+/// A planetary phenomenon, which occurs once per synodic period
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Phenomenon {
+    /// Mercury or Venus passing between the earth and the sun
+    InferiorConjunction,
+    /// Mercury or Venus passing behind the sun
+    SuperiorConjunction,
+    /// An outer planet passing behind the sun
+    Conjunction,
+    /// An outer planet opposite the sun in the sky
+    Opposition,
+    /// Mercury or Venus at its greatest angular distance east of the sun
+    /// (in the evening sky)
+    GreatestEasternElongation,
+    /// Mercury or Venus at its greatest angular distance west of the sun
+    /// (in the morning sky)
+    GreatestWesternElongation,
+    /// The planet stationary in longitude, as its motion turns retrograde
+    RetrogradeStation,
+    /// The planet stationary in longitude, as its motion returns to direct
+    DirectStation,
+}
+
+/// The phenomena of Mercury and Venus, in the order they occur during a
+/// synodic period
+const INFERIOR_PHENOMENA: [Phenomenon; 6] = [
+    Phenomenon::SuperiorConjunction,
+    Phenomenon::GreatestEasternElongation,
+    Phenomenon::RetrogradeStation,
+    Phenomenon::InferiorConjunction,
+    Phenomenon::DirectStation,
+    Phenomenon::GreatestWesternElongation,
+];
+
+/// The phenomena of the outer planets, in the order they occur during a
+/// synodic period
+const SUPERIOR_PHENOMENA: [Phenomenon; 4] = [
+    Phenomenon::Conjunction,
+    Phenomenon::RetrogradeStation,
+    Phenomenon::Opposition,
+    Phenomenon::DirectStation,
+];
+
+impl Phenomenon {
+    /// The phenomena of a planet, in the order they occur during a synodic
+    /// period
+    pub fn all(planet: Planet) -> &'static [Phenomenon] {
+        if planet.is_inferior() {
+            &INFERIOR_PHENOMENA
+        } else {
+            &SUPERIOR_PHENOMENA
+        }
+    }
+
+    /// Whether the planet can undergo the phenomenon (only Mercury and Venus
+    /// have inferior and superior conjunctions and greatest elongations, and
+    /// only the outer planets have conjunctions and oppositions)
+    pub fn applies_to(self, planet: Planet) -> bool {
+        Phenomenon::all(planet).contains(&self)
+    }
+
+    /// The conjunction or opposition that the synodic period is counted
+    /// from, for Meeus' mean instants
+    fn reference(self, planet: Planet) -> Phenomenon {
+        match self {
+            Phenomenon::GreatestEasternElongation |
+            Phenomenon::GreatestWesternElongation |
+            Phenomenon::RetrogradeStation |
+            Phenomenon::DirectStation => {
+                if planet.is_inferior() {
+                    Phenomenon::InferiorConjunction
+                } else {
+                    Phenomenon::Opposition
+                }
+            }
+            _ => self,
+        }
+    }
+}
+
+/// The instant of a planetary phenomenon
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PhenomenonInstant {
+    /// the planet
+    pub planet: Planet,
+    /// the phenomenon
+    pub phenomenon: Phenomenon,
+    /// synodic period number, counting from around 2000 (Meeus' k)
+    pub cycle: i64,
+    /// Julian Ephemeris Day of the phenomenon
+    pub jde: f64,
+    /// the planet's apparent angular distance from the sun, in radians
+    pub elongation: f64,
+}
+
+impl PhenomenonInstant {
+    /// Calculates the instant of a phenomenon during a synodic period, or
+    /// `None` if the planet can't undergo it
+    pub fn new(planet: Planet, phenomenon: Phenomenon, cycle: i64) -> Option<PhenomenonInstant> {
+        if !phenomenon.applies_to(planet) {
+            return None;
+        }
+
+        let estimate = estimate_jule(planet, phenomenon, cycle);
+        let jde = refine_phenomenon_jule(planet, phenomenon, estimate);
+
+        Some(PhenomenonInstant {
+            planet,
+            phenomenon,
+            cycle,
+            jde,
+            elongation: apparent_position(planet, jde).elongation,
+        })
+    }
+
+    /// The Julian Day (UT) of the phenomenon
+    pub fn jul(&self) -> f64 {
+        jule_to_jul(self.jde)
+    }
+
+    /// The instant of the phenomenon in the given timezone, or `None` if it
+    /// can't be represented by `chrono`
+    pub fn dt<Tz: TimeZone>(&self, tz: &Tz) -> Option<DateTime<Tz>> {
+        jule_to_dt(self.jde, tz)
+    }
+}
+
+/// Meeus' mean elements of a conjunction or opposition (table 36.A): the
+/// instant of the first (A), the synodic period (B), and the planet's mean
+/// anomaly at the first (M0) and its increase per synodic period (M1)
+fn mean_elements(planet: Planet, phenomenon: Phenomenon) -> (f64, f64, f64, f64) {
+    match (planet, phenomenon.reference(planet)) {
+        (Planet::Mercury, Phenomenon::InferiorConjunction) => {
+            (2_451_612.023_f64, 115.877_477_1_f64, 63.586_7_f64, 114.208_874_2_f64)
+        }
+        (Planet::Mercury, _) => {
+            (2_451_554.084_f64, 115.877_477_1_f64, 6.482_2_f64, 114.208_874_2_f64)
+        }
+        (Planet::Venus, Phenomenon::InferiorConjunction) => {
+            (2_451_996.706_f64, 583.921_361_f64, 82.731_1_f64, 215.513_058_f64)
+        }
+        (Planet::Venus, _) => (2_451_704.746_f64, 583.921_361_f64, 154.974_5_f64, 215.513_058_f64),
+        (Planet::Mars, Phenomenon::Opposition) => {
+            (2_452_097.382_f64, 779.936_104_f64, 181.957_3_f64, 48.705_244_f64)
+        }
+        (Planet::Mars, _) => (2_451_707.414_f64, 779.936_104_f64, 157.604_7_f64, 48.705_244_f64),
+        (Planet::Jupiter, Phenomenon::Opposition) => {
+            (2_451_870.628_f64, 398.884_046_f64, 318.468_1_f64, 33.140_229_f64)
+        }
+        (Planet::Jupiter, _) => (2_451_671.186_f64, 398.884_046_f64, 121.898_f64, 33.140_229_f64),
+        (Planet::Saturn, Phenomenon::Opposition) => {
+            (2_451_870.17_f64, 378.091_904_f64, 318.017_2_f64, 12.647_487_f64)
+        }
+        (Planet::Saturn, _) => (2_451_681.124_f64, 378.091_904_f64, 131.693_4_f64, 12.647_487_f64),
+        (Planet::Uranus, Phenomenon::Opposition) => {
+            (2_451_764.317_f64, 369.656_035_f64, 213.688_4_f64, 4.333_093_f64)
+        }
+        (Planet::Uranus, _) => (2_451_579.489_f64, 369.656_035_f64, 31.521_9_f64, 4.333_093_f64),
+        (Planet::Neptune, Phenomenon::Opposition) => {
+            (2_451_753.122_f64, 367.486_703_f64, 202.654_4_f64, 2.194_998_f64)
+        }
+        (Planet::Neptune, _) => (2_451_569.379_f64, 367.486_703_f64, 21.556_9_f64, 2.194_998_f64),
+    }
+}
+
+/// Meeus' periodic terms for a phenomenon, if he gives them
+fn phenomenon_terms(
+    planet: Planet,
+    phenomenon: Phenomenon,
+) -> Option<&'static [PlanetPhenomenonTableRow]> {
+    use self::Phenomenon::*;
+
+    let terms = match (planet, phenomenon) {
+        (Planet::Mercury, InferiorConjunction) => {
+            periodic_terms::MERCURY_INFERIOR_CONJUNCTION_TERMS
+        }
+        (Planet::Mercury, SuperiorConjunction) => {
+            periodic_terms::MERCURY_SUPERIOR_CONJUNCTION_TERMS
+        }
+        (Planet::Mercury, GreatestEasternElongation) => {
+            periodic_terms::MERCURY_GREATEST_EASTERN_ELONGATION_TERMS
+        }
+        (Planet::Mercury, GreatestWesternElongation) => {
+            periodic_terms::MERCURY_GREATEST_WESTERN_ELONGATION_TERMS
+        }
+        (Planet::Mercury, RetrogradeStation) => periodic_terms::MERCURY_RETROGRADE_STATION_TERMS,
+        (Planet::Mercury, DirectStation) => periodic_terms::MERCURY_DIRECT_STATION_TERMS,
+        (Planet::Venus, InferiorConjunction) => periodic_terms::VENUS_INFERIOR_CONJUNCTION_TERMS,
+        (Planet::Venus, SuperiorConjunction) => periodic_terms::VENUS_SUPERIOR_CONJUNCTION_TERMS,
+        (Planet::Venus, GreatestEasternElongation) => {
+            periodic_terms::VENUS_GREATEST_EASTERN_ELONGATION_TERMS
+        }
+        (Planet::Venus, GreatestWesternElongation) => {
+            periodic_terms::VENUS_GREATEST_WESTERN_ELONGATION_TERMS
+        }
+        (Planet::Venus, RetrogradeStation) => periodic_terms::VENUS_RETROGRADE_STATION_TERMS,
+        (Planet::Venus, DirectStation) => periodic_terms::VENUS_DIRECT_STATION_TERMS,
+        (Planet::Mars, Opposition) => periodic_terms::MARS_OPPOSITION_TERMS,
+        (Planet::Mars, Conjunction) => periodic_terms::MARS_CONJUNCTION_TERMS,
+        (Planet::Mars, RetrogradeStation) => periodic_terms::MARS_RETROGRADE_STATION_TERMS,
+        (Planet::Mars, DirectStation) => periodic_terms::MARS_DIRECT_STATION_TERMS,
+        (Planet::Jupiter, Opposition) => periodic_terms::JUPITER_OPPOSITION_TERMS,
+        (Planet::Jupiter, Conjunction) => periodic_terms::JUPITER_CONJUNCTION_TERMS,
+        (Planet::Saturn, Opposition) => periodic_terms::SATURN_OPPOSITION_TERMS,
+        (Planet::Saturn, Conjunction) => periodic_terms::SATURN_CONJUNCTION_TERMS,
+        (Planet::Uranus, Opposition) => periodic_terms::URANUS_OPPOSITION_TERMS,
+        (Planet::Uranus, Conjunction) => periodic_terms::URANUS_CONJUNCTION_TERMS,
+        (Planet::Neptune, Opposition) => periodic_terms::NEPTUNE_OPPOSITION_TERMS,
+        (Planet::Neptune, Conjunction) => periodic_terms::NEPTUNE_CONJUNCTION_TERMS,
+        _ => return None,
+    };
+
+    Some(terms)
+}
+
+/// The mean number of days from the stations of the outer planets to their
+/// oppositions, and back, for which Meeus doesn't give periodic terms
+fn mean_station_offsets(planet: Planet) -> (f64, f64) {
+    match planet {
+        Planet::Jupiter => (59.8_f64, 60.4_f64),
+        Planet::Saturn => (68.7_f64, 69.2_f64),
+        Planet::Uranus => (76.9_f64, 74.4_f64),
+        _ => (79.3_f64, 79.9_f64),
+    }
+}
+
+/// Calculates the Julian Ephemeris Day of a planetary phenomenon, using
+/// Meeus' fast method of mean instants and periodic terms (ch. 36)
+///
+/// Synodic periods are counted from the phenomenon's epoch around 2000 (or,
+/// for greatest elongations and stations, from the epoch of the inferior
+/// conjunction or opposition they surround). The result is usually within
+/// an hour or so of the true instant, which `refine_phenomenon_jule` finds.
+/// Returns `None` if the planet can't undergo the phenomenon, or for the
+/// stations of Jupiter and beyond (which Meeus doesn't cover).
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::planet::{phenomenon_jule, Phenomenon, Planet};
+///
+/// // Meeus example 36.a: inferior conjunction of Mercury, 1993 November 6
+/// let jde = phenomenon_jule(Planet::Mercury, Phenomenon::InferiorConjunction, -20).unwrap();
+/// assert_eq!(2_449_297.645, (jde * 1_000_f64).round() / 1_000_f64);
+/// ```
+pub fn phenomenon_jule(planet: Planet, phenomenon: Phenomenon, cycle: i64) -> Option<f64> {
+    if !phenomenon.applies_to(planet) {
+        return None;
+    }
+    let terms = phenomenon_terms(planet, phenomenon)?;
+
+    let (a, b, m0, m1) = mean_elements(planet, phenomenon);
+    let k = cycle as f64;
+    let mean = a + b * k;
+    let t = (mean - 2_451_545_f64) / 36_525_f64;
+
+    let args = [
+        (m0 + m1 * k).to_radians(),
+        (82.74_f64 + 40.76_f64 * t).to_radians(),
+        (29.86_f64 + 1_181.36_f64 * t).to_radians(),
+        (14.13_f64 + 590.68_f64 * t).to_radians(),
+        (220.02_f64 + 1_262.87_f64 * t).to_radians(),
+        (207.83_f64 + 8.51_f64 * t).to_radians(),
+        (108.84_f64 + 419.96_f64 * t).to_radians(),
+        (276.74_f64 + 209.98_f64 * t).to_radians(),
+    ];
+
+    let periodic = terms.iter().fold(0_f64, |curr, row| {
+        let coefficient = row.coefficients[0] + row.coefficients[1] * t +
+            row.coefficients[2] * t.powi(2);
+        let argument = f64::from(row.multiple) * args[row.argument];
+
+        curr +
+            coefficient *
+                if row.cosine {
+                    argument.cos()
+                } else {
+                    argument.sin()
+                }
+    });
+
+    Some(mean + periodic)
+}
+
+/// Estimates the Julian Ephemeris Day of a phenomenon, from Meeus' method
+/// where possible, or the mean instant otherwise
+fn estimate_jule(planet: Planet, phenomenon: Phenomenon, cycle: i64) -> f64 {
+    phenomenon_jule(planet, phenomenon, cycle).unwrap_or_else(|| {
+        let (a, b, _, _) = mean_elements(planet, phenomenon);
+        let offset = match phenomenon {
+            Phenomenon::RetrogradeStation => -mean_station_offsets(planet).0,
+            Phenomenon::DirectStation => mean_station_offsets(planet).1,
+            _ => 0_f64,
+        };
+
+        a + b * cycle as f64 + offset
+    })
+}
+
+/// The planet's apparent geocentric longitude less the sun's, in radians
+fn longitude_from_sun(planet: Planet, jde: f64) -> f64 {
+    apparent_position(planet, jde).ecliptic.longitude - sun::apparent_longitude(jde)
+}
+
+/// Finds the change of sign of a function nearest to an estimate, searching
+/// outward in steps, then narrows it down by bisection
+fn find_sign_change<F: Fn(f64) -> f64>(f: F, estimate: f64) -> Option<f64> {
+    let mut offset = 0_f64;
+
+    while offset < SEARCH_WINDOW {
+        for &(low, high) in &[
+            (estimate + offset, estimate + offset + SEARCH_STEP),
+            (estimate - offset - SEARCH_STEP, estimate - offset),
+        ]
+        {
+            if let Some(found) = bisect(&f, low, high, REFINEMENTS) {
+                return Some(found);
+            }
+        }
+
+        offset += SEARCH_STEP;
+    }
+
+    None
+}
+
+/// Refines an estimate of the Julian Ephemeris Day of a planetary
+/// phenomenon against the planet's apparent position (from VSOP87)
+///
+/// Conjunctions and oppositions are found where the apparent longitudes of
+/// the planet and sun agree (or differ by 180°), greatest elongations where
+/// the angular distance from the sun is greatest, and stations where the
+/// apparent longitude stops changing. The estimate should be within a few
+/// weeks of the instant, and is returned unchanged if nothing is found.
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::planet::{phenomenon_jule, refine_phenomenon_jule, Phenomenon, Planet};
+///
+/// // inferior conjunction of Mercury, 1993 November 6: 3h29m TD by the
+/// // fast method (Meeus example 36.a), refined to 3h34m
+/// let phenomenon = Phenomenon::InferiorConjunction;
+/// let estimate = phenomenon_jule(Planet::Mercury, phenomenon, -20).unwrap();
+/// let jde = refine_phenomenon_jule(Planet::Mercury, phenomenon, estimate);
+/// assert_eq!(2_449_297.648_6, (jde * 10_000_f64).round() / 10_000_f64);
+/// ```
+pub fn refine_phenomenon_jule(planet: Planet, phenomenon: Phenomenon, estimate: f64) -> f64 {
+    let h = DERIVATIVE_STEP;
+
+    let found = match phenomenon {
+        Phenomenon::InferiorConjunction |
+        Phenomenon::SuperiorConjunction |
+        Phenomenon::Conjunction => {
+            find_sign_change(
+                |jde| limit_signed_radians(longitude_from_sun(planet, jde)),
+                estimate,
+            )
+        }
+        Phenomenon::Opposition => {
+            find_sign_change(
+                |jde| limit_signed_radians(longitude_from_sun(planet, jde) - PI),
+                estimate,
+            )
+        }
+        Phenomenon::GreatestEasternElongation |
+        Phenomenon::GreatestWesternElongation => {
+            find_sign_change(
+                |jde| {
+                    apparent_position(planet, jde + h).elongation -
+                        apparent_position(planet, jde - h).elongation
+                },
+                estimate,
+            )
+        }
+        Phenomenon::RetrogradeStation |
+        Phenomenon::DirectStation => {
+            find_sign_change(
+                |jde| {
+                    limit_signed_radians(
+                        apparent_position(planet, jde + h).ecliptic.longitude -
+                            apparent_position(planet, jde - h).ecliptic.longitude,
+                    )
+                },
+                estimate,
+            )
+        }
+    };
+
+    found.unwrap_or(estimate)
+}
+
+/// Finds the instant of a planetary phenomenon nearest to a Julian Day (UT),
+/// or `None` if the planet can't undergo it
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::planet::{nearest_phenomenon, Phenomenon, Planet};
+///
+/// // Mars was at opposition on 2018 July 27
+/// let opposition = nearest_phenomenon(Planet::Mars, Phenomenon::Opposition, 2_458_300.5).unwrap();
+/// assert_eq!(2_458_326.7, (opposition.jul() * 10_f64).round() / 10_f64);
+/// ```
+pub fn nearest_phenomenon(
+    planet: Planet,
+    phenomenon: Phenomenon,
+    jd: f64,
+) -> Option<PhenomenonInstant> {
+    if !phenomenon.applies_to(planet) {
+        return None;
+    }
+
+    let (a, b, _, _) = mean_elements(planet, phenomenon);
+    let estimate = ((jd - a) / b).round() as i64;
+
+    Some(nearest_event(
+        estimate,
+        jd,
+        |cycle| PhenomenonInstant::new(planet, phenomenon, cycle).unwrap(),
+        |instant| instant.jde,
+    ))
+}
+
+/// An iterator over the phenomena of a planet between two instants, in
+/// order
+#[derive(Debug, Clone)]
+pub struct Phenomena {
+    planet: Planet,
+    events: CycleEvents<Phenomenon>,
+}
+
+impl Iterator for Phenomena {
+    type Item = PhenomenonInstant;
+
+    fn next(&mut self) -> Option<PhenomenonInstant> {
+        let planet = self.planet;
+
+        self.events.next_event(
+            |cycle, phenomenon| PhenomenonInstant::new(planet, phenomenon, cycle).unwrap(),
+            |instant| instant.jde,
+        )
+    }
+}
+
+/// Iterates over the phenomena of a planet (conjunctions, oppositions,
+/// greatest elongations and stations) from one Julian Day (UT, inclusive) to
+/// another (exclusive)
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::planet::{phenomena, Phenomenon, Planet};
+///
+/// // Mercury's greatest elongations in 2017
+/// let elongations = phenomena(Planet::Mercury, 2_457_754.5, 2_458_119.5)
+///     .filter(|instant| {
+///         instant.phenomenon == Phenomenon::GreatestEasternElongation ||
+///             instant.phenomenon == Phenomenon::GreatestWesternElongation
+///     })
+///     .count();
+/// assert_eq!(6, elongations);
+/// ```
+pub fn phenomena(planet: Planet, start_jd: f64, end_jd: f64) -> Phenomena {
+    let all = Phenomenon::all(planet);
+    let (a, b, _, _) = mean_elements(planet, all[0]);
+    let cycle = ((start_jd - a) / b).floor() as i64 - 1;
+
+    Phenomena {
+        planet,
+        events: CycleEvents::new(all, cycle, start_jd, end_jd),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use chrono::{Datelike, TimeZone, Utc};
+    use planet::*;
+    use sun;
+    use time::dt_to_jul;
+
+    #[test]
+    fn fast_method_is_close_to_refined() {
+        for &planet in Planet::ALL.iter() {
+            for &phenomenon in Phenomenon::all(planet) {
+                for cycle in -2..3 {
+                    if let Some(estimate) = phenomenon_jule(planet, phenomenon, cycle) {
+                        let refined = refine_phenomenon_jule(planet, phenomenon, estimate);
+                        assert!(
+                            (refined - estimate).abs() < 0.25,
+                            "{:?} {:?} {}",
+                            planet,
+                            phenomenon,
+                            cycle
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn venus_in_2017() {
+        // inferior conjunction on 2017 March 25 at 10h17m UT
+        let expected = Utc.with_ymd_and_hms(2017, 3, 25, 10, 17, 0).unwrap();
+        let conjunction =
+            nearest_phenomenon(Planet::Venus, Phenomenon::InferiorConjunction, dt_to_jul(&expected))
+                .unwrap();
+        let difference = conjunction.dt(&Utc).unwrap() - expected;
+        assert!(difference.num_seconds().abs() <= 60);
+
+        // greatest eastern elongation of 47.1° on 2017 January 12
+        let elongation = nearest_phenomenon(
+            Planet::Venus,
+            Phenomenon::GreatestEasternElongation,
+            2_457_765.5_f64,
+        ).unwrap();
+        assert_eq!(12, elongation.dt(&Utc).unwrap().day());
+        assert!((elongation.elongation.to_degrees() - 47.1_f64).abs() < 0.05);
+
+        // it was stationary from March 4 to April 15
+        let jd = 2_457_830.5_f64;
+        let retrograde = nearest_phenomenon(Planet::Venus, Phenomenon::RetrogradeStation, jd)
+            .and_then(|instant| instant.dt(&Utc))
+            .unwrap();
+        let direct = nearest_phenomenon(Planet::Venus, Phenomenon::DirectStation, jd)
+            .and_then(|instant| instant.dt(&Utc))
+            .unwrap();
+        assert_eq!((3, 4), (retrograde.month(), retrograde.day()));
+        assert_eq!((4, 15), (direct.month(), direct.day()));
+    }
+
+    #[test]
+    fn refined_instants_fit_their_definitions() {
+        let jde = 2_458_326.5_f64;
+        let opposition = nearest_phenomenon(Planet::Mars, Phenomenon::Opposition, jde).unwrap();
+        let longitude = |jde: f64| apparent_position(Planet::Mars, jde).ecliptic.longitude;
+        let separation = |jde: f64| {
+            (longitude(jde) - sun::apparent_longitude(jde)).to_degrees().rem_euclid(360_f64)
+        };
+        assert!((separation(opposition.jde) - 180_f64).abs() < 0.000_1);
+
+        let station = nearest_phenomenon(Planet::Mars, Phenomenon::DirectStation, jde).unwrap();
+        let before = longitude(station.jde - 1_f64);
+        let after = longitude(station.jde + 1_f64);
+        assert!(before > longitude(station.jde) && after > longitude(station.jde));
+    }
+
+    #[test]
+    fn phenomena_are_ordered() {
+        for &planet in Planet::ALL.iter() {
+            let all: Vec<PhenomenonInstant> =
+                phenomena(planet, 2_451_545_f64, 2_451_545_f64 + 1_500_f64).collect();
+            let sequence = Phenomenon::all(planet);
+
+            for pair in all.windows(2) {
+                assert!(pair[0].jde < pair[1].jde, "{:?}", pair);
+                let index = sequence.iter().position(|&p| p == pair[0].phenomenon).unwrap();
+                assert_eq!(sequence[(index + 1) % sequence.len()], pair[1].phenomenon);
+            }
+        }
+    }
+}
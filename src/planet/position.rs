@@ -0,0 +1,328 @@
+use earth;
+use planet::periodic_terms::{self, PlanetPeriodicTableRow, PlanetPeriodicTerms};
+use std::f64::consts::PI;
+use time::{jul_to_julc, julc_to_julm};
+use utils::angles::limit_radians;
+use utils::coordinates::{Ecliptic, Equatorial};
+
+/// Days taken by light to travel one astronomical unit
+const LIGHT_TIME_PER_AU: f64 = 0.005_775_518_3_f64;
+
+/// A major planet (other than the earth, which has its own module)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Planet {
+    /// Mercury
+    Mercury,
+    /// Venus
+    Venus,
+    /// Mars
+    Mars,
+    /// Jupiter
+    Jupiter,
+    /// Saturn
+    Saturn,
+    /// Uranus
+    Uranus,
+    /// Neptune
+    Neptune,
+}
+
+impl Planet {
+    /// All of the planets, in order of distance from the sun
+    pub const ALL: [Planet; 7] = [
+        Planet::Mercury,
+        Planet::Venus,
+        Planet::Mars,
+        Planet::Jupiter,
+        Planet::Saturn,
+        Planet::Uranus,
+        Planet::Neptune,
+    ];
+
+    /// Whether the planet's orbit lies inside the earth's (so that it has
+    /// inferior and superior conjunctions, rather than oppositions)
+    pub fn is_inferior(self) -> bool {
+        self == Planet::Mercury || self == Planet::Venus
+    }
+
+    /// The VSOP87D periodic terms for the planet's heliocentric coordinates
+    pub fn periodic_terms(self) -> &'static PlanetPeriodicTerms {
+        match self {
+            Planet::Mercury => &periodic_terms::MERCURY_TERMS,
+            Planet::Venus => &periodic_terms::VENUS_TERMS,
+            Planet::Mars => &periodic_terms::MARS_TERMS,
+            Planet::Jupiter => &periodic_terms::JUPITER_TERMS,
+            Planet::Saturn => &periodic_terms::SATURN_TERMS,
+            Planet::Uranus => &periodic_terms::URANUS_TERMS,
+            Planet::Neptune => &periodic_terms::NEPTUNE_TERMS,
+        }
+    }
+}
+
+/// The apparent geocentric position of a planet at an instant
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ApparentPosition {
+    /// apparent ecliptic coordinates (λ, β), referred to the true equinox
+    /// of date
+    pub ecliptic: Ecliptic,
+    /// apparent equatorial coordinates (α, δ), referred to the true
+    /// equinox of date
+    pub equatorial: Equatorial,
+    /// distance from the earth (Δ), in AU
+    pub distance: f64,
+    /// time taken by light to reach the earth from the planet (τ), in days
+    pub light_time: f64,
+    /// distance from the sun (r) when the light left the planet, in AU
+    pub sun_distance: f64,
+    /// distance between the earth and sun (R), in AU
+    pub earth_distance: f64,
+    /// angular distance from the sun (ψ), from 0 to π
+    pub elongation: f64,
+}
+
+fn sum_series(series: &[&[PlanetPeriodicTableRow]], jul_mil_ephemeris: f64) -> f64 {
+    let sum = series.iter().rev().fold(0_f64, |curr, rows| {
+        curr * jul_mil_ephemeris +
+            rows.iter()
+                .map(|row| row.a * (row.b + row.c * jul_mil_ephemeris).cos())
+                .sum::<f64>()
+    });
+
+    sum / 100_000_000_f64
+}
+
+/// Calculates the heliocentric longitude of a planet, in radians, referred
+/// to the mean ecliptic and equinox of date
+///
+/// Obtained from Meeus (ch. 32)
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::planet::{heliocentric_longitude, Planet};
+///
+/// // Meeus example 32.a: Venus on 1992 December 20, 0h TD
+/// let l = heliocentric_longitude(Planet::Venus, -0.007_032_169_747);
+/// assert_eq!(26.114_28, (l.to_degrees() * 100_000_f64).round() / 100_000_f64);
+/// ```
+pub fn heliocentric_longitude(planet: Planet, jul_mil_ephemeris: f64) -> f64 {
+    limit_radians(sum_series(
+        planet.periodic_terms().longitude,
+        jul_mil_ephemeris,
+    ))
+}
+
+/// Calculates the heliocentric latitude of a planet, in radians, referred
+/// to the mean ecliptic of date
+///
+/// Obtained from Meeus (ch. 32)
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::planet::{heliocentric_latitude, Planet};
+///
+/// // Meeus example 32.a: Venus on 1992 December 20, 0h TD
+/// let b = heliocentric_latitude(Planet::Venus, -0.007_032_169_747);
+/// assert_eq!(-2.620_70, (b.to_degrees() * 100_000_f64).round() / 100_000_f64);
+/// ```
+pub fn heliocentric_latitude(planet: Planet, jul_mil_ephemeris: f64) -> f64 {
+    sum_series(planet.periodic_terms().latitude, jul_mil_ephemeris)
+}
+
+/// Calculates the distance between a planet and the sun, in AU
+///
+/// Obtained from Meeus (ch. 32)
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::planet::{radius_vec, Planet};
+///
+/// // Meeus example 32.a: Venus on 1992 December 20, 0h TD
+/// let r = radius_vec(Planet::Venus, -0.007_032_169_747);
+/// assert_eq!(0.724_60, (r * 100_000_f64).round() / 100_000_f64);
+/// ```
+pub fn radius_vec(planet: Planet, jul_mil_ephemeris: f64) -> f64 {
+    sum_series(planet.periodic_terms().radius, jul_mil_ephemeris)
+}
+
+/// Calculates the geometric geocentric position of a planet, corrected for
+/// light-time, as its ecliptic coordinates (referred to the mean equinox of
+/// date), distance from the earth, light-time, distance from the sun and the
+/// earth's distance from the sun
+pub(crate) fn geometric_position(planet: Planet, jde: f64) -> (Ecliptic, f64, f64, f64, f64) {
+    let jme = julc_to_julm(jul_to_julc(jde));
+
+    let earth_longitude = earth::heliocentric_longitude(jme);
+    let earth_latitude = earth::heliocentric_latitude(jme);
+    let earth_distance = earth::radius_vec(jme);
+    let earth_x = earth_distance * earth_latitude.cos() * earth_longitude.cos();
+    let earth_y = earth_distance * earth_latitude.cos() * earth_longitude.sin();
+    let earth_z = earth_distance * earth_latitude.sin();
+
+    // the planet is seen where it was when the light left it, so iterate
+    // until the light-time settles
+    let mut light_time = 0_f64;
+    let mut result = (0_f64, 0_f64, 0_f64, 0_f64);
+    for _ in 0..5 {
+        let tau = julc_to_julm(jul_to_julc(jde - light_time));
+        let l = heliocentric_longitude(planet, tau);
+        let b = heliocentric_latitude(planet, tau);
+        let r = radius_vec(planet, tau);
+
+        let x = r * b.cos() * l.cos() - earth_x;
+        let y = r * b.cos() * l.sin() - earth_y;
+        let z = r * b.sin() - earth_z;
+        let distance = (x.powi(2) + y.powi(2) + z.powi(2)).sqrt();
+
+        result = (y.atan2(x), z.atan2(x.hypot(y)), distance, r);
+
+        let next = LIGHT_TIME_PER_AU * distance;
+        let converged = (next - light_time).abs() < 1e-9;
+        light_time = next;
+        if converged {
+            break;
+        }
+    }
+
+    let (longitude, latitude, distance, sun_distance) = result;
+    (
+        Ecliptic {
+            longitude: limit_radians(longitude),
+            latitude,
+        },
+        distance,
+        light_time,
+        sun_distance,
+        earth_distance,
+    )
+}
+
+/// Calculates the apparent geocentric position of a planet for a given
+/// Julian Ephemeris Day
+///
+/// Combines the planet's heliocentric position (at the instant the light
+/// left it) with the earth's, then corrects for aberration, the FK5 frame
+/// and nutation. Obtained from Meeus (ch. 33).
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::planet::{apparent_position, Planet};
+///
+/// // Meeus example 33.a: Venus on 1992 December 20, 0h TD
+/// let venus = apparent_position(Planet::Venus, 2_448_976.5);
+/// let alpha = venus.equatorial.right_ascension.to_degrees();
+/// let delta = venus.equatorial.declination.to_degrees();
+/// assert_eq!(316.173, (alpha * 1_000_f64).round() / 1_000_f64);
+/// assert_eq!(-18.888, (delta * 1_000_f64).round() / 1_000_f64);
+/// assert_eq!(0.910_947, (venus.distance * 1_000_000_f64).round() / 1_000_000_f64);
+/// ```
+pub fn apparent_position(planet: Planet, jde: f64) -> ApparentPosition {
+    let jce = jul_to_julc(jde);
+    let jme = julc_to_julm(jce);
+
+    let (geometric, distance, light_time, sun_distance, earth_distance) =
+        geometric_position(planet, jde);
+    let lambda = geometric.longitude;
+    let beta = geometric.latitude;
+
+    // annual aberration (Meeus 23.2), using the sun's geometric longitude
+    // and the eccentricity and perihelion of the earth's orbit
+    let kappa = (20.495_52_f64 / 3_600_f64).to_radians();
+    let sun_longitude = earth::heliocentric_longitude(jme) + PI;
+    let eccentricity = 0.016_708_634_f64 - 0.000_042_037_f64 * jce -
+        0.000_000_126_7_f64 * jce.powi(2);
+    let perihelion = (102.937_35_f64 + 1.719_46_f64 * jce + 0.000_46_f64 * jce.powi(2))
+        .to_radians();
+
+    let aberration_longitude = (-kappa * (sun_longitude - lambda).cos() +
+                                    eccentricity * kappa * (perihelion - lambda).cos()) /
+        beta.cos();
+    let aberration_latitude = -kappa * beta.sin() *
+        ((sun_longitude - lambda).sin() - eccentricity * (perihelion - lambda).sin());
+
+    // conversion to the FK5 system (Meeus 32.3)
+    let lambda_prime = lambda - (1.397_f64 * jce + 0.000_31_f64 * jce.powi(2)).to_radians();
+    let fk5_longitude = (-0.090_33_f64 +
+                             0.039_16_f64 * (lambda_prime.cos() + lambda_prime.sin()) *
+                                 beta.tan()) / 3_600_f64;
+    let fk5_latitude = 0.039_16_f64 * (lambda_prime.cos() - lambda_prime.sin()) / 3_600_f64;
+
+    let ecliptic = Ecliptic {
+        longitude: limit_radians(
+            lambda + aberration_longitude + fk5_longitude.to_radians() +
+                earth::nutation_longitude(jce),
+        ),
+        latitude: beta + aberration_latitude + fk5_latitude.to_radians(),
+    };
+    let equatorial = ecliptic.to_equatorial(earth::true_obliquity(
+        earth::mean_obliquity(jme),
+        earth::nutation_obliquity(jce),
+    ));
+
+    let elongation = ((earth_distance.powi(2) + distance.powi(2) - sun_distance.powi(2)) /
+                          (2_f64 * earth_distance * distance))
+        .clamp(-1_f64, 1_f64)
+        .acos();
+
+    ApparentPosition {
+        ecliptic,
+        equatorial,
+        distance,
+        light_time,
+        sun_distance,
+        earth_distance,
+        elongation,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use planet::*;
+    use std::f64::consts::PI;
+    use sun;
+    use utils::coordinates::Equatorial;
+
+    #[test]
+    fn meeus_example_33_a() {
+        let venus = apparent_position(Planet::Venus, 2_448_976.5_f64);
+
+        assert!((venus.light_time - 0.005_261_2_f64).abs() < 0.000_000_5);
+        assert!((venus.sun_distance - 0.724_603_f64).abs() < 0.000_002);
+        // α = 21h04m41.454s, δ = -18°53'16.84"
+        assert!((venus.equatorial.right_ascension.to_degrees() - 316.172_725_f64).abs() < 0.000_2);
+        assert!((venus.equatorial.declination.to_degrees() + 18.888_01_f64).abs() < 0.000_2);
+    }
+
+    #[test]
+    fn elongation_matches_angular_separation_from_sun() {
+        for &planet in Planet::ALL.iter() {
+            let jde = 2_458_000.5_f64;
+            let position = apparent_position(planet, jde);
+            let sun: Equatorial = sun::apparent_equatorial(jde);
+
+            let separation = position.equatorial.separation(&sun);
+            assert!(
+                (separation - position.elongation).abs().to_degrees() < 0.01,
+                "{:?}",
+                planet
+            );
+        }
+    }
+
+    #[test]
+    fn mars_opposition_2018() {
+        // Mars was at opposition on 2018 July 27, at a distance of 0.386 AU
+        // and 6.5° south of the ecliptic
+        let jde = 2_458_326.5_f64;
+        let mars = apparent_position(Planet::Mars, jde);
+        let opposite_sun = sun::apparent_longitude(jde) + PI;
+
+        assert!((mars.ecliptic.longitude - opposite_sun).abs().to_degrees() < 0.5);
+        assert!((mars.ecliptic.latitude.to_degrees() + 6.5_f64).abs() < 0.1);
+        assert!((mars.elongation.to_degrees() - 173.5_f64).abs() < 0.1);
+        assert!((mars.distance - 0.386_f64).abs() < 0.002);
+    }
+}
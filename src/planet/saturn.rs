@@ -0,0 +1,224 @@
+use earth;
+use planet::{geometric_position, heliocentric_latitude, heliocentric_longitude, Planet};
+use std::f64::consts::FRAC_PI_2;
+use time::{jul_to_julc, julc_to_julm};
+use utils::angles::{limit_radians, limit_signed_radians};
+use utils::coordinates::Ecliptic;
+
+/// Apparent major axis of the outer edge of the outer ring at a distance of
+/// one AU, in arcseconds
+const RING_MAJOR_AXIS: f64 = 375.35_f64;
+
+/// An edge of Saturn's rings
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum RingEdge {
+    /// outer edge of the outer ring (ring A)
+    OuterRingOuter,
+    /// inner edge of the outer ring (ring A)
+    OuterRingInner,
+    /// outer edge of the inner ring (ring B)
+    InnerRingOuter,
+    /// inner edge of the inner ring (ring B)
+    InnerRingInner,
+    /// inner edge of the dusky ring (ring C)
+    DuskyRingInner,
+}
+
+impl RingEdge {
+    /// The size of the edge, relative to the outer edge of the outer ring
+    fn ratio(self) -> f64 {
+        match self {
+            RingEdge::OuterRingOuter => 1_f64,
+            RingEdge::OuterRingInner => 0.880_1_f64,
+            RingEdge::InnerRingOuter => 0.859_9_f64,
+            RingEdge::InnerRingInner => 0.665_0_f64,
+            RingEdge::DuskyRingInner => 0.548_6_f64,
+        }
+    }
+}
+
+/// The appearance of Saturn's rings, as seen from the centre of the earth
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SaturnRings {
+    /// saturnicentric latitude of the earth, referred to the plane of the
+    /// rings (B), in radians (positive when the northern side is visible)
+    pub earth_latitude: f64,
+    /// saturnicentric latitude of the sun, referred to the plane of the
+    /// rings (B′), in radians
+    pub sun_latitude: f64,
+    /// difference between the saturnicentric longitudes of the sun and
+    /// earth, measured in the plane of the rings (ΔU), in radians
+    pub longitude_difference: f64,
+    /// position angle of the northern semiminor axis of the rings (P),
+    /// eastward from north, in radians
+    pub position_angle: f64,
+    /// major axis of the outer edge of the outer ring (a), in radians
+    pub major_axis: f64,
+    /// minor axis of the outer edge of the outer ring (b), in radians
+    pub minor_axis: f64,
+}
+
+impl SaturnRings {
+    /// The major and minor axes of an edge of the rings, in radians
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use meealgi::planet::{saturn_rings, RingEdge};
+    ///
+    /// // Meeus example 45.a: 1992 December 16, 0h TD
+    /// let rings = saturn_rings(2_448_972.5);
+    /// let (major, minor) = rings.axes(RingEdge::InnerRingInner);
+    /// assert_eq!(23.85, (major.to_degrees() * 360_000_f64).round() / 100_f64);
+    /// assert_eq!(6.75, (minor.to_degrees() * 360_000_f64).round() / 100_f64);
+    /// ```
+    pub fn axes(&self, edge: RingEdge) -> (f64, f64) {
+        (self.major_axis * edge.ratio(), self.minor_axis * edge.ratio())
+    }
+}
+
+/// Calculates the inclination (i) and ascending node (Ω) of the plane of
+/// Saturn's rings, referred to the ecliptic and mean equinox of date, in
+/// radians
+///
+/// Obtained from Meeus (ch. 45)
+fn ring_plane(jce: f64) -> (f64, f64) {
+    let inclination = (28.075_216_f64 - 0.012_998_f64 * jce + 0.000_004_f64 * jce.powi(2))
+        .to_radians();
+    let node = (169.508_47_f64 + 1.394_681_f64 * jce + 0.000_412_f64 * jce.powi(2))
+        .to_radians();
+
+    (inclination, node)
+}
+
+/// Calculates the saturnicentric latitude (B) and longitude (U) of a
+/// direction, referred to the plane of the rings
+fn ring_coordinates(direction: &Ecliptic, inclination: f64, node: f64) -> (f64, f64) {
+    let Ecliptic { longitude, latitude } = *direction;
+
+    let ring_latitude = (inclination.sin() * latitude.cos() * (longitude - node).sin() -
+                             inclination.cos() * latitude.sin())
+        .asin();
+    let ring_longitude = (inclination.sin() * latitude.sin() +
+                              inclination.cos() * latitude.cos() * (longitude - node).sin())
+        .atan2(latitude.cos() * (longitude - node).cos());
+
+    (ring_latitude, ring_longitude)
+}
+
+/// Calculates the saturnicentric latitude of the earth referred to the
+/// plane of the rings (B), and the difference between the saturnicentric
+/// longitudes of the sun and earth measured in that plane (ΔU), in radians
+///
+/// Obtained from Meeus (ch. 45)
+pub(crate) fn ring_tilt(jde: f64) -> (f64, f64) {
+    let rings = saturn_rings(jde);
+
+    (rings.earth_latitude, rings.longitude_difference)
+}
+
+/// Calculates the appearance of Saturn's rings for a given Julian Ephemeris
+/// Day
+///
+/// Obtained from Meeus (ch. 45)
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::planet::saturn_rings;
+///
+/// // Meeus example 45.a: 1992 December 16, 0h TD
+/// let rings = saturn_rings(2_448_972.5);
+/// assert_eq!(16.442, (rings.earth_latitude.to_degrees() * 1_000_f64).round() / 1_000_f64);
+/// assert_eq!(6.741, (rings.position_angle.to_degrees() * 1_000_f64).round() / 1_000_f64);
+/// ```
+pub fn saturn_rings(jde: f64) -> SaturnRings {
+    let jce = jul_to_julc(jde);
+    let jme = julc_to_julm(jce);
+    let (inclination, node) = ring_plane(jce);
+
+    let (geocentric, distance, light_time, sun_distance, _) =
+        geometric_position(Planet::Saturn, jde);
+    let lambda = geocentric.longitude;
+    let beta = geocentric.latitude;
+
+    // Saturn's heliocentric position when the light left it, corrected for
+    // the sun's aberration as seen from Saturn
+    let tau = julc_to_julm(jul_to_julc(jde - light_time));
+    let l = heliocentric_longitude(Planet::Saturn, tau);
+    let b = heliocentric_latitude(Planet::Saturn, tau);
+    let orbit_node = (113.665_5_f64 + 0.877_1_f64 * jce).to_radians();
+    let sun = Ecliptic {
+        longitude: l - (0.017_59_f64 / sun_distance).to_radians(),
+        latitude: b - (0.000_764_f64 * (l - orbit_node).cos() / sun_distance).to_radians(),
+    };
+
+    let (earth_latitude, earth_longitude) = ring_coordinates(&geocentric, inclination, node);
+    let (sun_latitude, sun_longitude) = ring_coordinates(&sun, inclination, node);
+    let difference = sun_longitude - earth_longitude;
+
+    // the apparent positions of Saturn and the pole of the rings, with
+    // aberration and nutation
+    let earth_heliocentric = earth::heliocentric_longitude(jme);
+    let aberration = 0.005_693_f64.to_radians();
+    let nutation_longitude = earth::nutation_longitude(jce);
+    let apparent = Ecliptic {
+        longitude: lambda + aberration * (earth_heliocentric - lambda).cos() / beta.cos() +
+            nutation_longitude,
+        latitude: beta + aberration * (earth_heliocentric - lambda).sin() * beta.sin(),
+    };
+    let pole = Ecliptic {
+        longitude: node - FRAC_PI_2 + nutation_longitude,
+        latitude: FRAC_PI_2 - inclination,
+    };
+
+    let obliquity = earth::true_obliquity(
+        earth::mean_obliquity(jme),
+        earth::nutation_obliquity(jce),
+    );
+    let pole = pole.to_equatorial(obliquity);
+    let saturn = apparent.to_equatorial(obliquity);
+    let ra_difference = pole.right_ascension - saturn.right_ascension;
+    let position_angle = (pole.declination.cos() * ra_difference.sin()).atan2(
+        pole.declination.sin() * saturn.declination.cos() -
+            pole.declination.cos() * saturn.declination.sin() * ra_difference.cos(),
+    );
+
+    let major_axis = (RING_MAJOR_AXIS / 3_600_f64 / distance).to_radians();
+
+    SaturnRings {
+        earth_latitude,
+        sun_latitude,
+        longitude_difference: limit_signed_radians(difference).abs(),
+        position_angle: limit_radians(position_angle),
+        major_axis,
+        minor_axis: major_axis * earth_latitude.sin().abs(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use planet::*;
+
+    #[test]
+    fn meeus_example_45_a() {
+        // Saturn on 1992 December 16, 0h TD
+        let rings = saturn_rings(2_448_972.5_f64);
+
+        assert!((rings.earth_latitude.to_degrees() - 16.442_f64).abs() < 0.001);
+        assert!((rings.sun_latitude.to_degrees() - 14.679_f64).abs() < 0.001);
+        assert!((rings.longitude_difference.to_degrees() - 4.198_f64).abs() < 0.001);
+        assert!((rings.position_angle.to_degrees() - 6.741_f64).abs() < 0.001);
+        assert!((rings.major_axis.to_degrees() * 3_600_f64 - 35.87_f64).abs() < 0.01);
+        assert!((rings.minor_axis.to_degrees() * 3_600_f64 - 10.15_f64).abs() < 0.01);
+    }
+
+    #[test]
+    fn rings_edge_on_in_2009() {
+        // the earth crossed the ring plane on 2009 September 4
+        let rings = saturn_rings(2_455_078.5_f64);
+
+        assert!(rings.earth_latitude.to_degrees().abs() < 0.05);
+        assert!(rings.minor_axis.to_degrees() * 3_600_f64 < 0.05);
+    }
+}
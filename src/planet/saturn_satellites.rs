@@ -0,0 +1,595 @@
+use planet::{geometric_position, Planet};
+use utils::coordinates::Ecliptic;
+
+/// Julian Ephemeris Day of the equinox of B1950.0, to which the satellites'
+/// orbits are referred
+const B1950: f64 = 2_433_282.423_5_f64;
+/// Longitude of the ascending node of Saturn's equator on the ecliptic of
+/// B1950.0, in degrees
+const EQUATOR_NODE: f64 = 168.811_2_f64;
+/// Inclination of Saturn's equator to the ecliptic of B1950.0, in degrees
+const EQUATOR_INCLINATION: f64 = 28.081_7_f64;
+
+/// One of Saturn's eight major satellites
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum SaturnSatellite {
+    /// Mimas (satellite I)
+    Mimas,
+    /// Enceladus (satellite II)
+    Enceladus,
+    /// Tethys (satellite III)
+    Tethys,
+    /// Dione (satellite IV)
+    Dione,
+    /// Rhea (satellite V)
+    Rhea,
+    /// Titan (satellite VI)
+    Titan,
+    /// Hyperion (satellite VII)
+    Hyperion,
+    /// Iapetus (satellite VIII)
+    Iapetus,
+}
+
+impl SaturnSatellite {
+    /// All of the major satellites, in order of distance from Saturn
+    pub const ALL: [SaturnSatellite; 8] = [
+        SaturnSatellite::Mimas,
+        SaturnSatellite::Enceladus,
+        SaturnSatellite::Tethys,
+        SaturnSatellite::Dione,
+        SaturnSatellite::Rhea,
+        SaturnSatellite::Titan,
+        SaturnSatellite::Hyperion,
+        SaturnSatellite::Iapetus,
+    ];
+
+    /// Meeus' constant (K) for the difference in light-time across the
+    /// satellite's orbit
+    fn light_time_constant(self) -> f64 {
+        match self {
+            SaturnSatellite::Mimas => 20_947_f64,
+            SaturnSatellite::Enceladus => 23_715_f64,
+            SaturnSatellite::Tethys => 26_382_f64,
+            SaturnSatellite::Dione => 29_876_f64,
+            SaturnSatellite::Rhea => 35_313_f64,
+            SaturnSatellite::Titan => 53_800_f64,
+            SaturnSatellite::Hyperion => 59_222_f64,
+            SaturnSatellite::Iapetus => 91_820_f64,
+        }
+    }
+}
+
+/// The apparent position of one of Saturn's satellites relative to Saturn,
+/// in units of Saturn's equatorial radius
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SaturnSatellitePosition {
+    /// the satellite
+    pub satellite: SaturnSatellite,
+    /// distance along Saturn's equator, positive to the west (X)
+    pub x: f64,
+    /// distance along Saturn's axis, positive to the north (Y)
+    pub y: f64,
+    /// distance along the line of sight, positive when the satellite is
+    /// farther from the earth than Saturn (Z)
+    pub z: f64,
+}
+
+/// A satellite's orbit: its longitude (λ), the inclination (γ) and node (Ω)
+/// of its orbit on Saturn's equator, and its distance from Saturn (r), in
+/// radians and Saturn radii
+struct SatelliteOrbit {
+    longitude: f64,
+    inclination: f64,
+    node: f64,
+    radius: f64,
+}
+
+/// The time arguments shared by the satellites' theories
+struct Arguments {
+    t1: f64,
+    t2: f64,
+    t4: f64,
+    t6: f64,
+    t7: f64,
+    t8: f64,
+    t9: f64,
+    t10: f64,
+    t11: f64,
+    w: [f64; 9],
+    e1: f64,
+}
+
+impl Arguments {
+    fn new(jde: f64) -> Arguments {
+        let t1 = jde - 2_411_093_f64;
+        let t2 = t1 / 365.25_f64;
+        let t3 = (jde - 2_433_282.423_f64) / 365.25_f64 + 1_950_f64;
+        let t4 = jde - 2_411_368_f64;
+        let t5 = t4 / 365.25_f64;
+        let t6 = jde - 2_415_020_f64;
+        let t7 = t6 / 36_525_f64;
+        let t8 = t6 / 365.25_f64;
+        let t9 = (jde - 2_442_000.5_f64) / 365.25_f64;
+        let t10 = jde - 2_409_786_f64;
+        let t11 = t10 / 36_525_f64;
+
+        let w = [
+            5.095_f64 * (t3 - 1_866.39_f64),
+            74.4_f64 + 32.39_f64 * t2,
+            134.3_f64 + 92.62_f64 * t2,
+            42_f64 - 0.511_8_f64 * t5,
+            276.59_f64 + 0.511_8_f64 * t5,
+            267.263_5_f64 + 1_222.113_6_f64 * t7,
+            175.476_2_f64 + 1_221.551_5_f64 * t7,
+            2.489_1_f64 + 0.002_435_f64 * t7,
+            113.35_f64 - 0.259_7_f64 * t7,
+        ];
+
+        Arguments {
+            t1,
+            t2,
+            t4,
+            t6,
+            t7,
+            t8,
+            t9,
+            t10,
+            t11,
+            w: [
+                w[0].to_radians(),
+                w[1].to_radians(),
+                w[2].to_radians(),
+                w[3].to_radians(),
+                w[4].to_radians(),
+                w[5].to_radians(),
+                w[6].to_radians(),
+                w[7].to_radians(),
+                w[8].to_radians(),
+            ],
+            e1: 0.055_89_f64 - 0.000_346_f64 * t7,
+        }
+    }
+}
+
+/// Precesses ecliptic coordinates from the mean equinox of one Julian
+/// Ephemeris Day to another
+///
+/// Obtained from Meeus (ch. 21)
+fn precess(position: &Ecliptic, from_jde: f64, to_jde: f64) -> Ecliptic {
+    let big_t = (from_jde - 2_451_545_f64) / 36_525_f64;
+    let t = (to_jde - from_jde) / 36_525_f64;
+
+    let eta = ((47.002_9_f64 - 0.066_03_f64 * big_t + 0.000_598_f64 * big_t.powi(2)) * t +
+                   (-0.033_02_f64 + 0.000_598_f64 * big_t) * t.powi(2) +
+                   0.000_060_f64 * t.powi(3)) / 3_600_f64;
+    let pi = 174.876_383_889_f64 +
+        (3_289.478_9_f64 * big_t + 0.606_22_f64 * big_t.powi(2) -
+             (869.808_9_f64 + 0.504_91_f64 * big_t) * t + 0.035_36_f64 * t.powi(2)) /
+            3_600_f64;
+    let p = ((5_029.096_6_f64 + 2.222_26_f64 * big_t - 0.000_042_f64 * big_t.powi(2)) * t +
+                 (1.111_13_f64 - 0.000_042_f64 * big_t) * t.powi(2) -
+                 0.000_006_f64 * t.powi(3)) / 3_600_f64;
+    let (eta, pi, p) = (eta.to_radians(), pi.to_radians(), p.to_radians());
+
+    let Ecliptic { longitude, latitude } = *position;
+    let a = eta.cos() * latitude.cos() * (pi - longitude).sin() - eta.sin() * latitude.sin();
+    let b = latitude.cos() * (pi - longitude).cos();
+    let c = eta.cos() * latitude.sin() + eta.sin() * latitude.cos() * (pi - longitude).sin();
+
+    Ecliptic {
+        longitude: p + pi - a.atan2(b),
+        latitude: c.asin(),
+    }
+}
+
+/// Converts the elements of an orbit referred to the ecliptic of B1950.0
+/// into the satellite's position on Saturn's equator
+fn from_elements(
+    eccentricity: f64,
+    semimajor_axis: f64,
+    node: f64,
+    inclination: f64,
+    mean_longitude: f64,
+    perisaturnium: f64,
+) -> SatelliteOrbit {
+    let e = eccentricity;
+    let m = mean_longitude - perisaturnium;
+    let centre = (2_f64 * e - 0.25_f64 * e.powi(3) + 0.052_083_333_f64 * e.powi(5)) * m.sin() +
+        (1.25_f64 * e.powi(2) - 0.458_333_333_f64 * e.powi(4)) * (2_f64 * m).sin() +
+        (1.083_333_333_f64 * e.powi(3) - 0.671_875_f64 * e.powi(5)) * (3_f64 * m).sin() +
+        1.072_917_f64 * e.powi(4) * (4_f64 * m).sin() +
+        1.142_708_f64 * e.powi(5) * (5_f64 * m).sin();
+    let radius = semimajor_axis * (1_f64 - e.powi(2)) / (1_f64 + e * (m + centre).cos());
+
+    let (sin_i0, cos_i0) = EQUATOR_INCLINATION.to_radians().sin_cos();
+    let g = node - EQUATOR_NODE.to_radians();
+    let a1 = inclination.sin() * g.sin();
+    let a2 = cos_i0 * inclination.sin() * g.cos() - sin_i0 * inclination.cos();
+    let u = a1.atan2(a2);
+    let h = cos_i0 * inclination.sin() - sin_i0 * inclination.cos() * g.cos();
+    let psi = (sin_i0 * g.sin()).atan2(h);
+
+    SatelliteOrbit {
+        longitude: mean_longitude + centre + u - g - psi,
+        inclination: a1.hypot(a2).asin(),
+        node: EQUATOR_NODE.to_radians() + u,
+        radius,
+    }
+}
+
+fn mimas(arguments: &Arguments) -> SatelliteOrbit {
+    let w0 = arguments.w[0];
+    let l = (127.64_f64 + 381.994_497_f64 * arguments.t1 - 43.57_f64 * w0.sin() -
+                 0.720_f64 * (3_f64 * w0).sin() - 0.021_44_f64 * (5_f64 * w0).sin())
+        .to_radians();
+    let p = (106.1_f64 + 365.549_f64 * arguments.t2).to_radians();
+    let m = l - p;
+    let centre = (2.182_87_f64 * m.sin() + 0.025_988_f64 * (2_f64 * m).sin() +
+                      0.000_43_f64 * (3_f64 * m).sin())
+        .to_radians();
+
+    SatelliteOrbit {
+        longitude: l + centre,
+        inclination: 1.563_f64.to_radians(),
+        node: (54.5_f64 - 365.072_f64 * arguments.t2).to_radians(),
+        radius: 3.068_79_f64 / (1_f64 + 0.019_05_f64 * (m + centre).cos()),
+    }
+}
+
+fn enceladus(arguments: &Arguments) -> SatelliteOrbit {
+    let l = (200.317_f64 + 262.731_900_2_f64 * arguments.t1 +
+                 0.256_67_f64 * arguments.w[1].sin() + 0.208_83_f64 * arguments.w[2].sin())
+        .to_radians();
+    let p = (309.107_f64 + 123.441_21_f64 * arguments.t2).to_radians();
+    let m = l - p;
+    let centre = (0.555_77_f64 * m.sin() + 0.001_68_f64 * (2_f64 * m).sin()).to_radians();
+
+    SatelliteOrbit {
+        longitude: l + centre,
+        inclination: 0.026_2_f64.to_radians(),
+        node: (348_f64 - 151.95_f64 * arguments.t2).to_radians(),
+        radius: 3.941_18_f64 / (1_f64 + 0.004_85_f64 * (m + centre).cos()),
+    }
+}
+
+fn tethys(arguments: &Arguments) -> SatelliteOrbit {
+    let w0 = arguments.w[0];
+
+    SatelliteOrbit {
+        longitude: (285.306_f64 + 190.697_912_26_f64 * arguments.t1 + 2.063_f64 * w0.sin() +
+                        0.034_09_f64 * (3_f64 * w0).sin() +
+                        0.001_015_f64 * (5_f64 * w0).sin())
+            .to_radians(),
+        inclination: 1.097_6_f64.to_radians(),
+        node: (111.33_f64 - 72.244_1_f64 * arguments.t2).to_radians(),
+        radius: 4.880_998_f64,
+    }
+}
+
+fn dione(arguments: &Arguments) -> SatelliteOrbit {
+    let l = (254.712_f64 + 131.534_931_93_f64 * arguments.t1 -
+                 0.021_5_f64 * arguments.w[1].sin() - 0.017_33_f64 * arguments.w[2].sin())
+        .to_radians();
+    let p = (174.8_f64 + 30.82_f64 * arguments.t2).to_radians();
+    let m = l - p;
+    let centre = (0.247_17_f64 * m.sin() + 0.000_33_f64 * (2_f64 * m).sin()).to_radians();
+
+    SatelliteOrbit {
+        longitude: l + centre,
+        inclination: 0.013_9_f64.to_radians(),
+        node: (232_f64 - 30.27_f64 * arguments.t2).to_radians(),
+        radius: 6.248_71_f64 / (1_f64 + 0.002_157_f64 * (m + centre).cos()),
+    }
+}
+
+fn rhea(arguments: &Arguments) -> SatelliteOrbit {
+    let w = &arguments.w;
+    let p_prime = (342.7_f64 + 10.057_f64 * arguments.t2).to_radians();
+    let a1 = 0.000_265_f64 * p_prime.sin() + 0.001_f64 * w[4].sin();
+    let a2 = 0.000_265_f64 * p_prime.cos() + 0.001_f64 * w[4].cos();
+    let n = (345_f64 - 10.057_f64 * arguments.t2).to_radians();
+    let longitude = 359.244_f64 + 79.690_047_2_f64 * arguments.t1 + 0.086_754_f64 * n.sin();
+    let inclination = 28.036_2_f64 + 0.346_898_f64 * n.cos() + 0.019_30_f64 * w[3].cos();
+    let node = 168.803_4_f64 + 0.736_936_f64 * n.sin() + 0.041_f64 * w[3].sin();
+
+    from_elements(
+        a1.hypot(a2),
+        8.725_924_f64,
+        node.to_radians(),
+        inclination.to_radians(),
+        longitude.to_radians(),
+        a1.atan2(a2),
+    )
+}
+
+fn titan(arguments: &Arguments) -> SatelliteOrbit {
+    let w = &arguments.w;
+    let l = (261.158_2_f64 + 22.576_978_55_f64 * arguments.t4 + 0.074_025_f64 * w[3].sin())
+        .to_radians();
+    let i_prime = (27.451_41_f64 + 0.295_999_f64 * w[3].cos()).to_radians();
+    let node_prime = (168.669_25_f64 + 0.628_808_f64 * w[3].sin()).to_radians();
+    let a1 = w[7].sin() * (node_prime - w[8]).sin();
+    let a2 = w[7].cos() * i_prime.sin() - w[7].sin() * i_prime.cos() * (node_prime - w[8]).cos();
+    let g0 = 102.862_3_f64.to_radians();
+    let psi = a1.atan2(a2);
+    let s = a1.hypot(a2);
+
+    // three iterations are enough for the longitude of perisaturnium
+    let mut g = w[4] - node_prime - psi;
+    let mut perisaturnium = 0_f64;
+    for _ in 0..3 {
+        perisaturnium = w[4] + 0.375_15_f64.to_radians() * ((2_f64 * g).sin() - (2_f64 * g0).sin());
+        g = perisaturnium - node_prime - psi;
+    }
+
+    let e_prime = 0.029_092_f64 + 0.000_190_48_f64 * ((2_f64 * g).cos() - (2_f64 * g0).cos());
+    let q = 2_f64 * (w[5] - perisaturnium);
+    let b1 = i_prime.sin() * (node_prime - w[8]).sin();
+    let b2 = w[7].cos() * i_prime.sin() * (node_prime - w[8]).cos() - w[7].sin() * i_prime.cos();
+    let theta = b1.atan2(b2) + w[8];
+    let u = 2_f64 * (w[5] - theta) + psi;
+    let h = 0.937_5_f64 * e_prime.powi(2) * q.sin() +
+        0.187_5_f64 * s.powi(2) * (2_f64 * (w[5] - theta)).sin();
+    let e1 = arguments.e1;
+    let longitude = l -
+        0.254_744_f64.to_radians() *
+            (e1 * w[6].sin() + 0.75_f64 * e1.powi(2) * (2_f64 * w[6]).sin() + h);
+
+    from_elements(
+        e_prime + 0.002_778_797_f64 * e_prime * q.cos(),
+        20.216_193_f64,
+        node_prime + 0.031_843_f64.to_radians() * s * u.sin() / i_prime.sin(),
+        i_prime + 0.031_843_f64.to_radians() * s * u.cos(),
+        longitude,
+        perisaturnium + 0.159_215_f64.to_radians() * q.sin(),
+    )
+}
+
+fn hyperion(arguments: &Arguments) -> SatelliteOrbit {
+    let (t6, t8) = (arguments.t6, arguments.t8);
+    let eta = (92.39_f64 + 0.562_107_1_f64 * t6).to_radians();
+    let zeta = (148.19_f64 - 19.18_f64 * t8).to_radians();
+    let theta = (184.8_f64 - 35.41_f64 * arguments.t9).to_radians();
+    let theta_prime = theta - 7.5_f64.to_radians();
+    let a_s = (176_f64 + 12.22_f64 * t8).to_radians();
+    let b_s = (8_f64 + 24.44_f64 * t8).to_radians();
+    let c_s = b_s + 5_f64.to_radians();
+    let perisaturnium = 69.898_f64 - 18.670_88_f64 * t8;
+    let phi = 2_f64 * (perisaturnium.to_radians() - arguments.w[5]);
+    let chi = (94.9_f64 - 2.292_f64 * t8).to_radians();
+
+    let semimajor_axis = 24.506_01_f64 - 0.086_86_f64 * eta.cos() -
+        0.001_66_f64 * (zeta + eta).cos() + 0.001_75_f64 * (zeta - eta).cos();
+    let eccentricity = 0.103_458_f64 - 0.004_099_f64 * eta.cos() -
+        0.000_167_f64 * (zeta + eta).cos() + 0.000_235_f64 * (zeta - eta).cos() +
+        0.023_03_f64 * zeta.cos() - 0.002_12_f64 * (2_f64 * zeta).cos() +
+        0.000_151_f64 * (3_f64 * zeta).cos() + 0.000_13_f64 * phi.cos();
+    let p = perisaturnium + 0.156_48_f64 * chi.sin() - 0.445_7_f64 * eta.sin() -
+        0.265_7_f64 * (zeta + eta).sin() - 0.357_3_f64 * (zeta - eta).sin() -
+        12.872_f64 * zeta.sin() + 1.668_f64 * (2_f64 * zeta).sin() -
+        0.241_9_f64 * (3_f64 * zeta).sin() - 0.07_f64 * phi.sin();
+    let longitude = 177.047_f64 + 16.919_938_29_f64 * t6 + 0.156_48_f64 * chi.sin() +
+        9.142_f64 * eta.sin() + 0.007_f64 * (2_f64 * eta).sin() -
+        0.014_f64 * (3_f64 * eta).sin() + 0.227_5_f64 * (zeta + eta).sin() +
+        0.211_2_f64 * (zeta - eta).sin() - 0.26_f64 * zeta.sin() -
+        0.009_8_f64 * (2_f64 * zeta).sin() - 0.013_f64 * a_s.sin() + 0.017_f64 * b_s.sin() -
+        0.030_3_f64 * phi.sin();
+    let inclination = 27.334_7_f64 + 0.643_486_f64 * chi.cos() +
+        0.315_f64 * arguments.w[3].cos() + 0.018_f64 * theta.cos() - 0.018_f64 * c_s.cos();
+    let node = 168.681_2_f64 + 1.401_36_f64 * chi.cos() + 0.685_99_f64 * arguments.w[3].sin() -
+        0.039_2_f64 * c_s.sin() + 0.036_6_f64 * theta_prime.sin();
+
+    from_elements(
+        eccentricity,
+        semimajor_axis,
+        node.to_radians(),
+        inclination.to_radians(),
+        longitude.to_radians(),
+        p.to_radians(),
+    )
+}
+
+fn iapetus(arguments: &Arguments) -> SatelliteOrbit {
+    let w = &arguments.w;
+    let (t7, t11) = (arguments.t7, arguments.t11);
+    let l_titan = (261.158_2_f64 + 22.576_978_55_f64 * arguments.t4).to_radians();
+    let perisaturnium_sun = (91.796_f64 + 0.562_f64 * t7).to_radians();
+    let psi = (4.367_f64 - 0.195_f64 * t7).to_radians();
+    let theta = (146.819_f64 - 3.198_f64 * t7).to_radians();
+    let phi = (60.470_f64 + 1.521_f64 * t7).to_radians();
+    let big_phi = (205.055_f64 - 2.091_f64 * t7).to_radians();
+    let e_prime = 0.028_298_f64 + 0.001_156_f64 * t11;
+    let perisaturnium = (352.91_f64 + 11.71_f64 * t11).to_radians();
+    let mu = (76.385_2_f64 + 4.537_951_25_f64 * arguments.t10).to_radians();
+    let i_prime = (18.460_2_f64 - 0.951_8_f64 * t11 - 0.072_f64 * t11.powi(2) +
+                       0.005_4_f64 * t11.powi(3))
+        .to_radians();
+    let node_prime = (143.198_f64 - 3.919_f64 * t11 + 0.116_f64 * t11.powi(2) +
+                          0.008_f64 * t11.powi(3))
+        .to_radians();
+
+    let l = mu - perisaturnium;
+    let g = perisaturnium - node_prime - psi;
+    let g1 = perisaturnium - node_prime - phi;
+    let l_s = w[5] - perisaturnium_sun;
+    let g_s = perisaturnium_sun - theta;
+    let l_t = l_titan - w[4];
+    let g_t = w[4] - big_phi;
+    let u1 = 2_f64 * (l + g - l_s - g_s);
+    let u2 = l + g1 - l_t - g_t;
+    let u3 = l + 2_f64 * (g - l_s - g_s);
+    let u4 = l_t + g_t - g1;
+    let u5 = 2_f64 * (l_s + g_s);
+
+    let semimajor_axis = 58.935_028_f64 + 0.004_638_f64 * u1.cos() + 0.058_222_f64 * u2.cos();
+    let eccentricity = e_prime - 0.001_409_7_f64 * (g1 - g_t).cos() +
+        0.000_373_3_f64 * (u5 - 2_f64 * g).cos() + 0.000_118_f64 * u3.cos() +
+        0.000_240_8_f64 * l.cos() + 0.000_284_9_f64 * (l + u2).cos() +
+        0.000_619_f64 * u4.cos();
+    let w_correction = 0.080_77_f64 * (g1 - g_t).sin() + 0.021_39_f64 * (u5 - 2_f64 * g).sin() -
+        0.006_76_f64 * u3.sin() + 0.013_8_f64 * l.sin() + 0.016_32_f64 * (l + u2).sin() +
+        0.035_47_f64 * u4.sin();
+    let longitude = mu +
+        (-0.042_99_f64 * u2.sin() - 0.007_89_f64 * u1.sin() - 0.063_12_f64 * l_s.sin() -
+             0.002_95_f64 * (2_f64 * l_s).sin() - 0.022_31_f64 * u5.sin() +
+             0.006_5_f64 * (u5 + psi).sin())
+            .to_radians();
+    let inclination = i_prime +
+        (0.042_04_f64 * (u5 + psi).cos() + 0.002_35_f64 * (l + g1 + l_t + g_t + phi).cos() +
+             0.003_6_f64 * (u2 + phi).cos())
+            .to_radians();
+    let node_correction = (0.042_04_f64 * (u5 + psi).sin() +
+                               0.002_35_f64 * (l + g1 + l_t + g_t + phi).sin() +
+                               0.003_58_f64 * (u2 + phi).sin())
+        .to_radians();
+
+    from_elements(
+        eccentricity,
+        semimajor_axis,
+        node_prime + node_correction / i_prime.sin(),
+        inclination,
+        longitude,
+        perisaturnium + (w_correction / e_prime).to_radians(),
+    )
+}
+
+/// Rotates a point from Saturn's equator (of B1950.0) into the frame of an
+/// observer in the direction of Saturn (λ0, β0), returning its coordinates
+/// across and along the line of sight
+fn project(x: f64, y: f64, z: f64, saturn: &Ecliptic) -> (f64, f64, f64) {
+    let (sin_i0, cos_i0) = EQUATOR_INCLINATION.to_radians().sin_cos();
+    let (sin_node, cos_node) = EQUATOR_NODE.to_radians().sin_cos();
+    let (sin_lambda, cos_lambda) = saturn.longitude.sin_cos();
+    let (sin_beta, cos_beta) = saturn.latitude.sin_cos();
+
+    let a1 = x;
+    let b1 = cos_i0 * y - sin_i0 * z;
+    let c1 = sin_i0 * y + cos_i0 * z;
+    let a2 = cos_node * a1 - sin_node * b1;
+    let b2 = sin_node * a1 + cos_node * b1;
+    let a3 = a2 * sin_lambda - b2 * cos_lambda;
+    let b3 = a2 * cos_lambda + b2 * sin_lambda;
+
+    (a3, b3 * cos_beta + c1 * sin_beta, c1 * cos_beta - b3 * sin_beta)
+}
+
+/// Calculates the apparent positions of Saturn's eight major satellites
+/// relative to Saturn for a given Julian Ephemeris Day, in order of
+/// distance from Saturn
+///
+/// Obtained from Meeus (ch. 46)
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::planet::saturn_satellite_positions;
+///
+/// // Meeus example 46.a: 1999 September 18, 0h UT
+/// let positions = saturn_satellite_positions(2_451_439.500_74);
+/// let titan = positions[5];
+/// assert_eq!(14.57, (titan.x * 100_f64).round() / 100_f64);
+/// assert_eq!(4.74, (titan.y * 100_f64).round() / 100_f64);
+/// ```
+pub fn saturn_satellite_positions(jde: f64) -> [SaturnSatellitePosition; 8] {
+    let (geocentric, distance, light_time, _, _) = geometric_position(Planet::Saturn, jde);
+    let saturn = precess(&geocentric, jde, B1950);
+
+    // the satellites are seen where they were when the light left them
+    let arguments = Arguments::new(jde - light_time);
+    let orbits = [
+        mimas(&arguments),
+        enceladus(&arguments),
+        tethys(&arguments),
+        dione(&arguments),
+        rhea(&arguments),
+        titan(&arguments),
+        hyperion(&arguments),
+        iapetus(&arguments),
+    ];
+
+    // the direction of Saturn's north pole fixes the orientation of the
+    // axes, by way of a fictitious satellite above it
+    let (a_pole, _, c_pole) = project(0_f64, 0_f64, 1_f64, &saturn);
+    let d = a_pole.atan2(c_pole);
+
+    let position = |index: usize| {
+        let orbit = &orbits[index];
+        let satellite = SaturnSatellite::ALL[index];
+        let u = orbit.longitude - orbit.node;
+        let w = orbit.node - EQUATOR_NODE.to_radians();
+
+        let (a, b, c) = project(
+            orbit.radius * (u.cos() * w.cos() - u.sin() * orbit.inclination.cos() * w.sin()),
+            orbit.radius * (u.sin() * w.cos() * orbit.inclination.cos() + u.cos() * w.sin()),
+            orbit.radius * u.sin() * orbit.inclination.sin(),
+            &saturn,
+        );
+        let x = a * d.cos() - c * d.sin();
+        let y = a * d.sin() + c * d.cos();
+
+        // correct for the difference in light-time across the orbit, and
+        // for perspective
+        let x = x +
+            b.abs() / satellite.light_time_constant() *
+                (1_f64 - (x / orbit.radius).powi(2)).max(0_f64).sqrt();
+        let perspective = distance / (distance + b / 2_475_f64);
+
+        SaturnSatellitePosition {
+            satellite,
+            x: x * perspective,
+            y: y * perspective,
+            z: b,
+        }
+    };
+
+    [
+        position(0),
+        position(1),
+        position(2),
+        position(3),
+        position(4),
+        position(5),
+        position(6),
+        position(7),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use planet::*;
+
+    #[test]
+    #[allow(clippy::approx_constant)]
+    fn meeus_example_46_a() {
+        // 1999 September 18, 0h UT
+        let positions = saturn_satellite_positions(2_451_439.500_74_f64);
+        let expected = [
+            (3.102_f64, -0.204_f64),
+            (3.823, 0.318),
+            (4.027, -1.061),
+            (-5.365, -1.148),
+            (-0.972, -3.136),
+            (14.568, 4.738),
+            (-18.001, -5.328),
+            (-48.760, 4.137),
+        ];
+
+        for (position, &(x, y)) in positions.iter().zip(&expected) {
+            assert!((position.x - x).abs() < 0.002, "{:?}", position);
+            assert!((position.y - y).abs() < 0.002, "{:?}", position);
+        }
+    }
+
+    #[test]
+    fn titan_returns_after_a_revolution() {
+        // Titan's synodic period is about 15.95 days
+        let jde = 2_458_000.5_f64;
+        let before = saturn_satellite_positions(jde)[5];
+        let after = saturn_satellite_positions(jde + 15.95_f64)[5];
+
+        assert_eq!(SaturnSatellite::Titan, before.satellite);
+        assert!((before.x - after.x).abs() < 0.3, "{:?} {:?}", before, after);
+        assert!(before.x.hypot(before.z) < 21_f64);
+    }
+}
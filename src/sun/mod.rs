@@ -0,0 +1,7 @@
+//! Calculations involving the sun, such as its apparent position and the
+//! instants of the equinoxes and solstices
+mod position;
+mod seasons;
+
+pub use self::position::*;
+pub use self::seasons::*;
@@ -0,0 +1,72 @@
+use earth;
+use time::{jul_to_julc, julc_to_julm};
+use utils::coordinates::{Ecliptic, Equatorial};
+
+/// Calculates the apparent geocentric longitude of the sun, in radians,
+/// for a given Julian Ephemeris Day
+///
+/// Combines the earth's heliocentric position with nutation and
+/// aberration, as per the NREL SPA report.
+///
+/// # Examples:
+/// ```
+/// use meealgi::sun::apparent_longitude;
+///
+/// // NREL SPA example (A.5)
+/// let lambda = apparent_longitude(2_452_930.313_623_f64);
+/// assert_eq!(204.008_55, (lambda.to_degrees() * 100_000_f64).round() / 100_000_f64);
+/// ```
+pub fn apparent_longitude(jde: f64) -> f64 {
+    let jce = jul_to_julc(jde);
+    let jme = julc_to_julm(jce);
+
+    earth::apparent_sun_longitude(
+        earth::geocentric_longitude(earth::heliocentric_longitude(jme)),
+        earth::nutation_longitude(jce),
+        earth::aberration_correction(earth::radius_vec(jme)),
+    )
+}
+
+/// Calculates the distance between the earth and sun, in AU, for a given
+/// Julian Ephemeris Day
+///
+/// # Examples:
+/// ```
+/// use meealgi::sun::distance;
+///
+/// // NREL SPA example (A.5)
+/// let r = distance(2_452_930.313_623_f64);
+/// assert_eq!(0.996_542, (r * 1_000_000_f64).round() / 1_000_000_f64);
+/// ```
+pub fn distance(jde: f64) -> f64 {
+    earth::radius_vec(julc_to_julm(jul_to_julc(jde)))
+}
+
+/// Calculates the apparent geocentric equatorial position of the sun for a
+/// given Julian Ephemeris Day
+///
+/// # Examples:
+/// ```
+/// use meealgi::sun::apparent_equatorial;
+///
+/// // NREL SPA example (A.5)
+/// let position = apparent_equatorial(2_452_930.313_623_f64);
+/// let alpha = position.right_ascension.to_degrees();
+/// let delta = position.declination.to_degrees();
+/// assert_eq!(202.227_41, (alpha * 100_000_f64).round() / 100_000_f64);
+/// assert_eq!(-9.314_34, (delta * 100_000_f64).round() / 100_000_f64);
+/// ```
+pub fn apparent_equatorial(jde: f64) -> Equatorial {
+    let jce = jul_to_julc(jde);
+    let jme = julc_to_julm(jce);
+
+    let position = Ecliptic {
+        longitude: apparent_longitude(jde),
+        latitude: earth::geocentric_latitude(earth::heliocentric_latitude(jme)),
+    };
+
+    position.to_equatorial(earth::true_obliquity(
+        earth::mean_obliquity(jme),
+        earth::nutation_obliquity(jce),
+    ))
+}
@@ -0,0 +1,194 @@
+use chrono::{DateTime, TimeZone};
+use std::f64::consts::FRAC_PI_2;
+use sun::apparent_longitude;
+use time::jul_to_dt;
+use time::nasa::jule_to_jul;
+
+/// An equinox or solstice
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Season {
+    /// The March (northward) equinox, when the sun's apparent longitude is 0°
+    MarchEquinox,
+    /// The June solstice, when the sun's apparent longitude is 90°
+    JuneSolstice,
+    /// The September (southward) equinox, when the sun's apparent longitude
+    /// is 180°
+    SeptemberEquinox,
+    /// The December solstice, when the sun's apparent longitude is 270°
+    DecemberSolstice,
+}
+
+impl Season {
+    /// The sun's apparent longitude at the equinox or solstice, in radians
+    pub fn longitude(self) -> f64 {
+        FRAC_PI_2 * f64::from(self.index())
+    }
+
+    fn index(self) -> u8 {
+        match self {
+            Season::MarchEquinox => 0,
+            Season::JuneSolstice => 1,
+            Season::SeptemberEquinox => 2,
+            Season::DecemberSolstice => 3,
+        }
+    }
+}
+
+/// Calculates the Julian Ephemeris Day of the mean equinox or solstice
+/// for a given year
+///
+/// Uses the polynomials from Meeus (tables 27.A and 27.B), which are
+/// accurate to within an hour or so between -1000 and 3000.
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::sun::{mean_season_jule, Season};
+///
+/// // Meeus example 27.a
+/// let jde = mean_season_jule(1962, Season::JuneSolstice);
+/// assert_eq!(2_437_837.385_89, (jde * 100_000_f64).round() / 100_000_f64);
+/// ```
+pub fn mean_season_jule(year: i32, season: Season) -> f64 {
+    let coefficients: [f64; 5] = if year < 1_000 {
+        match season {
+            Season::MarchEquinox => [1_721_139.291_89, 365_242.137_40, 0.061_34, 0.001_11, -0.000_71],
+            Season::JuneSolstice => [1_721_233.254_01, 365_241.725_62, -0.053_23, 0.009_07, 0.000_25],
+            Season::SeptemberEquinox => [1_721_325.704_55, 365_242.495_58, -0.116_77, -0.002_97, 0.000_74],
+            Season::DecemberSolstice => [1_721_414.399_87, 365_242.882_57, -0.007_69, -0.009_33, -0.000_06],
+        }
+    } else {
+        match season {
+            Season::MarchEquinox => [2_451_623.809_84, 365_242.374_04, 0.051_69, -0.004_11, -0.000_57],
+            Season::JuneSolstice => [2_451_716.567_67, 365_241.626_03, 0.003_25, 0.008_88, -0.000_30],
+            Season::SeptemberEquinox => [2_451_810.217_15, 365_242.017_67, -0.115_75, 0.003_37, 0.000_78],
+            Season::DecemberSolstice => [2_451_900.059_52, 365_242.740_49, -0.062_23, -0.008_23, 0.000_32],
+        }
+    };
+
+    let y = if year < 1_000 {
+        f64::from(year) / 1_000_f64
+    } else {
+        f64::from(year - 2_000) / 1_000_f64
+    };
+
+    coefficients.iter().rev().fold(0_f64, |curr, coeff| curr * y + coeff)
+}
+
+/// Calculates the Julian Ephemeris Day of an equinox or solstice for a
+/// given year
+///
+/// Starts from the mean instant, then refines it against the sun's
+/// apparent longitude until the correction is below a tenth of a second.
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::sun::{season_jule, Season};
+///
+/// // Meeus example 27.a: 1962 June 21, 21h24m42s TD (from VSOP87)
+/// let jde = season_jule(1962, Season::JuneSolstice);
+/// assert!((jde - 2_437_837.392_15_f64).abs() < 0.000_1_f64);
+/// ```
+pub fn season_jule(year: i32, season: Season) -> f64 {
+    const MAX_ITERATIONS: usize = 20;
+    const TOLERANCE: f64 = 0.000_001_f64;
+
+    let target = season.longitude();
+    let mut jde = mean_season_jule(year, season);
+
+    for _ in 0..MAX_ITERATIONS {
+        let correction = 58_f64 * (target - apparent_longitude(jde)).sin();
+        jde += correction;
+
+        if correction.abs() < TOLERANCE {
+            break;
+        }
+    }
+
+    jde
+}
+
+/// Calculates the Julian Day (UT) of an equinox or solstice for a given
+/// year
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::sun::{season_jul, Season};
+///
+/// let jd = season_jul(2017, Season::MarchEquinox);
+/// ```
+pub fn season_jul(year: i32, season: Season) -> f64 {
+    jule_to_jul(season_jule(year, season))
+}
+
+/// Calculates the instant of an equinox or solstice for a given year, in
+/// the given timezone
+///
+/// Returns `None` if the instant can't be represented by `chrono`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate chrono;
+/// # extern crate meealgi;
+/// # use chrono::{Utc, Timelike};
+/// # use meealgi::sun::{season_dt, Season};
+/// # fn main() {
+/// // 2017 March 20, 10h28m38s UT
+/// let equinox = season_dt(2017, Season::MarchEquinox, &Utc).unwrap();
+/// assert_eq!((10, 28), (equinox.hour(), equinox.minute()));
+/// # }
+/// ```
+pub fn season_dt<Tz: TimeZone>(year: i32, season: Season, tz: &Tz) -> Option<DateTime<Tz>> {
+    jul_to_dt(season_jul(year, season), tz)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use sun::*;
+
+    #[test]
+    fn seasons_of_2017() {
+        let expected = [
+            (Season::MarchEquinox, Utc.with_ymd_and_hms(2017, 3, 20, 10, 28, 38)),
+            (Season::JuneSolstice, Utc.with_ymd_and_hms(2017, 6, 21, 4, 24, 9)),
+            (Season::SeptemberEquinox, Utc.with_ymd_and_hms(2017, 9, 22, 20, 1, 48)),
+            (Season::DecemberSolstice, Utc.with_ymd_and_hms(2017, 12, 21, 16, 27, 57)),
+        ];
+
+        for &(season, ref instant) in expected.iter() {
+            let actual = season_dt(2017, season, &Utc).unwrap();
+            let difference = actual - instant.unwrap();
+
+            assert!(
+                difference.num_seconds().abs() <= 30,
+                "{:?} expected at {:?}, found {}",
+                season,
+                instant,
+                actual
+            );
+        }
+    }
+
+    #[test]
+    fn refinement_reaches_target_longitude() {
+        for &year in &[-500, 1066, 1582, 2000, 2999] {
+            for &season in &[
+                Season::MarchEquinox,
+                Season::JuneSolstice,
+                Season::SeptemberEquinox,
+                Season::DecemberSolstice,
+            ]
+            {
+                let difference = (apparent_longitude(season_jule(year, season)) -
+                                      season.longitude())
+                    .sin();
+
+                assert!(difference.abs() < 1e-7, "{} {:?}", year, season);
+            }
+        }
+    }
+}
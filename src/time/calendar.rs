@@ -0,0 +1,536 @@
+//! A lightweight calendar date, independent of `chrono`'s supported range,
+//! along with calendar utilities such as the day of the week and the date
+//! of Easter.
+
+use chrono::{NaiveDateTime, Datelike, Weekday};
+use time::decimal_day;
+
+/// The calendar a date is expressed in.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Calendar {
+    /// The Julian calendar (proleptic before 45 BC)
+    Julian,
+    /// The Gregorian calendar (proleptic before 1582 October 15)
+    Gregorian,
+}
+
+/// Julian Day of the Gregorian reform, 1582 October 15.0
+const GREGORIAN_REFORM_JD: f64 = 2_299_160.5_f64;
+
+/// Julian Days of 2000 January 1.0 in each calendar
+const GREGORIAN_REFERENCE_JD: f64 = 2_451_544.5_f64;
+const JULIAN_REFERENCE_JD: f64 = 2_451_557.5_f64;
+const REFERENCE_YEAR: i64 = 2_000;
+
+/// Days in a full Gregorian leap cycle (400 years)
+const GREGORIAN_CYCLE_DAYS: f64 = 146_097_f64;
+/// Days in a full Julian leap cycle (4 years)
+const JULIAN_CYCLE_DAYS: f64 = 1_461_f64;
+
+impl Calendar {
+    /// The calendar in astronomical use at a given Julian Day: Julian
+    /// before 1582 October 15, Gregorian from then on.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use meealgi::time::calendar::Calendar;
+    ///
+    /// assert_eq!(Calendar::Julian, Calendar::for_jul(2_299_160_f64));
+    /// assert_eq!(Calendar::Gregorian, Calendar::for_jul(2_299_160.5_f64));
+    /// ```
+    pub fn for_jul(jd: f64) -> Calendar {
+        if jd < GREGORIAN_REFORM_JD {
+            Calendar::Julian
+        } else {
+            Calendar::Gregorian
+        }
+    }
+
+    /// Whether a given (astronomical) year is a leap year in this calendar.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use meealgi::time::calendar::Calendar;
+    ///
+    /// assert!(Calendar::Julian.is_leap_year(1900));
+    /// assert!(!Calendar::Gregorian.is_leap_year(1900));
+    /// assert!(Calendar::Gregorian.is_leap_year(2000));
+    /// ```
+    pub fn is_leap_year(self, year: i64) -> bool {
+        match self {
+            Calendar::Julian => year.rem_euclid(4) == 0,
+            Calendar::Gregorian => {
+                year.rem_euclid(4) == 0 && (year.rem_euclid(100) != 0 || year.rem_euclid(400) == 0)
+            }
+        }
+    }
+
+    /// The date of Easter Sunday for a given year, as reckoned in this
+    /// calendar.
+    ///
+    /// Uses the methods from Meeus (ch. 8). Gregorian Easter is proleptic
+    /// before 1583.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use meealgi::time::calendar::*;
+    ///
+    /// assert_eq!(
+    ///     CalendarDate::new(2000, 4, 23_f64, Calendar::Gregorian),
+    ///     Calendar::Gregorian.easter(2000)
+    /// );
+    /// assert_eq!(
+    ///     CalendarDate::new(179, 4, 12_f64, Calendar::Julian),
+    ///     Calendar::Julian.easter(179)
+    /// );
+    /// ```
+    pub fn easter(self, year: i64) -> CalendarDate {
+        let (month, day) = match self {
+            Calendar::Julian => {
+                let a = year.rem_euclid(4);
+                let b = year.rem_euclid(7);
+                let c = year.rem_euclid(19);
+                let d = (19 * c + 15).rem_euclid(30);
+                let e = (2 * a + 4 * b - d + 34).rem_euclid(7);
+
+                ((d + e + 114) / 31, (d + e + 114) % 31 + 1)
+            }
+            Calendar::Gregorian => {
+                let a = year.rem_euclid(19);
+                let b = year.div_euclid(100);
+                let c = year.rem_euclid(100);
+                let d = b.div_euclid(4);
+                let e = b.rem_euclid(4);
+                let f = (b + 8).div_euclid(25);
+                let g = (b - f + 1).div_euclid(3);
+                let h = (19 * a + b - d - g + 15).rem_euclid(30);
+                let i = c / 4;
+                let k = c % 4;
+                let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+                let m = (a + 11 * h + 22 * l) / 451;
+
+                ((h + l - 7 * m + 114) / 31, (h + l - 7 * m + 114) % 31 + 1)
+            }
+        };
+
+        CalendarDate::new(year, month as u32, day as f64, self)
+    }
+
+    fn cycle_years(self) -> i64 {
+        match self {
+            Calendar::Julian => 4,
+            Calendar::Gregorian => 400,
+        }
+    }
+
+    fn cycle_days(self) -> f64 {
+        match self {
+            Calendar::Julian => JULIAN_CYCLE_DAYS,
+            Calendar::Gregorian => GREGORIAN_CYCLE_DAYS,
+        }
+    }
+
+    fn reference_jd(self) -> f64 {
+        match self {
+            Calendar::Julian => JULIAN_REFERENCE_JD,
+            Calendar::Gregorian => GREGORIAN_REFERENCE_JD,
+        }
+    }
+}
+
+/// A calendar date with a fractional day, in either the Julian or
+/// Gregorian calendar.
+///
+/// Years are astronomical (1 BC is year 0, 2 BC is year -1) and may be
+/// any `i64`, so dates far outside `chrono`'s range can be represented.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CalendarDate {
+    /// astronomical year number
+    pub year: i64,
+    /// month of the year, 1 to 12
+    pub month: u32,
+    /// day of the month, including the fraction of the day
+    pub day: f64,
+    /// calendar the date is expressed in
+    pub calendar: Calendar,
+}
+
+impl CalendarDate {
+    /// Creates a new calendar date. The values aren't validated.
+    pub fn new(year: i64, month: u32, day: f64, calendar: Calendar) -> CalendarDate {
+        CalendarDate {
+            year,
+            month,
+            day,
+            calendar,
+        }
+    }
+
+    /// Creates a Gregorian calendar date from a `chrono::NaiveDateTime`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate chrono;
+    /// # extern crate meealgi;
+    /// # use meealgi::time::calendar::*;
+    /// # fn main() {
+    /// let ndt = chrono::NaiveDate::from_ymd_opt(2017, 1, 7)
+    ///     .unwrap()
+    ///     .and_hms_opt(12, 0, 0)
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     CalendarDate::new(2017, 1, 7.5, Calendar::Gregorian),
+    ///     CalendarDate::from_ndt(&ndt)
+    /// );
+    /// # }
+    /// ```
+    pub fn from_ndt(ndt: &NaiveDateTime) -> CalendarDate {
+        CalendarDate::new(
+            i64::from(ndt.year()),
+            ndt.month(),
+            decimal_day(ndt),
+            Calendar::Gregorian,
+        )
+    }
+
+    /// Converts a Julian Day into a date in the given calendar.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use meealgi::time::calendar::*;
+    ///
+    /// let date = CalendarDate::from_jul(1_842_713_f64, Calendar::Julian);
+    /// assert_eq!(CalendarDate::new(333, 1, 27.5, Calendar::Julian), date);
+    /// ```
+    pub fn from_jul(jd: f64, calendar: Calendar) -> CalendarDate {
+        // shift into a single leap cycle after the reference date, where
+        // Meeus' algorithm (ch. 7) is valid, then shift the year back
+        let cycles = ((jd - calendar.reference_jd()) / calendar.cycle_days()).floor();
+        let shifted = jd - cycles * calendar.cycle_days();
+
+        let shifted = shifted + 0.5_f64;
+        let z = shifted.floor();
+        let f = shifted - z;
+
+        let a = match calendar {
+            Calendar::Julian => z,
+            Calendar::Gregorian => {
+                let alpha = ((z - 1_867_216.25_f64) / 36_524.25_f64).floor();
+                z + 1_f64 + alpha - (alpha / 4_f64).floor()
+            }
+        };
+
+        let b = a + 1_524_f64;
+        let c = ((b - 122.1_f64) / 365.25_f64).floor();
+        let d = (365.25_f64 * c).floor();
+        let e = ((b - d) / 30.6001_f64).floor();
+
+        let day = b - d - (30.6001_f64 * e).floor() + f;
+        let month = if e < 14_f64 { e - 1_f64 } else { e - 13_f64 };
+        let year = if month > 2_f64 {
+            c - 4_716_f64
+        } else {
+            c - 4_715_f64
+        };
+
+        CalendarDate::new(
+            year as i64 + cycles as i64 * calendar.cycle_years(),
+            month as u32,
+            day,
+            calendar,
+        )
+    }
+
+    /// Converts the date into a decimal Julian Day.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use meealgi::time::calendar::*;
+    ///
+    /// let date = CalendarDate::new(1957, 10, 4.81, Calendar::Gregorian);
+    /// assert_eq!(2_436_116.31_f64, (date.to_jul() * 100_f64).round() / 100_f64);
+    /// ```
+    pub fn to_jul(&self) -> f64 {
+        let calendar = self.calendar;
+
+        // shift the year into the cycle starting at the reference year,
+        // where Meeus' algorithm (ch. 7) is valid
+        let cycles = (self.year - REFERENCE_YEAR).div_euclid(calendar.cycle_years());
+        let year = self.year - cycles * calendar.cycle_years();
+
+        let (year, month) = match self.month {
+            1 | 2 => (year - 1, self.month + 12),
+            _ => (year, self.month),
+        };
+        let year = year as f64;
+        let month = f64::from(month);
+
+        let b = match calendar {
+            Calendar::Julian => 0_f64,
+            Calendar::Gregorian => {
+                let a = (year / 100_f64).floor();
+                2_f64 - a + (a / 4_f64).floor()
+            }
+        };
+
+        (365.25_f64 * (year + 4_716_f64)).floor() + (30.6001_f64 * (month + 1_f64)).floor() +
+            self.day + b - 1_524.5_f64 + cycles as f64 * calendar.cycle_days()
+    }
+
+    /// Creates a date from a year and a day of the year (1 for January 1).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use meealgi::time::calendar::*;
+    ///
+    /// assert_eq!(
+    ///     CalendarDate::new(1978, 11, 14_f64, Calendar::Gregorian),
+    ///     CalendarDate::from_day_of_year(1978, 318, Calendar::Gregorian)
+    /// );
+    /// ```
+    pub fn from_day_of_year(year: i64, day_of_year: u32, calendar: Calendar) -> CalendarDate {
+        let k = if calendar.is_leap_year(year) { 1 } else { 2 };
+        let n = day_of_year;
+
+        let month = if n < 32 {
+            1
+        } else {
+            (9_f64 * f64::from(k + n) / 275_f64 + 0.98_f64) as u32
+        };
+        let day = n + k * ((month + 9) / 12) + 30 - 275 * month / 9;
+
+        CalendarDate::new(year, month, f64::from(day), calendar)
+    }
+
+    /// The day of the year (1 for January 1), ignoring any fraction of the
+    /// day. As dates aren't validated, a day before January 1 gives zero or
+    /// less, and a month outside 1 to 12 gives a meaningless (but not
+    /// overflowing) result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use meealgi::time::calendar::*;
+    ///
+    /// let date = CalendarDate::new(1988, 4, 22.75, Calendar::Gregorian);
+    /// assert_eq!(113, date.day_of_year());
+    /// ```
+    pub fn day_of_year(&self) -> i64 {
+        let k = if self.calendar.is_leap_year(self.year) {
+            1
+        } else {
+            2
+        };
+        let month = i64::from(self.month);
+
+        275 * month / 9 - k * ((month + 9) / 12) + self.day.floor() as i64 - 30
+    }
+
+    /// The day of the week.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate chrono;
+    /// # extern crate meealgi;
+    /// # use meealgi::time::calendar::*;
+    /// # fn main() {
+    /// let date = CalendarDate::new(1954, 6, 30_f64, Calendar::Gregorian);
+    /// assert_eq!(chrono::Weekday::Wed, date.day_of_week());
+    /// # }
+    /// ```
+    pub fn day_of_week(&self) -> Weekday {
+        let days_from_sunday = (self.to_jul() + 1.5_f64).floor() as i64;
+
+        match days_from_sunday.rem_euclid(7) {
+            0 => Weekday::Sun,
+            1 => Weekday::Mon,
+            2 => Weekday::Tue,
+            3 => Weekday::Wed,
+            4 => Weekday::Thu,
+            5 => Weekday::Fri,
+            _ => Weekday::Sat,
+        }
+    }
+
+    /// Expresses the same instant in a different calendar.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use meealgi::time::calendar::*;
+    ///
+    /// let reform = CalendarDate::new(1582, 10, 15_f64, Calendar::Gregorian);
+    /// assert_eq!(
+    ///     CalendarDate::new(1582, 10, 5_f64, Calendar::Julian),
+    ///     reform.to_calendar(Calendar::Julian)
+    /// );
+    /// ```
+    pub fn to_calendar(&self, calendar: Calendar) -> CalendarDate {
+        if calendar == self.calendar {
+            *self
+        } else {
+            CalendarDate::from_jul(self.to_jul(), calendar)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Weekday;
+    use time::calendar::*;
+    use spectral::prelude::*;
+
+    fn check_round_trip(date: CalendarDate, jd: f64) {
+        assert_that!(date.to_jul()).is_close_to(jd, 1e-6_f64);
+
+        let back = CalendarDate::from_jul(jd, date.calendar);
+        assert_eq!(date.year, back.year);
+        assert_eq!(date.month, back.month);
+        assert_that!(back.day).is_close_to(date.day, 1e-6_f64);
+    }
+
+    #[test]
+    fn meeus_examples() {
+        // Meeus examples 7.a - 7.c
+        check_round_trip(CalendarDate::new(1957, 10, 4.81, Calendar::Gregorian), 2_436_116.31);
+        check_round_trip(CalendarDate::new(333, 1, 27.5, Calendar::Julian), 1_842_713.0);
+        check_round_trip(CalendarDate::new(-584, 5, 28.63, Calendar::Julian), 1_507_900.13);
+    }
+
+    #[test]
+    fn jd_solar_report_dates() {
+        // the NREL A.4.1 dates, including the one chrono can't represent
+        check_round_trip(CalendarDate::new(2000, 1, 1.5, Calendar::Gregorian), 2_451_545.0);
+        check_round_trip(CalendarDate::new(1600, 12, 31.0, Calendar::Gregorian), 2_305_812.5);
+        check_round_trip(CalendarDate::new(837, 4, 10.3, Calendar::Julian), 2_026_871.8);
+        check_round_trip(CalendarDate::new(-1000, 7, 12.5, Calendar::Julian), 1_356_001.0);
+        check_round_trip(CalendarDate::new(-1000, 2, 29.0, Calendar::Julian), 1_355_866.5);
+        check_round_trip(CalendarDate::new(-1001, 8, 17.9, Calendar::Julian), 1_355_671.4);
+        check_round_trip(CalendarDate::new(-4712, 1, 1.5, Calendar::Julian), 0.0);
+    }
+
+    #[test]
+    fn negative_julian_days() {
+        check_round_trip(CalendarDate::new(-4713, 12, 31.5, Calendar::Julian), -1.0);
+        check_round_trip(CalendarDate::new(-4716, 3, 1.5, Calendar::Julian), -1_401.0);
+    }
+
+    #[test]
+    fn years_beyond_chrono() {
+        for &calendar in &[Calendar::Julian, Calendar::Gregorian] {
+            for &year in &[-1_000_000, -262_145, 262_144, 1_000_000] {
+                let date = CalendarDate::new(year, 2, 28.25, calendar);
+                let back = CalendarDate::from_jul(date.to_jul(), calendar);
+
+                assert_eq!(date.year, back.year);
+                assert_eq!(date.month, back.month);
+                assert_that!(back.day).is_close_to(date.day, 1e-6_f64);
+            }
+        }
+    }
+
+    #[test]
+    fn calendars_drift_apart() {
+        // the calendars agree during the 3rd century
+        let julian = CalendarDate::new(250, 6, 1.0, Calendar::Julian);
+        assert_eq!(
+            CalendarDate::new(250, 6, 1.0, Calendar::Gregorian),
+            julian.to_calendar(Calendar::Gregorian)
+        );
+
+        let gregorian = CalendarDate::new(2000, 1, 1.0, Calendar::Gregorian);
+        assert_eq!(
+            CalendarDate::new(1999, 12, 19.0, Calendar::Julian),
+            gregorian.to_calendar(Calendar::Julian)
+        );
+    }
+
+    #[test]
+    fn gregorian_easter() {
+        // Meeus ch. 8
+        let dates = [
+            (1991, 3, 31),
+            (1992, 4, 19),
+            (1993, 4, 11),
+            (1954, 4, 18),
+            (2000, 4, 23),
+            (1818, 3, 22),
+            (2285, 3, 22),
+            (1943, 4, 25),
+            (2038, 4, 25),
+        ];
+
+        for &(year, month, day) in dates.iter() {
+            assert_eq!(
+                CalendarDate::new(year, month, f64::from(day), Calendar::Gregorian),
+                Calendar::Gregorian.easter(year)
+            );
+        }
+    }
+
+    #[test]
+    fn julian_easter() {
+        for &year in &[179, 711, 1243] {
+            assert_eq!(
+                CalendarDate::new(year, 4, 12_f64, Calendar::Julian),
+                Calendar::Julian.easter(year)
+            );
+        }
+
+        // Orthodox Easter in 2017 fell on the same day as Western Easter
+        assert_eq!(
+            CalendarDate::new(2017, 4, 16_f64, Calendar::Gregorian),
+            Calendar::Julian.easter(2017).to_calendar(Calendar::Gregorian)
+        );
+    }
+
+    #[test]
+    fn day_of_year_round_trips() {
+        for &calendar in &[Calendar::Julian, Calendar::Gregorian] {
+            for &year in &[-4712, 1900, 2000, 2017] {
+                let days = if calendar.is_leap_year(year) { 366 } else { 365 };
+
+                for day_of_year in 1..(days + 1) {
+                    let date = CalendarDate::from_day_of_year(year, day_of_year, calendar);
+                    assert_eq!(i64::from(day_of_year), date.day_of_year());
+
+                    let next = CalendarDate::from_jul(date.to_jul() + 1_f64, calendar);
+                    if day_of_year < days {
+                        assert_eq!(i64::from(day_of_year) + 1, next.day_of_year());
+                    } else {
+                        assert_eq!((year + 1, 1, 1_f64), (next.year, next.month, next.day));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn day_of_year_before_january() {
+        assert_eq!(0, CalendarDate::new(2017, 1, 0_f64, Calendar::Gregorian).day_of_year());
+        // an invalid month mustn't overflow
+        assert!(CalendarDate::new(2017, 0, 1_f64, Calendar::Gregorian).day_of_year() < 1);
+    }
+
+    #[test]
+    fn days_of_the_week() {
+        // the Julian and Gregorian reform dates are consecutive days
+        let julian = CalendarDate::new(1582, 10, 4_f64, Calendar::Julian);
+        let gregorian = CalendarDate::new(1582, 10, 15_f64, Calendar::Gregorian);
+
+        assert_eq!(Weekday::Thu, julian.day_of_week());
+        assert_eq!(Weekday::Fri, gregorian.day_of_week());
+
+        // the time of day doesn't matter
+        let evening = CalendarDate::new(2017, 1, 7.99, Calendar::Gregorian);
+        assert_eq!(Weekday::Sat, evening.day_of_week());
+    }
+}
@@ -2,7 +2,8 @@ extern crate chrono;
 
 use chrono::{NaiveDateTime, Datelike, Timelike};
 
-/// Convert a `chrono::NaiveDateTime` to a decimal day
+/// Convert a `chrono::NaiveDateTime` to a decimal day, including any
+/// sub-second component
 ///
 /// # Examples
 ///
@@ -19,9 +20,38 @@ pub fn decimal_day(date: &NaiveDateTime) -> f64 {
     const SECONDS_IN_MINUTE: f64 = 60_f64;
     const SECONDS_IN_HOUR: f64 = 60_f64 * SECONDS_IN_MINUTE;
     const SECONDS_IN_DAY: f64 = 24_f64 * SECONDS_IN_HOUR;
+    const NANOSECONDS_IN_SECOND: f64 = 1_000_000_000_f64;
 
     f64::from(date.day()) +
         (f64::from(date.hour()) * SECONDS_IN_HOUR +
-             f64::from(date.minute()) * SECONDS_IN_MINUTE + f64::from(date.second())) /
+             f64::from(date.minute()) * SECONDS_IN_MINUTE + f64::from(date.second()) +
+             f64::from(date.nanosecond()) / NANOSECONDS_IN_SECOND) /
             SECONDS_IN_DAY
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+    use time::decimal_day;
+    use spectral::prelude::*;
+
+    #[test]
+    fn includes_milliseconds() {
+        let date = NaiveDate::from_ymd_opt(2017, 1, 7)
+            .unwrap()
+            .and_hms_milli_opt(12, 0, 0, 500)
+            .unwrap();
+
+        assert_that!(decimal_day(&date)).is_close_to(7.5_f64 + 0.5_f64 / 86_400_f64, 1e-12_f64);
+    }
+
+    #[test]
+    fn includes_microseconds() {
+        let date = NaiveDate::from_ymd_opt(2017, 1, 7)
+            .unwrap()
+            .and_hms_micro_opt(0, 0, 0, 250)
+            .unwrap();
+
+        assert_that!(decimal_day(&date)).is_close_to(7_f64 + 0.000_25_f64 / 86_400_f64, 1e-14_f64);
+    }
+}
@@ -0,0 +1,184 @@
+//! Modified Julian Dates, and Julian and Besselian epochs.
+
+/// Offset between a Julian Day and a Modified Julian Date
+const MJD_OFFSET: f64 = 2_400_000.5_f64;
+
+/// Julian Day of the J2000.0 epoch
+const J2000_JD: f64 = 2_451_545_f64;
+/// Length of a Julian year, in days
+const JULIAN_YEAR: f64 = 365.25_f64;
+
+/// Julian Day of the B1900.0 epoch
+const B1900_JD: f64 = 2_415_020.313_52_f64;
+/// Length of a tropical year at B1900.0, in days
+const BESSELIAN_YEAR: f64 = 365.242_198_781_f64;
+
+/// An epoch as used in catalogues and orbital elements, such as `J2000.0`
+/// or `B1950.0`.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Epoch {
+    /// Julian epoch (e.g. `J2000.0`), in Julian years
+    Julian(f64),
+    /// Besselian epoch (e.g. `B1950.0`), in Besselian years
+    Besselian(f64),
+}
+
+impl Epoch {
+    /// The Julian Day of the epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use meealgi::time::Epoch;
+    ///
+    /// assert_eq!(2_451_545_f64, Epoch::Julian(2000_f64).to_jul());
+    /// ```
+    pub fn to_jul(&self) -> f64 {
+        match *self {
+            Epoch::Julian(year) => julian_epoch_to_jul(year),
+            Epoch::Besselian(year) => besselian_epoch_to_jul(year),
+        }
+    }
+}
+
+/// Convert a Julian Day to a Modified Julian Date
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(51_544.5_f64, meealgi::time::jul_to_mjd(2_451_545_f64));
+/// ```
+pub fn jul_to_mjd(jd: f64) -> f64 {
+    jd - MJD_OFFSET
+}
+
+/// Convert a Modified Julian Date to a Julian Day
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(2_451_545_f64, meealgi::time::mjd_to_jul(51_544.5_f64));
+/// ```
+pub fn mjd_to_jul(mjd: f64) -> f64 {
+    mjd + MJD_OFFSET
+}
+
+/// Convert a Julian Day to a Julian epoch (e.g. `2000.0` for J2000.0)
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(2015.5_f64, meealgi::time::jul_to_julian_epoch(2_457_206.375_f64));
+/// ```
+pub fn jul_to_julian_epoch(jd: f64) -> f64 {
+    2_000_f64 + (jd - J2000_JD) / JULIAN_YEAR
+}
+
+/// Convert a Julian epoch (e.g. `2000.0` for J2000.0) to a Julian Day
+///
+/// # Examples
+///
+/// ```rust
+/// assert_eq!(2_457_206.375_f64, meealgi::time::julian_epoch_to_jul(2015.5_f64));
+/// ```
+pub fn julian_epoch_to_jul(epoch: f64) -> f64 {
+    J2000_JD + (epoch - 2_000_f64) * JULIAN_YEAR
+}
+
+/// Convert a Julian Day to a Besselian epoch (e.g. `1950.0` for B1950.0)
+///
+/// # Examples
+///
+/// ```rust
+/// let epoch = meealgi::time::jul_to_besselian_epoch(2_415_020.313_52_f64);
+/// assert_eq!(1900_f64, epoch);
+/// ```
+pub fn jul_to_besselian_epoch(jd: f64) -> f64 {
+    1_900_f64 + (jd - B1900_JD) / BESSELIAN_YEAR
+}
+
+/// Convert a Besselian epoch (e.g. `1950.0` for B1950.0) to a Julian Day
+///
+/// # Examples
+///
+/// ```rust
+/// let jd = meealgi::time::besselian_epoch_to_jul(1900_f64);
+/// assert_eq!(2_415_020.313_52_f64, jd);
+/// ```
+pub fn besselian_epoch_to_jul(epoch: f64) -> f64 {
+    B1900_JD + (epoch - 1_900_f64) * BESSELIAN_YEAR
+}
+
+/// Parse an epoch string such as `"J2015.5"` or `"B1950"`.
+///
+/// A bare year (without a `J` or `B` prefix) is treated as a Julian epoch
+/// from 1984 onwards, and a Besselian epoch before then, as per IAU
+/// convention. Returns `None` if the string isn't a valid epoch, including
+/// when the year isn't finite (e.g. `"JNaN"` or `"inf"`).
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::time::{parse_epoch, Epoch};
+///
+/// assert_eq!(Some(Epoch::Julian(2015.5_f64)), parse_epoch("J2015.5"));
+/// assert_eq!(Some(Epoch::Besselian(1950_f64)), parse_epoch("B1950.0"));
+/// assert_eq!(None, parse_epoch("X2000"));
+/// ```
+pub fn parse_epoch(epoch: &str) -> Option<Epoch> {
+    let epoch = epoch.trim();
+    let parse_year = |year: &str| year.parse::<f64>().ok().filter(|year| year.is_finite());
+
+    if let Some(year) = epoch.strip_prefix('J') {
+        parse_year(year).map(Epoch::Julian)
+    } else if let Some(year) = epoch.strip_prefix('B') {
+        parse_year(year).map(Epoch::Besselian)
+    } else {
+        parse_year(epoch).map(|year| if year >= 1_984_f64 {
+            Epoch::Julian(year)
+        } else {
+            Epoch::Besselian(year)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::*;
+    use spectral::prelude::*;
+
+    #[test]
+    fn standard_epochs() {
+        assert_that!(Epoch::Besselian(1950_f64).to_jul()).is_close_to(2_433_282.423_5_f64, 1e-4);
+        assert_that!(Epoch::Julian(2000_f64).to_jul()).is_close_to(2_451_545_f64, 1e-9);
+        assert_that!(jul_to_mjd(Epoch::Julian(2000_f64).to_jul())).is_close_to(51_544.5, 1e-9);
+
+        // B1900.0 and J1900.0 differ by a fraction of a day
+        assert_that!(jul_to_julian_epoch(Epoch::Besselian(1900_f64).to_jul()))
+            .is_close_to(1900_f64, 0.001_f64);
+    }
+
+    #[test]
+    fn epochs_round_trip() {
+        for &jd in &[0_f64, 2_400_000.5_f64, 2_433_282.423_5_f64, 2_460_000.25_f64] {
+            assert_that!(mjd_to_jul(jul_to_mjd(jd))).is_close_to(jd, 1e-9);
+            assert_that!(julian_epoch_to_jul(jul_to_julian_epoch(jd))).is_close_to(jd, 1e-6);
+            assert_that!(besselian_epoch_to_jul(jul_to_besselian_epoch(jd))).is_close_to(jd, 1e-6);
+        }
+    }
+
+    #[test]
+    fn parses_epoch_strings() {
+        assert_eq!(Some(Epoch::Julian(2015.5)), parse_epoch("J2015.5"));
+        assert_eq!(Some(Epoch::Julian(2000_f64)), parse_epoch(" J2000 "));
+        assert_eq!(Some(Epoch::Besselian(1875_f64)), parse_epoch("B1875.0"));
+        assert_eq!(Some(Epoch::Julian(2010_f64)), parse_epoch("2010"));
+        assert_eq!(Some(Epoch::Besselian(1950_f64)), parse_epoch("1950"));
+        assert_eq!(None, parse_epoch("J"));
+        assert_eq!(None, parse_epoch("JNaN"));
+        assert_eq!(None, parse_epoch("Binf"));
+        assert_eq!(None, parse_epoch("inf"));
+        assert_eq!(None, parse_epoch("J20x5"));
+        assert_eq!(None, parse_epoch(""));
+    }
+}
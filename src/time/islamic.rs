@@ -0,0 +1,182 @@
+//! Conversions between the tabular Islamic calendar and Julian Days
+//! (Meeus ch. 9).
+//!
+//! The tabular calendar is arithmetical, so it may differ by a day or two
+//! from calendars based on sighting the lunar crescent. Dates correspond
+//! to the civil day (from midnight to midnight).
+
+use time::calendar::{Calendar, CalendarDate};
+
+/// Julian Day Number of 1 Muharram AH 1 (622 July 16, Julian calendar)
+const EPOCH_DAY: i64 = 1_948_440;
+/// Days in a 30 year cycle
+const CYCLE_DAYS: i64 = 10_631;
+
+/// A date in the tabular Islamic calendar.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct IslamicDate {
+    /// year, counted from the Hijra (AH)
+    pub year: i64,
+    /// month, from Muharram (1) to Dhu al-Hijja (12)
+    pub month: u32,
+    /// day of the month
+    pub day: u32,
+}
+
+/// Whether a year is a leap year in the tabular Islamic calendar, giving
+/// Dhu al-Hijja 30 days rather than 29.
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::time::islamic::is_leap_year;
+///
+/// assert!(is_leap_year(1420));
+/// assert!(!is_leap_year(1421));
+/// ```
+pub fn is_leap_year(year: i64) -> bool {
+    (11 * year + 14).rem_euclid(30) < 11
+}
+
+/// The number of days in a month of the tabular Islamic calendar.
+pub fn month_length(year: i64, month: u32) -> u32 {
+    if month % 2 == 1 || (month == 12 && is_leap_year(year)) {
+        30
+    } else {
+        29
+    }
+}
+
+/// The Julian Day Number (the day containing noon) of 1 Muharram
+fn new_year_day(year: i64) -> i64 {
+    EPOCH_DAY + (year - 1) * 354 + (3 + 11 * year).div_euclid(30)
+}
+
+impl IslamicDate {
+    /// Creates a new Islamic date. The values aren't validated.
+    pub fn new(year: i64, month: u32, day: u32) -> IslamicDate {
+        IslamicDate { year, month, day }
+    }
+
+    /// Finds the Islamic date for the civil day containing a Julian Day.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use meealgi::time::islamic::IslamicDate;
+    ///
+    /// // 2000 April 6, 12h UT
+    /// assert_eq!(IslamicDate::new(1421, 1, 1), IslamicDate::from_jul(2_451_641_f64));
+    /// ```
+    pub fn from_jul(jd: f64) -> IslamicDate {
+        let day_number = (jd + 0.5_f64).floor() as i64;
+
+        let mut year = (30 * (day_number - EPOCH_DAY) + 10_646).div_euclid(CYCLE_DAYS);
+        if day_number < new_year_day(year) {
+            year -= 1;
+        } else if day_number >= new_year_day(year + 1) {
+            year += 1;
+        }
+
+        let mut days = (day_number - new_year_day(year)) as u32;
+        for month in 1..13 {
+            let length = month_length(year, month);
+
+            if days < length {
+                return IslamicDate::new(year, month, days + 1);
+            }
+            days -= length;
+        }
+
+        unreachable!("day beyond the end of the Islamic year")
+    }
+
+    /// Finds the Islamic date for a Julian or Gregorian calendar date.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use meealgi::time::calendar::*;
+    /// use meealgi::time::islamic::IslamicDate;
+    ///
+    /// // Meeus example 9.c
+    /// let date = CalendarDate::new(1991, 8, 13_f64, Calendar::Gregorian);
+    /// assert_eq!(IslamicDate::new(1412, 2, 2), IslamicDate::from_calendar_date(&date));
+    /// ```
+    pub fn from_calendar_date(date: &CalendarDate) -> IslamicDate {
+        IslamicDate::from_jul(date.to_jul())
+    }
+
+    /// The Julian Day at the start (0h) of the civil day.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use meealgi::time::islamic::IslamicDate;
+    ///
+    /// assert_eq!(2_451_640.5_f64, IslamicDate::new(1421, 1, 1).to_jul());
+    /// ```
+    pub fn to_jul(&self) -> f64 {
+        let preceding_days: u32 = (1..self.month)
+            .map(|month| month_length(self.year, month))
+            .sum();
+
+        (new_year_day(self.year) + i64::from(preceding_days + self.day - 1)) as f64 - 0.5_f64
+    }
+
+    /// The equivalent Julian or Gregorian calendar date.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use meealgi::time::calendar::*;
+    /// use meealgi::time::islamic::IslamicDate;
+    ///
+    /// // Meeus example 9.b
+    /// assert_eq!(
+    ///     CalendarDate::new(1991, 8, 13_f64, Calendar::Gregorian),
+    ///     IslamicDate::new(1412, 2, 2).to_calendar_date(Calendar::Gregorian)
+    /// );
+    /// ```
+    pub fn to_calendar_date(&self, calendar: Calendar) -> CalendarDate {
+        CalendarDate::from_jul(self.to_jul(), calendar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::calendar::*;
+    use time::islamic::*;
+
+    #[test]
+    fn epoch() {
+        let epoch = IslamicDate::new(1, 1, 1);
+
+        assert_eq!(
+            CalendarDate::new(622, 7, 16_f64, Calendar::Julian),
+            epoch.to_calendar_date(Calendar::Julian)
+        );
+    }
+
+    #[test]
+    fn leap_years_in_cycle() {
+        let leap_years: Vec<i64> = (1..31).filter(|year| is_leap_year(*year)).collect();
+
+        assert_eq!(vec![2, 5, 7, 10, 13, 16, 18, 21, 24, 26, 29], leap_years);
+        assert_eq!(CYCLE_DAYS, new_year_day(31) - new_year_day(1));
+    }
+
+    #[test]
+    fn dates_round_trip() {
+        let start = IslamicDate::new(1400, 1, 1).to_jul();
+        let end = IslamicDate::new(1460, 1, 1).to_jul();
+        let mut jd = start;
+
+        while jd < end {
+            let date = IslamicDate::from_jul(jd);
+            assert_eq!(jd, date.to_jul(), "{:?}", date);
+
+            jd += 1_f64;
+        }
+    }
+}
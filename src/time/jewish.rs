@@ -0,0 +1,291 @@
+//! Conversions between the Jewish calendar and Julian Days (Meeus ch. 9).
+//!
+//! Months are numbered from Nisan (1) to Adar (12), with Adar II (13) in
+//! leap years. The year starts on 1 Tishri (month 7). Jewish days begin at
+//! sunset, but dates here correspond to the civil day on which most of the
+//! Jewish day falls (i.e. from midnight to midnight).
+
+use time::calendar::{Calendar, CalendarDate};
+
+/// Nisan, the month containing Pesach
+pub const NISAN: u32 = 1;
+/// Tishri, the first month of the year
+pub const TISHRI: u32 = 7;
+/// Adar (Adar I in leap years)
+pub const ADAR: u32 = 12;
+/// Adar II, only present in leap years
+pub const ADAR_II: u32 = 13;
+
+/// Days from Pesach (15 Nisan) to the following 1 Tishri
+const PESACH_TO_NEW_YEAR: f64 = 163_f64;
+/// Offset between a Jewish year and the Julian year its Pesach falls in
+const YEAR_OFFSET: i64 = 3_760;
+
+/// A date in the Jewish calendar.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct JewishDate {
+    /// year, counted from the traditional date of creation (anno mundi)
+    pub year: i64,
+    /// month, from Nisan (1) to Adar II (13)
+    pub month: u32,
+    /// day of the month
+    pub day: u32,
+}
+
+/// The date of Pesach (Passover, 15 Nisan) in a given Julian or Gregorian
+/// calendar year.
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::time::calendar::*;
+/// use meealgi::time::jewish::pesach;
+///
+/// // Meeus example 9.a
+/// assert_eq!(
+///     CalendarDate::new(1990, 4, 10_f64, Calendar::Gregorian),
+///     pesach(1990, Calendar::Gregorian)
+/// );
+/// ```
+pub fn pesach(year: i64, calendar: Calendar) -> CalendarDate {
+    CalendarDate::from_jul(pesach_jul(year), calendar)
+}
+
+/// The Julian Day (at 0h) of Pesach in the Julian year given.
+fn pesach_jul(julian_year: i64) -> f64 {
+    let x = julian_year as f64;
+    let a = (12 * julian_year + 12).rem_euclid(19);
+    let b = julian_year.rem_euclid(4);
+
+    let q = -1.904_412_361_576_f64 + 1.554_241_796_621_f64 * a as f64 + 0.25_f64 * b as f64 -
+        0.003_177_794_022_f64 * x;
+    let q_int = q.floor();
+    let r = q - q_int;
+    let j = (q_int as i64 + 3 * julian_year + 5 * b + 2).rem_euclid(7);
+
+    let day_of_march = match j {
+        2 | 4 | 6 => q_int + 23_f64,
+        1 if a > 6 && r >= 0.632_870_370_f64 => q_int + 24_f64,
+        0 if a > 11 && r >= 0.897_723_765_f64 => q_int + 23_f64,
+        _ => q_int + 22_f64,
+    };
+
+    CalendarDate::new(julian_year, 3, day_of_march, Calendar::Julian).to_jul()
+}
+
+/// The Julian Day (at 0h) of 1 Tishri, the first day of a Jewish year.
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::time::calendar::*;
+/// use meealgi::time::jewish::new_year_jul;
+///
+/// // 1 Tishri 5751 was 1990 September 20
+/// assert_eq!(
+///     CalendarDate::new(1990, 9, 20_f64, Calendar::Gregorian).to_jul(),
+///     new_year_jul(5751)
+/// );
+/// ```
+pub fn new_year_jul(year: i64) -> f64 {
+    pesach_jul(year - YEAR_OFFSET - 1) + PESACH_TO_NEW_YEAR
+}
+
+/// Whether a Jewish year is a leap (embolismic) year, with 13 months.
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::time::jewish::is_leap_year;
+///
+/// assert!(is_leap_year(5784));
+/// assert!(!is_leap_year(5785));
+/// ```
+pub fn is_leap_year(year: i64) -> bool {
+    (7 * year + 1).rem_euclid(19) < 7
+}
+
+/// The number of days in a Jewish year: 353, 354 or 355 for common years,
+/// and 383, 384 or 385 for leap years.
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::time::jewish::year_length;
+///
+/// assert_eq!(354, year_length(5751));
+/// ```
+pub fn year_length(year: i64) -> u32 {
+    (new_year_jul(year + 1) - new_year_jul(year)) as u32
+}
+
+/// The number of days in a month of a Jewish year.
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::time::jewish::{month_length, NISAN};
+///
+/// assert_eq!(30, month_length(5778, NISAN));
+/// ```
+pub fn month_length(year: i64, month: u32) -> u32 {
+    let length = year_length(year) % 10;
+
+    match month {
+        // Heshvan is long in complete (355/385 day) years
+        8 if length == 5 => 30,
+        // Kislev is short in deficient (353/383 day) years
+        9 if length == 3 => 29,
+        ADAR if is_leap_year(year) => 30,
+        2 | 4 | 6 | 8 | 10 | ADAR | ADAR_II => 29,
+        _ => 30,
+    }
+}
+
+/// The months of a Jewish year, in order from Tishri
+fn months_from_tishri(year: i64) -> Vec<u32> {
+    let mut months = vec![TISHRI, 8, 9, 10, 11, ADAR];
+
+    if is_leap_year(year) {
+        months.push(ADAR_II);
+    }
+    months.extend(NISAN..TISHRI);
+
+    months
+}
+
+impl JewishDate {
+    /// Creates a new Jewish date. The values aren't validated.
+    pub fn new(year: i64, month: u32, day: u32) -> JewishDate {
+        JewishDate { year, month, day }
+    }
+
+    /// Finds the Jewish date for the civil day containing a Julian Day.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use meealgi::time::jewish::*;
+    ///
+    /// // 2017 September 21, 12h UT
+    /// assert_eq!(JewishDate::new(5778, TISHRI, 1), JewishDate::from_jul(2_458_018_f64));
+    /// ```
+    pub fn from_jul(jd: f64) -> JewishDate {
+        let midnight = (jd + 0.5_f64).floor() - 0.5_f64;
+
+        let julian_year = CalendarDate::from_jul(midnight, Calendar::Julian).year;
+        let mut year = julian_year + YEAR_OFFSET + 1;
+        if midnight < new_year_jul(year) {
+            year -= 1;
+        }
+
+        let mut days = (midnight - new_year_jul(year)) as u32;
+        for month in months_from_tishri(year) {
+            let length = month_length(year, month);
+
+            if days < length {
+                return JewishDate::new(year, month, days + 1);
+            }
+            days -= length;
+        }
+
+        unreachable!("day beyond the end of the Jewish year")
+    }
+
+    /// Finds the Jewish date for a Julian or Gregorian calendar date.
+    pub fn from_calendar_date(date: &CalendarDate) -> JewishDate {
+        JewishDate::from_jul(date.to_jul())
+    }
+
+    /// The Julian Day at the start (0h) of the civil day.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use meealgi::time::jewish::*;
+    ///
+    /// // 15 Nisan 5750 was 1990 April 10
+    /// assert_eq!(2_447_991.5_f64, JewishDate::new(5750, NISAN, 15).to_jul());
+    /// ```
+    pub fn to_jul(&self) -> f64 {
+        let preceding_days: u32 = months_from_tishri(self.year)
+            .into_iter()
+            .take_while(|month| *month != self.month)
+            .map(|month| month_length(self.year, month))
+            .sum();
+
+        new_year_jul(self.year) + f64::from(preceding_days + self.day - 1)
+    }
+
+    /// The equivalent Julian or Gregorian calendar date.
+    pub fn to_calendar_date(&self, calendar: Calendar) -> CalendarDate {
+        CalendarDate::from_jul(self.to_jul(), calendar)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::calendar::*;
+    use time::jewish::*;
+
+    #[test]
+    fn pesach_dates() {
+        let dates = [(1990, 4, 10), (2017, 4, 11), (2019, 4, 20), (2024, 4, 23), (2000, 4, 20)];
+
+        for &(year, month, day) in dates.iter() {
+            assert_eq!(
+                CalendarDate::new(year, month, f64::from(day), Calendar::Gregorian),
+                pesach(year, Calendar::Gregorian)
+            );
+        }
+    }
+
+    #[test]
+    fn new_years_and_lengths() {
+        // (year, Rosh Hashanah, length)
+        let years = [
+            (5761, (2000, 9, 30), 353),
+            (5778, (2017, 9, 21), 354),
+            (5784, (2023, 9, 16), 383),
+            (5785, (2024, 10, 3), 355),
+        ];
+
+        for &(year, (y, m, d), length) in years.iter() {
+            let rosh_hashanah = CalendarDate::new(y, m, f64::from(d), Calendar::Gregorian);
+
+            assert_eq!(rosh_hashanah.to_jul(), new_year_jul(year));
+            assert_eq!(length, year_length(year));
+            assert_eq!(
+                length,
+                months_from_tishri(year)
+                    .into_iter()
+                    .map(|month| month_length(year, month))
+                    .sum::<u32>()
+            );
+        }
+    }
+
+    #[test]
+    fn dates_round_trip() {
+        let start = new_year_jul(5700);
+        let end = new_year_jul(5800);
+        let mut jd = start;
+
+        while jd < end {
+            let date = JewishDate::from_jul(jd);
+            assert_eq!(jd, date.to_jul(), "{:?}", date);
+
+            jd += 1_f64;
+        }
+    }
+
+    #[test]
+    fn calendar_date_conversions() {
+        // Purim 5777 (14 Adar) was 2017 March 12
+        let purim = JewishDate::new(5777, ADAR, 14);
+        let civil = CalendarDate::new(2017, 3, 12_f64, Calendar::Gregorian);
+
+        assert_eq!(civil, purim.to_calendar_date(Calendar::Gregorian));
+        assert_eq!(purim, JewishDate::from_calendar_date(&civil));
+    }
+}
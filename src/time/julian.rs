@@ -1,10 +1,14 @@
 extern crate chrono;
 
-use chrono::{NaiveDateTime, Datelike};
+use chrono::{NaiveDate, NaiveDateTime, DateTime, TimeZone, Datelike, Duration};
+use time::calendar::{Calendar, CalendarDate};
 use time::decimal_day;
 
 /// Convert a `chrono::NaiveDateTime` to a decimal Julian Day
 ///
+/// Sub-second components are carried through, although an `f64` Julian Day
+/// for a modern date can only resolve roughly 40 microseconds.
+///
 /// # Examples
 ///
 /// ```rust
@@ -12,7 +16,7 @@ use time::decimal_day;
 /// # extern crate chrono;
 /// # use chrono::TimeZone;
 /// # fn main() {
-/// let now = chrono::Utc.ymd(2017,01,01).and_hms(00, 00, 00);
+/// let now = chrono::Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 0).unwrap();
 /// meealgi::time::ndt_to_jul(&now.naive_utc());
 /// # }
 /// ```
@@ -41,6 +45,90 @@ pub fn ndt_to_jul(date: &NaiveDateTime) -> f64 {
     pre_shift_val + gregorian_shift_factor
 }
 
+/// Convert a timezone-aware `chrono::DateTime` to a decimal Julian Day
+///
+/// The instant is converted to UT first, so the result doesn't depend on
+/// the timezone of the input.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate meealgi;
+/// # extern crate chrono;
+/// # use chrono::{FixedOffset, TimeZone};
+/// # fn main() {
+/// let sydney = FixedOffset::east_opt(10 * 3600).unwrap();
+/// let dt = sydney.with_ymd_and_hms(2000, 01, 01, 22, 00, 00).unwrap();
+/// assert_eq!(2_451_545_f64, meealgi::time::dt_to_jul(&dt));
+/// # }
+/// ```
+pub fn dt_to_jul<Tz: TimeZone>(date: &DateTime<Tz>) -> f64 {
+    ndt_to_jul(&date.naive_utc())
+}
+
+/// Convert a decimal Julian Day to a `chrono::NaiveDateTime` in UT
+///
+/// Like `ndt_to_jul`, dates before 1582 October 15 are in the Julian
+/// calendar, so the two round-trip. Returns `None` if `jd` isn't finite, or
+/// if the date can't be represented by `chrono` (e.g. February 29 of a Julian
+/// century year, see `time::calendar` for those).
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate meealgi;
+/// # extern crate chrono;
+/// # fn main() {
+/// let ndt = meealgi::time::jul_to_ndt(2_451_545_f64).unwrap();
+/// assert_eq!(
+///     chrono::NaiveDate::from_ymd_opt(2000, 01, 01).unwrap().and_hms_opt(12, 00, 00).unwrap(),
+///     ndt
+/// );
+/// # }
+/// ```
+pub fn jul_to_ndt(jd: f64) -> Option<NaiveDateTime> {
+    const NANOSECONDS_IN_DAY: f64 = 86_400_000_000_000_f64;
+
+    if !jd.is_finite() {
+        return None;
+    }
+
+    let date = CalendarDate::from_jul(jd, Calendar::for_jul(jd));
+    if date.year < i64::from(i32::MIN) || date.year > i64::from(i32::MAX) {
+        return None;
+    }
+
+    let whole_day = date.day.floor();
+    let nanoseconds = ((date.day - whole_day) * NANOSECONDS_IN_DAY).round() as i64;
+
+    NaiveDate::from_ymd_opt(date.year as i32, date.month, whole_day as u32)
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .and_then(|midnight| {
+            midnight.checked_add_signed(Duration::nanoseconds(nanoseconds))
+        })
+}
+
+/// Convert a decimal Julian Day to a `chrono::DateTime` in the given
+/// timezone
+///
+/// Returns `None` if the date can't be represented by `chrono`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate meealgi;
+/// # extern crate chrono;
+/// # use chrono::{FixedOffset, TimeZone, Timelike};
+/// # fn main() {
+/// let sydney = FixedOffset::east_opt(10 * 3600).unwrap();
+/// let dt = meealgi::time::jul_to_dt(2_451_545_f64, &sydney).unwrap();
+/// assert_eq!(22, dt.hour());
+/// # }
+/// ```
+pub fn jul_to_dt<Tz: TimeZone>(jd: f64, tz: &Tz) -> Option<DateTime<Tz>> {
+    jul_to_ndt(jd).map(|ndt| tz.from_utc_datetime(&ndt))
+}
+
 /// Calculate the Julian century from a Julian Day.
 ///
 /// # Examples
@@ -78,81 +166,84 @@ pub fn julc_to_julm(jc: f64) -> f64 {
 
 #[cfg(test)]
 mod tests {
-    use chrono::prelude::{TimeZone, Utc, DateTime};
+    use chrono::prelude::{TimeZone, Utc, DateTime, FixedOffset};
+    use chrono::Duration;
     use time::*;
+    use spectral::prelude::*;
 
     #[test]
     fn jd_solar_report_tests() {
         struct CheckPair {
             date: DateTime<Utc>,
             result: f64,
-        };
+        }
 
         // test data from NREL Solar A.4.1
         let pairs = [
             CheckPair {
-                date: Utc.ymd(2000, 01, 01).and_hms(12, 00, 00),
+                date: Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap(),
                 result: 2451545.0_f64,
             },
             CheckPair {
-                date: Utc.ymd(1999, 01, 01).and_hms(00, 00, 00),
+                date: Utc.with_ymd_and_hms(1999, 1, 1, 0, 0, 0).unwrap(),
                 result: 2451179.5_f64,
             },
             CheckPair {
-                date: Utc.ymd(1987, 01, 27).and_hms(00, 00, 00),
+                date: Utc.with_ymd_and_hms(1987, 1, 27, 0, 0, 0).unwrap(),
                 result: 2446822.5_f64,
             },
             CheckPair {
-                date: Utc.ymd(1987, 06, 19).and_hms(12, 00, 00),
+                date: Utc.with_ymd_and_hms(1987, 6, 19, 12, 0, 0).unwrap(),
                 result: 2446966.0_f64,
             },
             CheckPair {
-                date: Utc.ymd(1988, 01, 27).and_hms(00, 00, 00),
+                date: Utc.with_ymd_and_hms(1988, 1, 27, 0, 0, 0).unwrap(),
                 result: 2447187.5_f64,
             },
             CheckPair {
-                date: Utc.ymd(1988, 06, 19).and_hms(12, 00, 00),
+                date: Utc.with_ymd_and_hms(1988, 6, 19, 12, 0, 0).unwrap(),
                 result: 2447332.0_f64,
             },
             CheckPair {
-                date: Utc.ymd(1900, 01, 01).and_hms(00, 00, 00),
+                date: Utc.with_ymd_and_hms(1900, 1, 1, 0, 0, 0).unwrap(),
                 result: 2415020.5_f64,
             },
             CheckPair {
-                date: Utc.ymd(1600, 01, 01).and_hms(00, 00, 00),
+                date: Utc.with_ymd_and_hms(1600, 1, 1, 0, 0, 0).unwrap(),
                 result: 2305447.5_f64,
             },
             CheckPair {
-                date: Utc.ymd(1600, 12, 31).and_hms(00, 00, 00),
+                date: Utc.with_ymd_and_hms(1600, 12, 31, 0, 0, 0).unwrap(),
                 result: 2305812.5_f64,
             },
             CheckPair {
-                date: Utc.ymd(0837, 04, 10).and_hms(07, 12, 00),
+                date: Utc.with_ymd_and_hms(837, 4, 10, 7, 12, 0).unwrap(),
                 result: 2026871.8_f64,
             },
             CheckPair {
-                date: Utc.ymd(-0123, 12, 31).and_hms(00, 00, 00),
+                date: Utc.with_ymd_and_hms(-123, 12, 31, 0, 0, 0).unwrap(),
                 result: 1676496.5_f64,
             },
             CheckPair {
-                date: Utc.ymd(-0122, 01, 01).and_hms(00, 00, 00),
+                date: Utc.with_ymd_and_hms(-122, 1, 1, 0, 0, 0).unwrap(),
                 result: 1676497.5_f64,
             },
             CheckPair {
-                date: Utc.ymd(-1000, 07, 12).and_hms(12, 00, 00),
+                date: Utc.with_ymd_and_hms(-1000, 7, 12, 12, 0, 0).unwrap(),
                 result: 1356001.0_f64,
             },
             // TODO: [vendor-issue]: chronotope/chrono#180
+            // (covered by the `time::calendar` tests instead)
             // CheckPair {
-            //    date: Utc.ymd(-1000, 02, 29).and_hms(00, 00, 00),
+            //    date: Utc.with_ymd_and_hms(-1000, 2, 29, 0, 0, 0).unwrap(),
             //    result: 1355866.5_f64
             // },
             CheckPair {
-                date: Utc.ymd(-1001, 08, 17).and_hms(21, 36, 00),
+                date: Utc.with_ymd_and_hms(-1001, 8, 17, 21, 36, 0).unwrap(),
                 result: 1355671.4_f64,
             },
             CheckPair {
-                date: Utc.ymd(-4712, 1, 1).and_hms(12, 00, 00),
+                date: Utc.with_ymd_and_hms(-4712, 1, 1, 12, 0, 0).unwrap(),
                 result: 0.0_f64,
             },
         ];
@@ -161,4 +252,58 @@ mod tests {
             assert_eq!(pair.result, ndt_to_jul(&pair.date.naive_utc()));
         }
     }
+
+    #[test]
+    fn jd_millisecond_precision() {
+        let date = Utc.with_ymd_and_hms(2000, 1, 1, 12, 0, 0).unwrap().naive_utc() +
+            Duration::milliseconds(1);
+
+        assert_that!(ndt_to_jul(&date) - 2451545.0_f64)
+            .is_close_to(0.001_f64 / 86_400_f64, 1e-9_f64);
+    }
+
+    #[test]
+    fn jd_microsecond_precision() {
+        // close to JD 0, where an f64 has precision to spare
+        let date = Utc.with_ymd_and_hms(-4712, 1, 1, 12, 0, 0).unwrap().naive_utc() +
+            Duration::microseconds(250);
+
+        assert_that!(ndt_to_jul(&date)).is_close_to(0.000_25_f64 / 86_400_f64, 1e-13_f64);
+    }
+
+    #[test]
+    fn jd_round_trips_through_datetime() {
+        let offset = FixedOffset::west_opt(5 * 3600 + 30 * 60).unwrap();
+        let date = offset.with_ymd_and_hms(1987, 6, 19, 6, 30, 0).unwrap() +
+            Duration::milliseconds(125);
+
+        let jd = dt_to_jul(&date);
+        assert_eq!(ndt_to_jul(&date.naive_utc()), jd);
+
+        let back = jul_to_dt(jd, &offset).unwrap();
+        assert_eq!(date.offset(), back.offset());
+        assert!((back - date).num_microseconds().unwrap().abs() < 100);
+    }
+
+    #[test]
+    fn jd_outside_chrono_range() {
+        assert_eq!(None, jul_to_ndt(1e12_f64));
+        assert_eq!(None, jul_to_ndt(-1e12_f64));
+        assert_eq!(None, jul_to_ndt(f64::NAN));
+        assert_eq!(None, jul_to_ndt(f64::INFINITY));
+    }
+
+    #[test]
+    fn jd_round_trips_in_julian_calendar() {
+        for date in &[
+            Utc.with_ymd_and_hms(1000, 3, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(837, 4, 10, 7, 12, 0).unwrap(),
+            Utc.with_ymd_and_hms(-1000, 7, 12, 12, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(1582, 10, 4, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(1582, 10, 15, 0, 0, 0).unwrap(),
+        ] {
+            let back = jul_to_dt(dt_to_jul(date), &Utc).unwrap();
+            assert!((back - *date).num_microseconds().unwrap().abs() < 100, "{}", back);
+        }
+    }
 }
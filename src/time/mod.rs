@@ -3,7 +3,16 @@ extern crate chrono;
 
 mod julian;
 mod decimal_day;
+mod epoch;
+mod sidereal;
 
 pub mod nasa;
+pub mod calendar;
+pub mod jewish;
+pub mod islamic;
+#[cfg(feature = "chrono-tz")]
+pub mod zone;
 pub use self::julian::*;
 pub use self::decimal_day::*;
+pub use self::epoch::*;
+pub use self::sidereal::*;
@@ -14,7 +14,7 @@ use chrono::{NaiveDate, Datelike};
 /// # extern crate meealgi;
 /// # fn main() {
 /// // day isn't used by the algorithm, but must be valid
-/// let month = chrono::NaiveDate::from_ymd(2017, 08, 01);
+/// let month = chrono::NaiveDate::from_ymd_opt(2017, 8, 1).unwrap();
 /// let delta_t = meealgi::time::nasa::delta_t(&month);
 /// # }
 /// ```
@@ -24,8 +24,21 @@ pub fn delta_t(date: &NaiveDate) -> f64 {
     )
 }
 
-fn delta_t_frac_year(y: f64) -> f64 {
-    if y < -500_f64 || y >= 2150_f64 {
+/// Estimates the ΔT (TD - UT) for a fractional year (e.g. `2017.5`).
+///
+/// Unlike `delta_t`, this isn't limited to month resolution, so it can be
+/// evaluated at any point in time.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate meealgi;
+/// # fn main() {
+/// let delta_t = meealgi::time::nasa::delta_t_frac_year(2017.625_f64);
+/// # }
+/// ```
+pub fn delta_t_frac_year(y: f64) -> f64 {
+    if !(-500_f64..2150_f64).contains(&y) {
         let u = (y - 1820_f64) / 100_f64;
 
         -20_f64 + 32_f64 * u.powi(2)
@@ -94,6 +107,43 @@ fn delta_t_frac_year(y: f64) -> f64 {
     }
 }
 
+/// Estimates the ΔT (TD - UT) for a given Julian Day.
+///
+/// The Julian Day is converted to a fractional Julian epoch year, so the
+/// result varies continuously with time.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate meealgi;
+/// # fn main() {
+/// // 2017-08-29T12:00:00
+/// let delta_t = meealgi::time::nasa::delta_t_jul(2_457_995_f64);
+/// # }
+/// ```
+pub fn delta_t_jul(jd: f64) -> f64 {
+    delta_t_frac_year(::time::jul_to_julian_epoch(jd))
+}
+
+/// Estimates the ΔT (TD - UT) for a given datetime.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate chrono;
+/// # extern crate meealgi;
+/// # fn main() {
+/// let ndt = chrono::NaiveDate::from_ymd_opt(2017, 08, 29)
+///     .unwrap()
+///     .and_hms_opt(12, 30, 00)
+///     .unwrap();
+/// let delta_t = meealgi::time::nasa::delta_t_ndt(&ndt);
+/// # }
+/// ```
+pub fn delta_t_ndt(ndt: &chrono::NaiveDateTime) -> f64 {
+    delta_t_jul(::time::ndt_to_jul(ndt))
+}
+
 /// Estimates the Julian Ephemeris Day for a given datetime.
 ///
 /// # Examples
@@ -104,11 +154,75 @@ fn delta_t_frac_year(y: f64) -> f64 {
 /// # use chrono::prelude::*;
 /// # use meealgi::time::nasa::*;
 /// # fn main () {
-/// let jule = ndt_to_jule(&chrono::NaiveDate::from_ymd(2017, 08, 29).and_hms(12, 00, 00));
+/// let jule = ndt_to_jule(&chrono::NaiveDate::from_ymd_opt(2017, 8, 29).unwrap().and_hms(12, 00, 00));
 /// # }
 /// ```
 pub fn ndt_to_jule(ndt: &chrono::NaiveDateTime) -> f64 {
-    ::time::ndt_to_jul(ndt) + delta_t(&ndt.date()) / 86_400_f64
+    jul_to_jule(::time::ndt_to_jul(ndt))
+}
+
+/// Estimates the Julian Ephemeris Day for a given timezone-aware datetime.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate chrono;
+/// # extern crate meealgi;
+/// # use chrono::prelude::*;
+/// # use meealgi::time::nasa::*;
+/// # fn main () {
+/// let jule = dt_to_jule(&Utc.with_ymd_and_hms(2017, 08, 29, 12, 00, 00).unwrap());
+/// # }
+/// ```
+pub fn dt_to_jule<Tz: chrono::TimeZone>(dt: &chrono::DateTime<Tz>) -> f64 {
+    ndt_to_jule(&dt.naive_utc())
+}
+
+/// Estimates the Julian Ephemeris Day for a given Julian Day (UT).
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::time::nasa::*;
+///
+/// let jde = jul_to_jule(2_451_545_f64);
+/// assert_eq!(64, ((jde - 2_451_545_f64) * 86_400_f64).round() as i64);
+/// ```
+pub fn jul_to_jule(jd: f64) -> f64 {
+    jd + delta_t_jul(jd) / 86_400_f64
+}
+
+/// Estimates the Julian Day (UT) for a given Julian Ephemeris Day.
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::time::nasa::*;
+///
+/// let jd = jule_to_jul(2_451_545_f64);
+/// assert_eq!(-64, ((jd - 2_451_545_f64) * 86_400_f64).round() as i64);
+/// ```
+pub fn jule_to_jul(jde: f64) -> f64 {
+    jde - delta_t_jul(jde) / 86_400_f64
+}
+
+/// Estimates the datetime in the given timezone for a given Julian Ephemeris
+/// Day, or `None` if it can't be represented by `chrono`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate chrono;
+/// # extern crate meealgi;
+/// # use chrono::prelude::*;
+/// # use meealgi::time::nasa::*;
+/// # fn main () {
+/// let dt = jule_to_dt(2_451_545_f64, &Utc).unwrap();
+/// assert_eq!((11, 58), (dt.hour(), dt.minute()));
+/// # }
+/// ```
+pub fn jule_to_dt<Tz: chrono::TimeZone>(jde: f64, tz: &Tz) -> Option<chrono::DateTime<Tz>> {
+    ::time::jul_to_dt(jule_to_jul(jde), tz)
 }
 
 #[cfg(test)]
@@ -125,7 +239,7 @@ mod tests {
             date: chrono::NaiveDate,
             expected: f64,
             within: f64,
-        };
+        }
 
         // test data from https://eclipse.gsfc.nasa.gov/SEcat5/deltat.html
         // (tables 1 and 2)
@@ -134,200 +248,200 @@ mod tests {
             //
             // For this item, math seems to check out, but significantly higher variance...
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(-500, 01, 01),
+                date: chrono::NaiveDate::from_ymd_opt(-500, 1, 1).unwrap(),
                 expected: 17190_f64,
                 within: 13_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(-400, 01, 01),
+                date: chrono::NaiveDate::from_ymd_opt(-400, 1, 1).unwrap(),
                 expected: 15530_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(-300, 01, 01),
+                date: chrono::NaiveDate::from_ymd_opt(-300, 1, 1).unwrap(),
                 expected: 14080_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(-200, 01, 01),
+                date: chrono::NaiveDate::from_ymd_opt(-200, 1, 1).unwrap(),
                 expected: 12790_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(-100, 01, 01),
+                date: chrono::NaiveDate::from_ymd_opt(-100, 1, 1).unwrap(),
                 expected: 11640_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(0000, 01, 01),
+                date: chrono::NaiveDate::from_ymd_opt(0, 1, 1).unwrap(),
                 expected: 10580_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(0100, 01, 01),
-                expected: 09600_f64,
+                date: chrono::NaiveDate::from_ymd_opt(100, 1, 1).unwrap(),
+                expected: 9600_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(0200, 01, 01),
-                expected: 08640_f64,
+                date: chrono::NaiveDate::from_ymd_opt(200, 1, 1).unwrap(),
+                expected: 8640_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(0300, 01, 01),
-                expected: 07680_f64,
+                date: chrono::NaiveDate::from_ymd_opt(300, 1, 1).unwrap(),
+                expected: 7680_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(0400, 01, 01),
-                expected: 06700_f64,
+                date: chrono::NaiveDate::from_ymd_opt(400, 1, 1).unwrap(),
+                expected: 6700_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(0500, 01, 01),
-                expected: 05710_f64,
+                date: chrono::NaiveDate::from_ymd_opt(500, 1, 1).unwrap(),
+                expected: 5710_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(0600, 01, 01),
-                expected: 04740_f64,
+                date: chrono::NaiveDate::from_ymd_opt(600, 1, 1).unwrap(),
+                expected: 4740_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(0700, 01, 01),
-                expected: 03810_f64,
+                date: chrono::NaiveDate::from_ymd_opt(700, 1, 1).unwrap(),
+                expected: 3810_f64,
                 within: 4_f64,
             },
             // For this item, math seems to check out, slightly higher variance?
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(0800, 01, 01),
-                expected: 02960_f64,
+                date: chrono::NaiveDate::from_ymd_opt(800, 1, 1).unwrap(),
+                expected: 2960_f64,
                 within: 5_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(0900, 01, 01),
-                expected: 02200_f64,
+                date: chrono::NaiveDate::from_ymd_opt(900, 1, 1).unwrap(),
+                expected: 2200_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(1000, 01, 01),
-                expected: 01570_f64,
+                date: chrono::NaiveDate::from_ymd_opt(1000, 1, 1).unwrap(),
+                expected: 1570_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(1100, 01, 01),
-                expected: 01090_f64,
+                date: chrono::NaiveDate::from_ymd_opt(1100, 1, 1).unwrap(),
+                expected: 1090_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(1200, 01, 01),
-                expected: 00740_f64,
+                date: chrono::NaiveDate::from_ymd_opt(1200, 1, 1).unwrap(),
+                expected: 740_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(1300, 01, 01),
-                expected: 00490_f64,
+                date: chrono::NaiveDate::from_ymd_opt(1300, 1, 1).unwrap(),
+                expected: 490_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(1400, 01, 01),
-                expected: 00320_f64,
+                date: chrono::NaiveDate::from_ymd_opt(1400, 1, 1).unwrap(),
+                expected: 320_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(1500, 01, 01),
-                expected: 00200_f64,
+                date: chrono::NaiveDate::from_ymd_opt(1500, 1, 1).unwrap(),
+                expected: 200_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(1600, 01, 01),
-                expected: 00120_f64,
+                date: chrono::NaiveDate::from_ymd_opt(1600, 1, 1).unwrap(),
+                expected: 120_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(1700, 01, 01),
-                expected: 00009_f64,
+                date: chrono::NaiveDate::from_ymd_opt(1700, 1, 1).unwrap(),
+                expected: 9_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(1750, 01, 01),
-                expected: 00013_f64,
+                date: chrono::NaiveDate::from_ymd_opt(1750, 1, 1).unwrap(),
+                expected: 13_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(1800, 01, 01),
-                expected: 00014_f64,
+                date: chrono::NaiveDate::from_ymd_opt(1800, 1, 1).unwrap(),
+                expected: 14_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(1850, 01, 01),
-                expected: 00007_f64,
+                date: chrono::NaiveDate::from_ymd_opt(1850, 1, 1).unwrap(),
+                expected: 7_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(1900, 01, 01),
-                expected: -0003_f64,
+                date: chrono::NaiveDate::from_ymd_opt(1900, 1, 1).unwrap(),
+                expected: -3_f64,
                 within: 4_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(1950, 01, 01),
-                expected: 00029_f64,
+                date: chrono::NaiveDate::from_ymd_opt(1950, 1, 1).unwrap(),
+                expected: 29_f64,
                 within: 4_f64,
             },
 
             // Table 2:
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(1955, 01, 01),
+                date: chrono::NaiveDate::from_ymd_opt(1955, 1, 1).unwrap(),
                 expected: 31.1,
                 within: 1_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(1960, 01, 01),
+                date: chrono::NaiveDate::from_ymd_opt(1960, 1, 1).unwrap(),
                 expected: 33.2,
                 within: 1_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(1965, 01, 01),
+                date: chrono::NaiveDate::from_ymd_opt(1965, 1, 1).unwrap(),
                 expected: 35.7,
                 within: 1_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(1970, 01, 01),
+                date: chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap(),
                 expected: 40.2,
                 within: 1_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(1975, 01, 01),
+                date: chrono::NaiveDate::from_ymd_opt(1975, 1, 1).unwrap(),
                 expected: 45.5,
                 within: 1_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(1980, 01, 01),
+                date: chrono::NaiveDate::from_ymd_opt(1980, 1, 1).unwrap(),
                 expected: 50.5,
                 within: 1_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(1985, 01, 01),
+                date: chrono::NaiveDate::from_ymd_opt(1985, 1, 1).unwrap(),
                 expected: 54.3,
                 within: 1_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(1990, 01, 01),
+                date: chrono::NaiveDate::from_ymd_opt(1990, 1, 1).unwrap(),
                 expected: 56.9,
                 within: 1_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(1995, 01, 01),
+                date: chrono::NaiveDate::from_ymd_opt(1995, 1, 1).unwrap(),
                 expected: 60.8,
                 within: 1_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(2000, 01, 01),
+                date: chrono::NaiveDate::from_ymd_opt(2000, 1, 1).unwrap(),
                 expected: 63.8,
                 within: 1_f64,
             },
             CheckSet {
-                date: chrono::NaiveDate::from_ymd(2005, 01, 01),
+                date: chrono::NaiveDate::from_ymd_opt(2005, 1, 1).unwrap(),
                 expected: 64.7,
                 within: 1_f64,
             },
@@ -337,14 +451,57 @@ mod tests {
             let val = delta_t(&set.date);
             assert!(
                 within(val, set.expected, set.within),
-                format!(
-                    "Expected NASA ΔT to be within {} seconds (date: {}, expected: {}, actual: {})",
-                    set.within,
-                    set.date,
-                    set.expected,
-                    val
-                )
+                "Expected NASA ΔT to be within {} seconds (date: {}, expected: {}, actual: {})",
+                set.within,
+                set.date,
+                set.expected,
+                val
             );
         }
     }
+
+    #[test]
+    fn delta_t_jul_matches_monthly_estimate() {
+        // 2017-08-16T12:00:00, roughly the middle of the month
+        let jd = ::time::ndt_to_jul(&chrono::NaiveDate::from_ymd_opt(2017, 8, 16)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap());
+        let monthly = delta_t(&chrono::NaiveDate::from_ymd_opt(2017, 8, 1).unwrap());
+
+        assert!(within(delta_t_jul(jd), monthly, 0.01_f64));
+    }
+
+    #[test]
+    fn delta_t_is_continuous_across_months() {
+        let before = chrono::NaiveDate::from_ymd_opt(2017, 8, 31)
+            .unwrap()
+            .and_hms_opt(23, 59, 0)
+            .unwrap();
+        let after = chrono::NaiveDate::from_ymd_opt(2017, 9, 1)
+            .unwrap()
+            .and_hms_opt(0, 1, 0)
+            .unwrap();
+
+        // monthly estimates jump at the boundary...
+        assert!(!within(delta_t(&before.date()), delta_t(&after.date()), 0.001_f64));
+        // ...but the continuous estimate barely moves over two minutes
+        assert!(within(delta_t_ndt(&before), delta_t_ndt(&after), 0.000_01_f64));
+        assert!(delta_t_ndt(&before) < delta_t_ndt(&after));
+    }
+
+    #[test]
+    fn jule_keeps_milliseconds() {
+        let base = chrono::NaiveDate::from_ymd_opt(2017, 8, 29)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let later = base + chrono::Duration::milliseconds(250);
+
+        assert!(within(
+            ndt_to_jule(&later) - ndt_to_jule(&base),
+            0.25_f64 / 86_400_f64,
+            1e-9_f64,
+        ));
+    }
 }
@@ -0,0 +1,51 @@
+use utils::angles::limit_radians;
+
+/// Calculates the mean sidereal time at Greenwich, in radians, for a given
+/// Julian Day (UT)
+///
+/// Obtained from NREL SPA report (A.4.8)
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::time::mean_sidereal_time;
+///
+/// // 1987 April 10, 19h21m UT (Meeus example 12.b)
+/// let theta_0 = mean_sidereal_time(2_446_896.306_25_f64);
+/// assert_eq!(128.737_873, (theta_0.to_degrees() * 1e6).round() / 1e6);
+/// ```
+pub fn mean_sidereal_time(jd: f64) -> f64 {
+    let jc = ::time::jul_to_julc(jd);
+
+    limit_radians(
+        (280.460_618_37_f64 + 360.985_647_366_29_f64 * (jd - 2_451_545_f64) +
+             0.000_387_933_f64 * jc.powi(2) - jc.powi(3) / 38_710_000_f64)
+            .to_radians(),
+    )
+}
+
+/// Calculates the apparent sidereal time at Greenwich, in radians, given
+/// the mean sidereal time, the nutation in longitude and the true obliquity
+/// of the ecliptic
+///
+/// Obtained from NREL SPA report (A.4.8)
+///
+/// # Examples
+///
+/// ```rust
+/// use meealgi::earth::*;
+/// use meealgi::time::*;
+///
+/// // 1987 April 10, 0h UT (Meeus example 12.a)
+/// let jd = 2_446_895.5_f64;
+/// let jce = jul_to_julc(nasa::ndt_to_jule(&jul_to_ndt(jd).unwrap()));
+/// let theta = apparent_sidereal_time(
+///     mean_sidereal_time(jd),
+///     nutation_longitude(jce),
+///     true_obliquity(mean_obliquity(julc_to_julm(jce)), nutation_obliquity(jce)),
+/// );
+/// assert_eq!(197.692_23, (theta.to_degrees() * 1e5).round() / 1e5);
+/// ```
+pub fn apparent_sidereal_time(mean_sidereal: f64, nutation_long: f64, true_obliquity: f64) -> f64 {
+    limit_radians(mean_sidereal + nutation_long * true_obliquity.cos())
+}
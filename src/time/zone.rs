@@ -0,0 +1,49 @@
+//! Conversions into named IANA timezones (requires the `chrono-tz` feature).
+
+use chrono::DateTime;
+use chrono_tz::Tz;
+use time::jul_to_dt;
+
+/// Convert a decimal Julian Day to a `chrono::DateTime` in a named IANA
+/// timezone (e.g. `"Australia/Sydney"`), with daylight saving applied.
+///
+/// Returns `None` if the zone name isn't recognised, or the date can't be
+/// represented by `chrono`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate chrono;
+/// # extern crate meealgi;
+/// # use chrono::Timelike;
+/// # fn main() {
+/// let dt = meealgi::time::zone::jul_to_zone(2_451_545_f64, "Australia/Sydney").unwrap();
+/// // daylight saving is in effect in January
+/// assert_eq!(23, dt.hour());
+/// # }
+/// ```
+pub fn jul_to_zone(jd: f64, zone: &str) -> Option<DateTime<Tz>> {
+    zone.parse::<Tz>().ok().and_then(|tz| jul_to_dt(jd, &tz))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Timelike;
+    use time::zone::*;
+
+    #[test]
+    fn applies_daylight_saving() {
+        // 2000-07-01T12:00:00Z, outside of Sydney's daylight saving
+        let winter = jul_to_zone(2_451_727_f64, "Australia/Sydney").unwrap();
+        assert_eq!(22, winter.hour());
+
+        // 2000-01-01T12:00:00Z, during Sydney's daylight saving
+        let summer = jul_to_zone(2_451_545_f64, "Australia/Sydney").unwrap();
+        assert_eq!(23, summer.hour());
+    }
+
+    #[test]
+    fn rejects_unknown_zones() {
+        assert!(jul_to_zone(2_451_545_f64, "Nowhere/Special").is_none());
+    }
+}
@@ -29,9 +29,21 @@ pub fn limit_radians(val: f64) -> f64 {
     }
 }
 
+/// Limits a value into the range -PI -> PI
+///
+/// # Examples:
+/// ```
+/// use meealgi::utils::angles::limit_signed_radians;
+///
+/// assert_eq!(-90_f64, limit_signed_radians(270_f64.to_radians()).to_degrees().round());
+/// ```
+pub fn limit_signed_radians(val: f64) -> f64 {
+    val.sin().atan2(val.cos())
+}
+
 #[cfg(test)]
 mod tests {
-    use utils::angles::limit_radians;
+    use utils::angles::{limit_radians, limit_signed_radians};
     use std::f64::consts::{PI, FRAC_PI_4};
     use spectral::prelude::*;
 
@@ -56,4 +68,14 @@ mod tests {
         assert_that!(limit_radians(-PI + FRAC_PI_4)).is_close_to(PI + FRAC_PI_4, 0.00000001_f64);
         assert_that!(limit_radians(-100_f64 * PI)).is_close_to(0_f64, 0.00000001_f64);
     }
+
+    #[test]
+    fn handles_signed() {
+        assert_that!(limit_signed_radians(0_f64)).is_close_to(0_f64, 0.00000001_f64);
+        assert_that!(limit_signed_radians(FRAC_PI_4)).is_close_to(FRAC_PI_4, 0.00000001_f64);
+        assert_that!(limit_signed_radians(-FRAC_PI_4)).is_close_to(-FRAC_PI_4, 0.00000001_f64);
+        assert_that!(limit_signed_radians(PI + FRAC_PI_4)).is_close_to(-3_f64 * FRAC_PI_4, 0.00000001_f64);
+        assert_that!(limit_signed_radians(-PI - FRAC_PI_4)).is_close_to(3_f64 * FRAC_PI_4, 0.00000001_f64);
+        assert_that!(limit_signed_radians(100_f64 * PI + FRAC_PI_4)).is_close_to(FRAC_PI_4, 0.00000001_f64);
+    }
 }
@@ -0,0 +1,37 @@
+//! Narrowing down the instant something changes, by bisection
+
+/// Narrows down the instant `side` changes between `low` and `high`,
+/// halving the interval `iterations` times
+pub(crate) fn bisect_change<S>(side: S, mut low: f64, mut high: f64, iterations: usize) -> f64
+where
+    S: Fn(f64) -> bool,
+{
+    let initial = side(low);
+
+    for _ in 0..iterations {
+        let middle = (low + high) / 2_f64;
+
+        if side(middle) == initial {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    (low + high) / 2_f64
+}
+
+/// Narrows down the instant `f` changes sign between `low` and `high`,
+/// halving the interval `iterations` times, or `None` if it has the same
+/// sign at both
+pub(crate) fn bisect<F>(f: F, low: f64, high: f64, iterations: usize) -> Option<f64>
+where
+    F: Fn(f64) -> f64,
+{
+    let non_negative = |x: f64| f(x) >= 0_f64;
+    if non_negative(low) == non_negative(high) {
+        return None;
+    }
+
+    Some(bisect_change(non_negative, low, high, iterations))
+}
@@ -0,0 +1,125 @@
+//! Celestial coordinate systems, and conversions between them
+
+/// A position in ecliptic coordinates, in radians
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Ecliptic {
+    /// ecliptic longitude (λ)
+    pub longitude: f64,
+    /// ecliptic latitude (β)
+    pub latitude: f64,
+}
+
+/// A position in equatorial coordinates, in radians
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Equatorial {
+    /// right ascension (α), from 0 to 2π
+    pub right_ascension: f64,
+    /// declination (δ)
+    pub declination: f64,
+}
+
+/// The location of an observer on the earth's surface, in radians
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Observer {
+    /// geographic latitude (φ), positive north of the equator
+    pub latitude: f64,
+    /// geographic longitude (L), positive east of Greenwich
+    pub longitude: f64,
+}
+
+impl Ecliptic {
+    /// Converts to equatorial coordinates, given the obliquity of the
+    /// ecliptic (ε)
+    ///
+    /// Obtained from NREL SPA report (A.4.9 and A.4.10)
+    ///
+    /// # Examples:
+    /// ```
+    /// use meealgi::utils::coordinates::Ecliptic;
+    ///
+    /// // Meeus example 13.a (Pollux)
+    /// let pollux = Ecliptic {
+    ///     longitude: 113.215_630_f64.to_radians(),
+    ///     latitude: 6.684_170_f64.to_radians(),
+    /// };
+    /// let equatorial = pollux.to_equatorial(23.439_281_1_f64.to_radians());
+    /// assert_eq!(116.328_9, (equatorial.right_ascension.to_degrees() * 1e4).round() / 1e4);
+    /// assert_eq!(28.026_2, (equatorial.declination.to_degrees() * 1e4).round() / 1e4);
+    /// ```
+    pub fn to_equatorial(&self, obliquity: f64) -> Equatorial {
+        let (sin_lambda, cos_lambda) = self.longitude.sin_cos();
+        let (sin_beta, cos_beta) = self.latitude.sin_cos();
+        let (sin_epsilon, cos_epsilon) = obliquity.sin_cos();
+
+        Equatorial {
+            right_ascension: ::utils::angles::limit_radians(
+                (sin_lambda * cos_epsilon - sin_beta / cos_beta * sin_epsilon).atan2(cos_lambda),
+            ),
+            declination: (sin_beta * cos_epsilon + cos_beta * sin_epsilon * sin_lambda).asin(),
+        }
+    }
+}
+
+impl Equatorial {
+    /// The local hour angle (H) of the position for an observer, in
+    /// radians, given the apparent sidereal time at Greenwich
+    ///
+    /// Obtained from NREL SPA report (A.4.11)
+    pub fn hour_angle(&self, sidereal_time: f64, observer: &Observer) -> f64 {
+        ::utils::angles::limit_radians(sidereal_time + observer.longitude - self.right_ascension)
+    }
+
+    /// The altitude (h) of the position above an observer's horizon, in
+    /// radians, given the apparent sidereal time at Greenwich
+    ///
+    /// Obtained from Meeus (ch. 13)
+    ///
+    /// # Examples:
+    /// ```
+    /// use meealgi::utils::coordinates::{Equatorial, Observer};
+    ///
+    /// // Meeus example 13.b (Venus from the US Naval Observatory)
+    /// let venus = Equatorial {
+    ///     right_ascension: 347.319_337_5_f64.to_radians(),
+    ///     declination: -6.719_892_f64.to_radians(),
+    /// };
+    /// let observer = Observer {
+    ///     latitude: 38.921_389_f64.to_radians(),
+    ///     longitude: -77.065_556_f64.to_radians(),
+    /// };
+    /// let altitude = venus.altitude(128.737_873_4_f64.to_radians(), &observer);
+    /// assert_eq!(15.12, (altitude.to_degrees() * 100_f64).round() / 100_f64);
+    /// ```
+    pub fn altitude(&self, sidereal_time: f64, observer: &Observer) -> f64 {
+        let hour_angle = self.hour_angle(sidereal_time, observer);
+
+        (observer.latitude.sin() * self.declination.sin() +
+             observer.latitude.cos() * self.declination.cos() * hour_angle.cos())
+            .asin()
+    }
+
+    /// The angular separation between two positions, in radians
+    ///
+    /// # Examples:
+    /// ```
+    /// use meealgi::utils::coordinates::Equatorial;
+    ///
+    /// // Meeus example 17.a (Arcturus and Spica)
+    /// let arcturus = Equatorial {
+    ///     right_ascension: 213.915_4_f64.to_radians(),
+    ///     declination: 19.182_4_f64.to_radians(),
+    /// };
+    /// let spica = Equatorial {
+    ///     right_ascension: 201.298_3_f64.to_radians(),
+    ///     declination: -11.161_4_f64.to_radians(),
+    /// };
+    /// assert_eq!(32.793, (arcturus.separation(&spica).to_degrees() * 1e3).round() / 1e3);
+    /// ```
+    pub fn separation(&self, other: &Equatorial) -> f64 {
+        let cos_separation = self.declination.sin() * other.declination.sin() +
+            self.declination.cos() * other.declination.cos() *
+                (self.right_ascension - other.right_ascension).cos();
+
+        cos_separation.clamp(-1_f64, 1_f64).acos()
+    }
+}
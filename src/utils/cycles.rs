@@ -0,0 +1,75 @@
+//! Finding events that recur each cycle, such as the phases of each
+//! lunation or the apsides of each anomalistic month
+
+use time::nasa::jule_to_jul;
+
+/// Finds the event nearest to a Julian Day (UT), given an estimate of its
+/// cycle number and a function calculating the event for a cycle
+///
+/// The mean cycle can be a little off the true event, so the neighbouring
+/// cycles are checked too. `jde` gives the Julian Ephemeris Day of an event.
+pub(crate) fn nearest_event<T, E, J>(estimate: i64, jd: f64, event: E, jde: J) -> T
+where
+    E: Fn(i64) -> T,
+    J: Fn(&T) -> f64,
+{
+    let distance = |event: &T| (jule_to_jul(jde(event)) - jd).abs();
+
+    (estimate - 1..estimate + 2)
+        .map(event)
+        .min_by(|a, b| distance(a).total_cmp(&distance(b)))
+        .unwrap()
+}
+
+/// Steps through each kind of event in each cycle (e.g. each phase of each
+/// lunation), in order, keeping those between two Julian Days (UT)
+#[derive(Debug, Clone)]
+pub(crate) struct CycleEvents<K: 'static> {
+    kinds: &'static [K],
+    cycle: i64,
+    kind_index: usize,
+    start_jd: f64,
+    end_jd: f64,
+}
+
+impl<K: Copy> CycleEvents<K> {
+    /// Starts at the first kind of event in a cycle, which should be at or
+    /// before the start
+    pub(crate) fn new(kinds: &'static [K], cycle: i64, start_jd: f64, end_jd: f64)
+                      -> CycleEvents<K> {
+        CycleEvents {
+            kinds,
+            cycle,
+            kind_index: 0,
+            start_jd,
+            end_jd,
+        }
+    }
+
+    /// Calculates events with `event` until one is between the Julian Days,
+    /// or `None` once they're past the end. `jde` gives the Julian Ephemeris
+    /// Day of an event.
+    pub(crate) fn next_event<T, E, J>(&mut self, event: E, jde: J) -> Option<T>
+    where
+        E: Fn(i64, K) -> T,
+        J: Fn(&T) -> f64,
+    {
+        loop {
+            let instant = event(self.cycle, self.kinds[self.kind_index]);
+
+            self.kind_index += 1;
+            if self.kind_index == self.kinds.len() {
+                self.kind_index = 0;
+                self.cycle += 1;
+            }
+
+            let jd = jule_to_jul(jde(&instant));
+            // a NaN end would never be reached
+            if jd >= self.end_jd || self.end_jd.is_nan() {
+                return None;
+            } else if jd >= self.start_jd {
+                return Some(instant);
+            }
+        }
+    }
+}
@@ -1,2 +1,6 @@
 //! Common calculation utilities
 pub mod angles;
+pub mod coordinates;
+pub mod rise_set;
+pub(crate) mod bisection;
+pub(crate) mod cycles;
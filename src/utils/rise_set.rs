@@ -0,0 +1,89 @@
+//! Searching for the instants a body rises, transits and sets
+
+use std::f64::consts::PI;
+use utils::bisection::bisect;
+
+/// Interval between samples when searching for events, in days
+const SEARCH_STEP: f64 = 1_f64 / 96_f64;
+/// Number of bisections used to refine an event, giving sub-second precision
+const REFINEMENTS: usize = 24;
+
+/// The instants a body rises, transits (crosses the meridian) and sets
+/// within a period, if it does so
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RiseTransitSet<T> {
+    /// when the body's upper limb crosses the horizon, moving upward
+    pub rise: Option<T>,
+    /// when the body crosses the observer's meridian, at its highest point
+    pub transit: Option<T>,
+    /// when the body's upper limb crosses the horizon, moving downward
+    pub set: Option<T>,
+}
+
+impl<T> RiseTransitSet<T> {
+    /// Converts each of the instants found, discarding any that can't be
+    /// converted
+    pub fn and_then<U, F: Fn(T) -> Option<U>>(self, f: F) -> RiseTransitSet<U> {
+        RiseTransitSet {
+            rise: self.rise.and_then(&f),
+            transit: self.transit.and_then(&f),
+            set: self.set.and_then(&f),
+        }
+    }
+}
+
+fn wrap_hour_angle(hour_angle: f64) -> f64 {
+    if hour_angle > PI {
+        hour_angle - 2_f64 * PI
+    } else {
+        hour_angle
+    }
+}
+
+/// Finds the first rise, transit and set between two Julian Days (UT).
+///
+/// `position` gives the body's altitude above its standard altitude (i.e.
+/// its altitude, less the altitude at which its upper limb appears on the
+/// horizon) and its local hour angle, both in radians, for a Julian Day.
+pub fn find_rise_transit_set<P>(start_jd: f64, end_jd: f64, position: P) -> RiseTransitSet<f64>
+where
+    P: Fn(f64) -> (f64, f64),
+{
+    let altitude = |jd: f64| position(jd).0;
+    let hour_angle = |jd: f64| wrap_hour_angle(position(jd).1);
+
+    let mut events = RiseTransitSet {
+        rise: None,
+        transit: None,
+        set: None,
+    };
+
+    let mut previous_jd = start_jd;
+    let (mut previous_altitude, previous_hour_angle) = position(start_jd);
+    let mut previous_hour_angle = wrap_hour_angle(previous_hour_angle);
+
+    while previous_jd < end_jd {
+        let jd = (previous_jd + SEARCH_STEP).min(end_jd);
+        let (current_altitude, current_hour_angle) = position(jd);
+        let current_hour_angle = wrap_hour_angle(current_hour_angle);
+
+        if previous_altitude < 0_f64 && current_altitude >= 0_f64 && events.rise.is_none() {
+            events.rise = bisect(altitude, previous_jd, jd, REFINEMENTS);
+        }
+        if previous_altitude >= 0_f64 && current_altitude < 0_f64 && events.set.is_none() {
+            events.set = bisect(altitude, previous_jd, jd, REFINEMENTS);
+        }
+        // ignore the jump in hour angle from π to -π (the lower transit)
+        if previous_hour_angle < 0_f64 && current_hour_angle >= 0_f64 &&
+            current_hour_angle - previous_hour_angle < PI && events.transit.is_none()
+        {
+            events.transit = bisect(hour_angle, previous_jd, jd, REFINEMENTS);
+        }
+
+        previous_jd = jd;
+        previous_altitude = current_altitude;
+        previous_hour_angle = current_hour_angle;
+    }
+
+    events
+}
@@ -1,4 +1,4 @@
-#![cfg_attr(feature="cargo-clippy", deny(clippy))]
+#![deny(clippy::all)]
 
 extern crate meealgi;
 extern crate chrono;
@@ -30,10 +30,10 @@ fn round_f64(val : f64, places : i32) -> f64 {
 // TODO: determine whether there's a bug causing the discrepencies
 #[ignore]
 fn calculates_expected_position() {
-    let test_date = chrono::NaiveDate::from_ymd(2017, 8, 30).and_hms(12, 0, 0);
+    let test_date = chrono::NaiveDate::from_ymd_opt(2017, 8, 30).unwrap().and_hms_opt(12, 0, 0).unwrap();
     let expected : Vec<f64> = vec![-0.000_168_f64, 337.315_687_f64, 1.009_582_f64];
     let actual : Vec<f64> = heliocentric_earth_from_time(&test_date)
-        .into_iter()
+        .iter()
         .map(|v| round_f64(*v, 6))
         .collect();
 
@@ -0,0 +1,45 @@
+#![deny(clippy::all)]
+
+extern crate meealgi;
+extern crate chrono;
+
+fn get_jce(time: &chrono::NaiveDateTime) -> f64 {
+    let jde = meealgi::time::nasa::ndt_to_jule(time);
+    meealgi::time::jul_to_julc(jde)
+}
+
+#[test]
+fn stays_within_orbital_bounds() {
+    let start = chrono::NaiveDate::from_ymd_opt(2017, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+
+    for hours in (0..24 * 365).step_by(7) {
+        let jce = get_jce(&(start + chrono::Duration::hours(hours)));
+
+        let distance = meealgi::moon::distance(jce);
+        assert!(distance > 356_000_f64 && distance < 407_000_f64, "{}", distance);
+
+        let latitude = meealgi::moon::geocentric_latitude(jce).to_degrees();
+        assert!(latitude.abs() < 5.35_f64, "{}", latitude);
+
+        let parallax = meealgi::moon::equatorial_horizontal_parallax(distance).to_degrees();
+        assert!(parallax > 0.89_f64 && parallax < 1.03_f64, "{}", parallax);
+    }
+}
+
+#[test]
+fn moves_eastward_about_13_degrees_a_day() {
+    let noon = chrono::NaiveDate::from_ymd_opt(2017, 8, 30)
+        .unwrap()
+        .and_hms_opt(12, 0, 0)
+        .unwrap();
+
+    let today = meealgi::moon::geocentric_longitude(get_jce(&noon));
+    let tomorrow =
+        meealgi::moon::geocentric_longitude(get_jce(&(noon + chrono::Duration::days(1))));
+
+    let motion = meealgi::utils::angles::limit_radians(tomorrow - today).to_degrees();
+    assert!(motion > 11.5_f64 && motion < 15.5_f64, "{}", motion);
+}